@@ -0,0 +1,124 @@
+//! A module that defines the `Action` trait and common monoid actions.
+//! `Action` trait および一般的なモノイド作用の実装を定義するモジュールである.
+
+use crate::algebra::{
+    monoid::{AddWithLenMonoid, MaxMonoid, MinMonoid, Monoid},
+    semi_group,
+};
+
+/// A trait representing a monoid of maps that acts on the elements of another monoid `M`.
+/// モノイド `M` の要素に作用する写像のモノイドを表現する trait である.
+///
+/// The binary operation `op(f, g)` of the action monoid is the composition
+/// "apply `f` first, then `g`", and the identity `id()` is the identity map.
+/// An implementation must satisfy the following for all `f`, `g`, `x`, `y`:
+/// - `act(&id(), &x) == x`
+/// - `act(&op(&f, &g), &x) == act(&g, &act(&f, &x))`
+/// - `act(&f, &M::op(&x, &y)) == M::op(&act(&f, &x), &act(&f, &y))`
+///
+/// 作用のモノイドの二項演算 `op(f, g)` は「`f` を作用させた後に `g` を作用させる」合成であり,
+/// 単位元 `id()` は恒等写像である.
+/// 実装は全ての `f`, `g`, `x`, `y` について以下を満たさなければならない.
+/// - `act(&id(), &x) == x`
+/// - `act(&op(&f, &g), &x) == act(&g, &act(&f, &x))`
+/// - `act(&f, &M::op(&x, &y)) == M::op(&act(&f, &x), &act(&f, &y))`
+pub trait Action<M>: Monoid
+where
+    M: Monoid,
+{
+    /// Applies the map `f` to the element `x`.
+    /// 写像 `f` を要素 `x` に作用させる.
+    ///
+    /// # Args
+    /// - `f`: The map to apply.
+    ///   作用させる写像.
+    /// - `x`: The element the map is applied to.
+    ///   作用を受ける要素.
+    ///
+    /// # Returns
+    /// `M::S`: The result of applying `f` to `x`.
+    ///         `x` に `f` を作用させた結果.
+    fn act(f: &Self::S, x: &M::S) -> M::S;
+}
+
+/// An action that adds an `i64` to every element of a range.
+/// With `MinMonoid` or `MaxMonoid`, an element that was never set holds the identity
+/// (`i64::MAX` or `i64::MIN`), so adding to it overflows for a positive or negative value
+/// respectively. Initialize every element (e.g. with `set` or `from_slice`) before range-add.
+/// 区間の全要素に `i64` を加算する作用である.
+/// `MinMonoid` や `MaxMonoid` と組み合わせる場合, 一度も設定されていない要素は単位元
+/// (`i64::MAX` や `i64::MIN`) を保持するため, それぞれ正や負の値を加えるとオーバーフローする.
+/// 区間加算の前に全要素を (`set` や `from_slice` などで) 初期化すること.
+pub struct AddAction;
+
+impl semi_group::SemiGroup for AddAction {
+    type S = i64;
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        *a + *b
+    }
+}
+
+impl Monoid for AddAction {
+    fn id() -> Self::S {
+        0
+    }
+}
+
+impl Action<MinMonoid> for AddAction {
+    fn act(f: &Self::S, x: &i64) -> i64 {
+        *x + *f
+    }
+}
+
+impl Action<MaxMonoid> for AddAction {
+    fn act(f: &Self::S, x: &i64) -> i64 {
+        *x + *f
+    }
+}
+
+impl Action<AddWithLenMonoid> for AddAction {
+    fn act(f: &Self::S, x: &(i64, i64)) -> (i64, i64) {
+        (x.0 + *f * x.1, x.1)
+    }
+}
+
+/// An action that assigns an `i64` to every element of a range.
+/// `None` is the identity map, and `Some(v)` overwrites every element with `v`.
+/// 区間の全要素に `i64` を代入する作用である.
+/// `None` は恒等写像であり, `Some(v)` は全要素を `v` で上書きする.
+pub struct AssignAction;
+
+impl semi_group::SemiGroup for AssignAction {
+    type S = Option<i64>;
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        // The later assignment wins.
+        b.or(*a)
+    }
+}
+
+impl Monoid for AssignAction {
+    fn id() -> Self::S {
+        None
+    }
+}
+
+impl Action<MinMonoid> for AssignAction {
+    fn act(f: &Self::S, x: &i64) -> i64 {
+        f.unwrap_or(*x)
+    }
+}
+
+impl Action<MaxMonoid> for AssignAction {
+    fn act(f: &Self::S, x: &i64) -> i64 {
+        f.unwrap_or(*x)
+    }
+}
+
+impl Action<AddWithLenMonoid> for AssignAction {
+    fn act(f: &Self::S, x: &(i64, i64)) -> (i64, i64) {
+        match f {
+            Some(v) => (*v * x.1, x.1),
+            None => *x,
+        }
+    }
+}
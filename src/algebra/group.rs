@@ -0,0 +1,36 @@
+//! A module that defines the `Group` trait and common abelian group implementations.
+//! `Group` trait および一般的なアーベル群の実装を定義するモジュールである.
+
+use crate::algebra::monoid;
+
+/// A trait representing a group, which is a monoid where every element has an inverse.
+/// 群 (group) を表現する trait であり, 全ての要素が逆元を持つモノイドである.
+pub trait Group: monoid::Monoid {
+    /// Returns the inverse element of `a`.
+    /// `a` の逆元を返す.
+    ///
+    /// # Args
+    /// - `a`: The element to invert.
+    ///   逆元を求める要素.
+    ///
+    /// # Returns
+    /// `Self::S`: The element `b` such that `op(a, b) = op(b, a) = id()`.
+    ///            `op(a, b) = op(b, a) = id()` を満たす要素 `b`.
+    fn inv(a: &Self::S) -> Self::S;
+}
+
+/// The addition on `i64` forms an abelian group with negation as the inverse.
+/// `i64` 型の加算は, 符号反転を逆元とするアーベル群をなす.
+impl Group for monoid::AddMonoid {
+    fn inv(a: &Self::S) -> Self::S {
+        -*a
+    }
+}
+
+/// The XOR on `u64` forms an abelian group in which every element is its own inverse.
+/// `u64` 型の排他的論理和 (XOR) は, 全ての要素が自身の逆元であるアーベル群をなす.
+impl Group for monoid::XorMonoid {
+    fn inv(a: &Self::S) -> Self::S {
+        *a
+    }
+}
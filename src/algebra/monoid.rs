@@ -28,7 +28,7 @@ impl semi_group::SemiGroup for MinMonoid {
 
 impl Monoid for MinMonoid {
     fn id() -> Self::S {
-        std::i64::MAX
+        i64::MAX
     }
 }
 
@@ -45,7 +45,7 @@ impl semi_group::SemiGroup for MaxMonoid {
 
 impl Monoid for MaxMonoid {
     fn id() -> Self::S {
-        std::i64::MIN
+        i64::MIN
     }
 }
 
@@ -66,6 +66,25 @@ impl Monoid for AddMonoid {
     }
 }
 
+/// A monoid for the addition operation on pairs `(sum, len)` of `i64`.
+/// Each leaf holds `(value, 1)`, so that actions such as range addition can scale by the length.
+/// `i64` 型の組 `(和, 長さ)` の加算を行うモノイドである.
+/// 各葉は `(値, 1)` を保持し, 区間加算などの作用が長さに応じて値を拡大できるようにする.
+pub struct AddWithLenMonoid;
+
+impl semi_group::SemiGroup for AddWithLenMonoid {
+    type S = (i64, i64);
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        (a.0 + b.0, a.1 + b.1)
+    }
+}
+
+impl Monoid for AddWithLenMonoid {
+    fn id() -> Self::S {
+        (0, 0)
+    }
+}
+
 /// A monoid for the XOR operation on `u64`.
 /// `u64` 型の排他的論理和 (XOR) を行うモノイドである.
 pub struct XorMonoid;
@@ -96,7 +115,7 @@ impl semi_group::SemiGroup for AndMonoid {
 
 impl Monoid for AndMonoid {
     fn id() -> Self::S {
-        std::u64::MAX
+        u64::MAX
     }
 }
 
@@ -0,0 +1,127 @@
+//! A module that defines the `PathMonoid` trait for shortest path problems and its common implementations.
+//! 最短路問題のための `PathMonoid` trait および一般的な実装を定義するモジュールである.
+
+use std::cmp::Ordering;
+
+use crate::algebra::{monoid, semi_group};
+use crate::ds::modint::modint998244353::ModInt998244353;
+
+/// A trait representing the values of paths, which is a monoid with a preference order.
+/// `op` extends a path by another, `id` is the value of the empty path,
+/// and `compare` tells which of two paths is better.
+///
+/// 経路の値を表現する trait であり, 優劣の順序を持つモノイドである.
+/// `op` は経路を別の経路で延長し, `id` は空の経路の値であり,
+/// `compare` は二つの経路のどちらが良いかを表す.
+pub trait PathMonoid: monoid::Monoid {
+    /// Compares two path values by preference.
+    /// 二つの経路の値を優劣で比較する.
+    ///
+    /// # Args
+    /// - `a`: The first path value.
+    ///   一つ目の経路の値.
+    /// - `b`: The second path value.
+    ///   二つ目の経路の値.
+    ///
+    /// # Returns
+    /// `Ordering`: `Less` if `a` is better than `b`, `Equal` if they are equally good, and `Greater` otherwise.
+    ///             `a` が `b` より良いなら `Less`, 同等なら `Equal`, そうでなければ `Greater`.
+    fn compare(a: &Self::S, b: &Self::S) -> Ordering;
+
+    /// Merges two equally good path values, which is `a` unless overridden.
+    /// 同等に良い二つの経路の値を併合する. 上書きしない限り `a` である.
+    ///
+    /// # Args
+    /// - `a`: The first path value.
+    ///   一つ目の経路の値.
+    /// - `b`: The second path value, for which `compare(a, b)` is `Equal`.
+    ///   `compare(a, b)` が `Equal` となる二つ目の経路の値.
+    ///
+    /// # Returns
+    /// `Self::S`: The merged value.
+    ///            併合した値.
+    fn merge(a: &Self::S, _b: &Self::S) -> Self::S
+    where
+        Self::S: Clone,
+    {
+        a.clone()
+    }
+}
+
+/// A path monoid for shortest paths, which sums the weights on `i64` and prefers smaller sums.
+/// `i64` 型の重みを足し合わせ, 和が小さい方を好む最短路のための経路モノイドである.
+pub struct MinPlusMonoid;
+
+impl semi_group::SemiGroup for MinPlusMonoid {
+    type S = i64;
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        *a + *b
+    }
+}
+
+impl monoid::Monoid for MinPlusMonoid {
+    fn id() -> Self::S {
+        0
+    }
+}
+
+impl PathMonoid for MinPlusMonoid {
+    fn compare(a: &Self::S, b: &Self::S) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// A path monoid for bottleneck paths, which takes the minimum weight on `i64` and prefers larger minimums.
+/// `i64` 型の重みの最小値を取り, 最小値が大きい方を好むボトルネック路のための経路モノイドである.
+pub struct MaxMinMonoid;
+
+impl semi_group::SemiGroup for MaxMinMonoid {
+    type S = i64;
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        std::cmp::min(*a, *b)
+    }
+}
+
+impl monoid::Monoid for MaxMinMonoid {
+    fn id() -> Self::S {
+        i64::MAX
+    }
+}
+
+impl PathMonoid for MaxMinMonoid {
+    fn compare(a: &Self::S, b: &Self::S) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+/// A path monoid on pairs `(length, count)` that counts shortest paths modulo 998244353.
+/// Each edge holds `(weight, 1)`, and equally short paths are merged by adding their counts.
+/// The counts are exact only when every edge weight is positive.
+///
+/// 組 `(長さ, 個数)` 上で, 最短路の個数を 998244353 を法として数える経路モノイドである.
+/// 各辺は `(重み, 1)` を保持し, 等しい長さの経路は個数を足し合わせて併合される.
+/// 個数は全ての辺の重みが正である場合にのみ正確である.
+pub struct ShortestPathCountMonoid;
+
+impl semi_group::SemiGroup for ShortestPathCountMonoid {
+    type S = (i64, ModInt998244353);
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        (a.0 + b.0, a.1 * b.1)
+    }
+}
+
+impl monoid::Monoid for ShortestPathCountMonoid {
+    fn id() -> Self::S {
+        (0, ModInt998244353::new(1))
+    }
+}
+
+impl PathMonoid for ShortestPathCountMonoid {
+    fn compare(a: &Self::S, b: &Self::S) -> Ordering {
+        a.0.cmp(&b.0)
+    }
+
+    fn merge(a: &Self::S, b: &Self::S) -> Self::S {
+        (a.0, a.1 + b.1)
+    }
+}
@@ -1,8 +1,10 @@
-//! A module that defines the `SemiGroup` trait.
-//! `SemiGroup` trait を定義するモジュールである.
+//! A module that defines the `SemiGroup` trait and the `Idempotent` marker trait.
+//! `SemiGroup` trait および `Idempotent` マーカー trait を定義するモジュールである.
 
 use std::cmp;
 
+use crate::algebra::monoid;
+
 /// A trait representing a semigroup, which has a single associative binary operation.
 /// 半群 (semigroup) を表現する trait であり, 一つの結合的な二項演算を持つ.
 pub trait SemiGroup {
@@ -15,9 +17,9 @@ pub trait SemiGroup {
     ///
     /// # Args
     /// - `a`: The first operand.
-    ///        第一オペランド.
+    ///   第一オペランド.
     /// - `b`: The second operand.
-    ///        第二オペランド.
+    ///   第二オペランド.
     ///
     /// # Returns
     /// `Self::S`: Returns the result of the operation.
@@ -57,3 +59,22 @@ impl SemiGroup for AddSemiGroup {
         *a + *b
     }
 }
+
+/// A marker trait for idempotent semigroups, in which `op(a, a) = a` for every element `a`.
+/// Overlapping ranges can then be combined without counting any element twice.
+///
+/// 冪等な半群, すなわち全ての要素 `a` について `op(a, a) = a` を満たす半群を表すマーカー trait である.
+/// このとき, 重なり合う区間を要素を二重に数えることなく結合できる.
+pub trait Idempotent: SemiGroup {}
+
+impl Idempotent for MinSemiGroup {}
+
+impl Idempotent for MaxSemiGroup {}
+
+impl Idempotent for monoid::MinMonoid {}
+
+impl Idempotent for monoid::MaxMonoid {}
+
+impl Idempotent for monoid::AndMonoid {}
+
+impl Idempotent for monoid::OrMonoid {}
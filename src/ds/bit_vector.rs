@@ -11,6 +11,30 @@ const MASKS: [u64; u64::BITS as usize] = {
     masks
 };
 
+/// The number of 64-bit blocks in a superblock of the two-level rank directory.
+/// 2 段の rank 辞書における 1 つの大ブロックに含まれる 64 ビットブロックの数である.
+const BLOCKS_PER_SUPERBLOCK: usize = 8;
+
+/// Every `SELECT_SAMPLE`-th 0 and 1 records the block containing it as a select hint.
+/// `SELECT_SAMPLE` 個おきの 0 と 1 について, それを含むブロックを select のヒントとして記録する.
+const SELECT_SAMPLE: usize = 512;
+
+/// The directory used to count the 1s before each 64-bit block.
+/// 各 64 ビットブロックより前にある 1 の個数を数えるための辞書である.
+#[derive(Clone)]
+enum RankDirectory {
+    // Stores the sum of 1s up to the end of each block.
+    Flat(Vec<u32>),
+
+    // Stores, for each superblock of `BLOCKS_PER_SUPERBLOCK` blocks, the sum of 1s before it
+    // and the sums of 1s from its start to the end of its first 7 blocks packed as 9-bit fields.
+    // This costs 32 + 64 bits per 512 bits of input.
+    TwoLevel {
+        absolute: Vec<u32>,
+        relative: Vec<u64>,
+    },
+}
+
 /// A data structure to efficiently store and query a sequence of bits (0s and 1s).
 /// 0 と 1 からなるビット列を効率的に格納し, クエリを実行するためのデータ構造である.
 ///
@@ -18,12 +42,25 @@ const MASKS: [u64; u64::BITS as usize] = {
 /// to allow for fast `sum` queries.
 /// `BitVector` は 64 ビットのブロックごとに 1 の累積和を事前計算することで,
 /// 高速な `sum` クエリを可能にする.
+///
+/// `new_compact` instead builds a two-level (superblock/block) directory that needs
+/// 18.75% extra space instead of 50%, at the cost of slightly slower queries.
+/// Both directories, and the select hints that add about 6.25% more, are a constant fraction
+/// of the bits, so the extra space is Θ(n) rather than o(n).
+/// `new_compact` は代わりに 2 段 (大ブロック/小ブロック) の辞書を構築し,
+/// クエリがわずかに遅くなる代わりに追加領域を 50% から 18.75% に抑える.
+/// どちらの辞書も, さらに約 6.25% を加える select のヒントも, ビット列の定数倍の領域であるため,
+/// 追加領域は o(n) ではなく Θ(n) である.
 #[derive(Clone)]
 pub struct BitVector {
     bits: Vec<u64>,
 
-    // Stores the sum of 1s up to the end of each block.
-    cumulative_sums: Vec<u32>,
+    // Counts the 1s before each block.
+    directory: RankDirectory,
+
+    // The blocks containing every `SELECT_SAMPLE`-th 1 and 0 respectively.
+    select1_hints: Vec<u32>,
+    select0_hints: Vec<u32>,
 
     // Length of the BitVector.
     len: usize,
@@ -36,9 +73,9 @@ impl BitVector {
     /// # Args
     ///
     /// - `v`: A slice of `u8` where each element is either `0` or `1`.
-    ///        The length of `v` must be less than 2^{32} (=4294967296).
+    ///   The length of `v` must be less than 2^{32} (=4294967296).
     ///   `v`: 各要素が `0` または `1` である `u8` のスライスである.
-    ///        `v` の長さは `2^{32}` (=4294967296) 未満でなければならない.
+    ///   `v` の長さは `2^{32}` (=4294967296) 未満でなければならない.
     ///
     /// # Returns
     ///
@@ -55,9 +92,9 @@ impl BitVector {
     /// # Complexity
     ///
     /// - Time complexity: O(N), where N is the length of `v`.
-    ///                    時間計算量: O(N) である. ここで N は `v` の長さである.
+    ///   時間計算量: O(N) である. ここで N は `v` の長さである.
     /// - Space complexity: O(N), where N is the length of `v`.
-    ///                     空間計算量: O(N) である. ここで N は `v` の長さである.
+    ///   空間計算量: O(N) である. ここで N は `v` の長さである.
     ///
     /// # Examples
     ///
@@ -71,6 +108,59 @@ impl BitVector {
     /// assert_eq!(0, bv.rank(0)); // sum([])
     /// ```
     pub fn new(v: &[u8]) -> Self {
+        Self::build(v, false)
+    }
+
+    /// Creates a new `BitVector` with a two-level (superblock/block) rank directory.
+    /// 2 段 (大ブロック/小ブロック) の rank 辞書を持つ新しい `BitVector` を作成する.
+    ///
+    /// The directory takes 96 bits per 512 bits (18.75%) instead of 50% for `new`, which suits
+    /// large bitmaps. This is still a constant-factor overhead, not o(n).
+    /// All queries return the same results as for `new`.
+    /// 辞書は `new` の 50% に対してビット列 512 ビットあたり 96 ビット (18.75%) の領域で済み,
+    /// 大きなビット列に適する. ただしこれは定数倍の追加領域であり, o(n) ではない.
+    /// 全てのクエリは `new` と同じ結果を返す.
+    ///
+    /// # Args
+    ///
+    /// - `v`: A slice of `u8` where each element is either `0` or `1`.
+    ///   The length of `v` must be less than 2^{32} (=4294967296).
+    ///   `v`: 各要素が `0` または `1` である `u8` のスライスである.
+    ///   `v` の長さは `2^{32}` (=4294967296) 未満でなければならない.
+    ///
+    /// # Returns
+    ///
+    /// A new `BitVector` instance.
+    /// 新しい `BitVector` インスタンスを返す.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any element in `v` is not `0` or `1`.
+    /// Panics if the length of `v` is greater than or equal to 2^{32}.
+    /// `v` のいずれかの要素が `0` または `1` でない場合にパニックする.
+    /// `v` の長さが `2^{32}` 以上の場合にパニックする.
+    ///
+    /// # Complexity
+    ///
+    /// - Time complexity: O(N), where N is the length of `v`.
+    ///   時間計算量: O(N) である. ここで N は `v` の長さである.
+    /// - Space complexity: O(N), where N is the length of `v`.
+    ///   空間計算量: O(N) である. ここで N は `v` の長さである.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use anmitsu::ds::bit_vector;
+    ///
+    /// let bv = bit_vector::BitVector::new_compact(&[1, 0, 1, 1, 0, 1]);
+    /// assert_eq!(3, bv.rank(4));
+    /// assert_eq!(Some(5), bv.select1(3));
+    /// ```
+    pub fn new_compact(v: &[u8]) -> Self {
+        Self::build(v, true)
+    }
+
+    fn build(v: &[u8], compact: bool) -> Self {
         let len = v.len();
         assert!(
             len < (1 << u32::BITS as usize),
@@ -79,8 +169,6 @@ impl BitVector {
 
         let num_blocks = len / u64::BITS as usize + 1;
         let mut bits = vec![0_u64; num_blocks];
-        let mut cumulative_sums = vec![0_u32; num_blocks];
-        let mut current_sum = 0_u32;
 
         // Iterate through the input vector to populate the bit vector
         // and validate that elements are either 0 or 1.
@@ -103,18 +191,85 @@ impl BitVector {
         // Calculate cumulative sums of set bits for rank operations.
         // This pre-computation allows for O(1) rank queries later,
         // by storing the total count of set bits up to the end of each block.
-        for i in 0..num_blocks {
-            current_sum += bits[i].count_ones();
-            cumulative_sums[i] = current_sum;
+        let directory = if compact {
+            let num_superblocks = num_blocks.div_ceil(BLOCKS_PER_SUPERBLOCK);
+            let mut absolute = vec![0_u32; num_superblocks];
+            let mut relative = vec![0_u64; num_superblocks];
+            let mut current_sum = 0_u32;
+            for (sb, chunk) in bits.chunks(BLOCKS_PER_SUPERBLOCK).enumerate() {
+                absolute[sb] = current_sum;
+                let mut inner_sum = 0_u64;
+                for (j, block) in chunk.iter().enumerate() {
+                    inner_sum += block.count_ones() as u64;
+                    // The sum up to the end of the last block is the next superblock's absolute sum.
+                    if j + 1 < BLOCKS_PER_SUPERBLOCK {
+                        relative[sb] |= inner_sum << (9 * j);
+                    }
+                }
+                current_sum += inner_sum as u32;
+            }
+            RankDirectory::TwoLevel { absolute, relative }
+        } else {
+            let mut cumulative_sums = vec![0_u32; num_blocks];
+            let mut current_sum = 0_u32;
+            for i in 0..num_blocks {
+                current_sum += bits[i].count_ones();
+                cumulative_sums[i] = current_sum;
+            }
+            RankDirectory::Flat(cumulative_sums)
+        };
+
+        // Record the block of every `SELECT_SAMPLE`-th 1 and 0 to narrow down select queries.
+        let mut select1_hints = Vec::new();
+        let mut select0_hints = Vec::new();
+        let (mut ones, mut zeros) = (0, 0);
+        for (i, &bit_val) in v.iter().enumerate() {
+            let block_index = (i / u64::BITS as usize) as u32;
+            if bit_val == 1 {
+                if ones % SELECT_SAMPLE == 0 {
+                    select1_hints.push(block_index);
+                }
+                ones += 1;
+            } else {
+                if zeros % SELECT_SAMPLE == 0 {
+                    select0_hints.push(block_index);
+                }
+                zeros += 1;
+            }
         }
 
         BitVector {
             bits,
-            cumulative_sums,
+            directory,
+            select1_hints,
+            select0_hints,
             len,
         }
     }
 
+    // Returns the number of 1s before the `block_index`-th block.
+    #[inline]
+    fn rank_block(&self, block_index: usize) -> usize {
+        match &self.directory {
+            RankDirectory::Flat(cumulative_sums) => {
+                if block_index == 0 {
+                    0
+                } else {
+                    cumulative_sums[block_index - 1] as usize
+                }
+            }
+            RankDirectory::TwoLevel { absolute, relative } => {
+                let sb = block_index / BLOCKS_PER_SUPERBLOCK;
+                let j = block_index % BLOCKS_PER_SUPERBLOCK;
+                let mut res = absolute[sb] as usize;
+                if j > 0 {
+                    res += ((relative[sb] >> (9 * (j - 1))) & 0x1ff) as usize;
+                }
+                res
+            }
+        }
+    }
+
     /// Returns the number of 1s in the range `v[0..r)` (sum of `v[0..r)`).
     /// 範囲 `v[0..r)` における `1` の数 ( `v[0..r)` の和) を返す.
     ///
@@ -136,9 +291,9 @@ impl BitVector {
     /// # Complexity
     ///
     /// - Time complexity: O(1) due to precomputation.
-    ///                    時間計算量: 事前計算により O(1) である.
+    ///   時間計算量: 事前計算により O(1) である.
     /// - Space complexity: O(1) for the query itself.
-    ///                     空間計算量: クエリ自体は O(1) である.
+    ///   空間計算量: クエリ自体は O(1) である.
     ///
     /// # Examples
     ///
@@ -168,15 +323,202 @@ impl BitVector {
         // bit data.
         let block_index = r / u64::BITS as usize;
 
-        let mut res = 0;
-        // Add the cumulative sum of 1s from all preceding full blocks.
-        if block_index > 0 {
-            res += self.cumulative_sums[block_index - 1];
+        // Add the number of 1s from all preceding full blocks, and then the number of 1s from
+        // the partial current block, up to the r-th bit, using MASKS to isolate the relevant bits.
+        self.rank_block(block_index)
+            + (self.bits[block_index] & MASKS[r % u64::BITS as usize]).count_ones() as usize
+    }
+
+    /// Returns the number of 0s in the range `v[0..r)`.
+    /// 範囲 `v[0..r)` における `0` の数を返す.
+    ///
+    /// # Args
+    ///
+    /// - `r`: The upper bound of the range. `r` must be less than or equal to `len()`.
+    ///   `r`: 範囲の上限である. `r` は `len()` 以下でなければならない.
+    ///
+    /// # Returns
+    ///
+    /// The number of 0s in `v[0..r)`, which equals `r - rank(r)`.
+    /// `v[0..r)` における `0` の数を返す. これは `r - rank(r)` に等しい.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r > len()`.
+    /// `r > len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    ///
+    /// - Time complexity: O(1) due to precomputation.
+    ///   時間計算量: 事前計算により O(1) である.
+    /// - Space complexity: O(1) for the query itself.
+    ///   空間計算量: クエリ自体は O(1) である.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use anmitsu::ds::bit_vector;
+    ///
+    /// let bv = bit_vector::BitVector::new(&[1, 0, 1, 1, 0, 1, 0, 0]);
+    /// assert_eq!(bv.rank0(0), 0);
+    /// assert_eq!(bv.rank0(5), 2); // v[0..5] = [1, 0, 1, 1, 0]
+    /// assert_eq!(bv.rank0(8), 4);
+    /// ```
+    pub fn rank0(&self, r: usize) -> usize {
+        r - self.rank(r)
+    }
+
+    /// Returns the bit `v[i]`.
+    /// ビット `v[i]` を返す.
+    ///
+    /// # Args
+    ///
+    /// - `i`: The index of the bit. `i` must be less than `len()`.
+    ///   `i`: ビットのインデックスである. `i` は `len()` 未満でなければならない.
+    ///
+    /// # Returns
+    ///
+    /// `1` if the bit is set, `0` otherwise.
+    /// ビットが立っていれば `1`, そうでなければ `0` を返す.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len()`.
+    /// `i >= len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    ///
+    /// - Time complexity: O(1).
+    ///   時間計算量: O(1) である.
+    /// - Space complexity: O(1).
+    ///   空間計算量: O(1) である.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use anmitsu::ds::bit_vector;
+    ///
+    /// let bv = bit_vector::BitVector::new(&[1, 0, 1]);
+    /// assert_eq!(1, bv.access(0));
+    /// assert_eq!(0, bv.access(1));
+    /// ```
+    pub fn access(&self, i: usize) -> u8 {
+        assert!(
+            i < self.len,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len,
+            i
+        );
+
+        ((self.bits[i / u64::BITS as usize] >> (i % u64::BITS as usize)) & 1) as u8
+    }
+
+    /// Returns the position of the `k`-th (0-indexed) 1.
+    /// `k` 番目 (0-indexed) の `1` の位置を返す.
+    ///
+    /// # Args
+    ///
+    /// - `k`: The rank of the 1 to find (0-indexed).
+    ///   `k`: 求める `1` の順位 (0-indexed) である.
+    ///
+    /// # Returns
+    ///
+    /// `Some(i)` such that `v[i] == 1` and `rank(i) == k`, or `None` if there are at most `k` 1s.
+    /// `v[i] == 1` かつ `rank(i) == k` を満たす `Some(i)` を返す. `1` が `k` 個以下なら `None` を返す.
+    ///
+    /// # Complexity
+    ///
+    /// - Time complexity: O(log B), where B is the number of blocks between two select hints,
+    ///   which is O(1) for evenly distributed bits.
+    ///   時間計算量: O(log B) である. ここで B は 2 つの select ヒント間のブロック数であり,
+    ///   ビットが均等に分布していれば O(1) である.
+    /// - Space complexity: O(1) for the query itself.
+    ///   空間計算量: クエリ自体は O(1) である.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use anmitsu::ds::bit_vector;
+    ///
+    /// let bv = bit_vector::BitVector::new(&[1, 0, 1, 1, 0, 1, 0, 0]);
+    /// assert_eq!(Some(0), bv.select1(0));
+    /// assert_eq!(Some(5), bv.select1(3));
+    /// assert_eq!(None, bv.select1(4));
+    /// ```
+    pub fn select1(&self, k: usize) -> Option<usize> {
+        if k >= self.rank(self.len) {
+            return None;
+        }
+
+        let block_index = self.find_block(k, &self.select1_hints, |b| self.rank_block(b));
+        let rest = k - self.rank_block(block_index);
+        Some(block_index * u64::BITS as usize + select_in_word(self.bits[block_index], rest))
+    }
+
+    /// Returns the position of the `k`-th (0-indexed) 0.
+    /// `k` 番目 (0-indexed) の `0` の位置を返す.
+    ///
+    /// # Args
+    ///
+    /// - `k`: The rank of the 0 to find (0-indexed).
+    ///   `k`: 求める `0` の順位 (0-indexed) である.
+    ///
+    /// # Returns
+    ///
+    /// `Some(i)` such that `v[i] == 0` and `rank0(i) == k`, or `None` if there are at most `k` 0s.
+    /// `v[i] == 0` かつ `rank0(i) == k` を満たす `Some(i)` を返す. `0` が `k` 個以下なら `None` を返す.
+    ///
+    /// # Complexity
+    ///
+    /// - Time complexity: O(log B), where B is the number of blocks between two select hints,
+    ///   which is O(1) for evenly distributed bits.
+    ///   時間計算量: O(log B) である. ここで B は 2 つの select ヒント間のブロック数であり,
+    ///   ビットが均等に分布していれば O(1) である.
+    /// - Space complexity: O(1) for the query itself.
+    ///   空間計算量: クエリ自体は O(1) である.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use anmitsu::ds::bit_vector;
+    ///
+    /// let bv = bit_vector::BitVector::new(&[1, 0, 1, 1, 0, 1, 0, 0]);
+    /// assert_eq!(Some(1), bv.select0(0));
+    /// assert_eq!(Some(7), bv.select0(3));
+    /// assert_eq!(None, bv.select0(4));
+    /// ```
+    pub fn select0(&self, k: usize) -> Option<usize> {
+        if k >= self.rank0(self.len) {
+            return None;
+        }
+
+        let rank0_block = |b: usize| b * u64::BITS as usize - self.rank_block(b);
+        let block_index = self.find_block(k, &self.select0_hints, rank0_block);
+        let rest = k - rank0_block(block_index);
+        Some(block_index * u64::BITS as usize + select_in_word(!self.bits[block_index], rest))
+    }
+
+    // Returns the last block whose preceding count, given by `count_before`, is at most `k`.
+    // The hints bound the search to the blocks between two sampled positions.
+    fn find_block<F>(&self, k: usize, hints: &[u32], count_before: F) -> usize
+    where
+        F: Fn(usize) -> usize,
+    {
+        let j = k / SELECT_SAMPLE;
+        let mut ok = hints[j] as usize;
+        let mut ng = match hints.get(j + 1) {
+            Some(&b) => b as usize + 1,
+            None => self.bits.len(),
+        };
+        while ng - ok > 1 {
+            let mid = ok + (ng - ok) / 2;
+            if count_before(mid) <= k {
+                ok = mid;
+            } else {
+                ng = mid;
+            }
         }
-        // Add the number of 1s from the partial current block, up to the r-th bit,
-        // using MASKS to isolate the relevant bits.
-        res += (self.bits[block_index] & MASKS[r % u64::BITS as usize]).count_ones();
-        res as usize
+        ok
     }
 
     /// Returns the length of the BitVector.
@@ -190,9 +532,9 @@ impl BitVector {
     /// # Complexity
     ///
     /// - Time complexity: O(1).
-    ///                    時間計算量: O(1) である.
+    ///   時間計算量: O(1) である.
     /// - Space complexity: O(1).
-    ///                     空間計算量: O(1) である.
+    ///   空間計算量: O(1) である.
     ///
     /// # Examples
     ///
@@ -217,9 +559,9 @@ impl BitVector {
     /// # Complexity
     ///
     /// - Time complexity: O(1).
-    ///                    時間計算量: O(1) である.
+    ///   時間計算量: O(1) である.
     /// - Space complexity: O(1).
-    ///                     空間計算量: O(1) である.
+    ///   空間計算量: O(1) である.
     ///
     /// # Examples
     ///
@@ -236,3 +578,12 @@ impl BitVector {
         self.len == 0
     }
 }
+
+// Returns the position of the `k`-th (0-indexed) set bit in `word`, which must exist.
+fn select_in_word(mut word: u64, k: usize) -> usize {
+    for _ in 0..k {
+        // Clear the lowest set bit.
+        word &= word - 1;
+    }
+    word.trailing_zeros() as usize
+}
@@ -0,0 +1,140 @@
+//! A module that defines `DisjointSparseTable`, which answers range queries on any semigroup in O(1).
+//! 任意の半群上の区間クエリに O(1) で答える `DisjointSparseTable` を定義するモジュールである.
+
+use crate::algebra::semi_group::SemiGroup;
+
+/// A disjoint sparse table that answers range folds of a static sequence on any semigroup in O(1).
+/// For each level `k`, the sequence is split into blocks of length `2^(k+1)`, and the table stores
+/// the folds from the middle of each block outward, so that any range is covered by two disjoint parts.
+///
+/// 任意の半群上で, 静的な列の区間の畳み込みに O(1) で答える disjoint sparse table である.
+/// 各段 `k` で列を長さ `2^(k+1)` のブロックに分け, 各ブロックの中央から外側への畳み込みを保持するため,
+/// 任意の区間は互いに素な二つの部分で覆われる.
+#[derive(Clone)]
+pub struct DisjointSparseTable<S>
+where
+    S: SemiGroup,
+{
+    v: Vec<S::S>,
+
+    // In `table[k]`, a block `[m - 2^k, m + 2^k)` with `m` a multiple of `2^(k+1)` plus `2^k` holds
+    // the fold of `[i, m)` at `i < m` and the fold of `[m, i]` at `i >= m`.
+    table: Vec<Vec<S::S>>,
+}
+
+impl<S> DisjointSparseTable<S>
+where
+    S: SemiGroup,
+    S::S: Clone,
+{
+    /// Creates a new `DisjointSparseTable` from a sequence.
+    /// 列から新しい `DisjointSparseTable` を生成する.
+    ///
+    /// # Args
+    /// - `v`: The sequence.
+    ///   列.
+    ///
+    /// # Returns
+    /// `DisjointSparseTable<S>`: A new instance of `DisjointSparseTable`.
+    ///                           `DisjointSparseTable` の新しいインスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the length of `v`.
+    ///   ここで N は `v` の長さである.
+    /// - Space complexity: O(N log N), where N is the length of `v`.
+    ///   ここで N は `v` の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::semi_group::AddSemiGroup, ds::disjoint_sparse_table::DisjointSparseTable};
+    /// let dst = DisjointSparseTable::<AddSemiGroup>::new(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(5, dst.len());
+    /// ```
+    pub fn new(v: &[S::S]) -> Self {
+        let n = v.len();
+        let mut table = Vec::new();
+        let mut half = 1;
+        while half < n {
+            let mut row = v.to_vec();
+            for mid in (half..n).step_by(2 * half) {
+                for i in (mid - half..mid - 1).rev() {
+                    row[i] = S::op(&v[i], &row[i + 1]);
+                }
+                for i in mid + 1..n.min(mid + half) {
+                    row[i] = S::op(&row[i - 1], &v[i]);
+                }
+            }
+            table.push(row);
+            half *= 2;
+        }
+        DisjointSparseTable {
+            v: v.to_vec(),
+            table,
+        }
+    }
+
+    /// Returns the length of the sequence.
+    /// 列の長さを返す.
+    ///
+    /// # Returns
+    /// `usize`: The length of the sequence.
+    ///          列の長さ.
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    /// Returns `true` if the sequence is empty.
+    /// 列が空の場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether the sequence is empty.
+    ///         列が空かどうか.
+    pub fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+
+    /// Folds the non-empty range `[l, r)`.
+    /// 空でない区間 `[l, r)` を畳み込む.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `S::S`: The fold `op(v[l], ..., v[r - 1])`.
+    ///         畳み込み `op(v[l], ..., v[r - 1])`.
+    ///
+    /// # Panics
+    /// Panics if `l >= r` or `r > self.len()`.
+    /// `l >= r` または `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::semi_group::AddSemiGroup, ds::disjoint_sparse_table::DisjointSparseTable};
+    /// let dst = DisjointSparseTable::<AddSemiGroup>::new(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(6, dst.fold(1, 4));
+    /// assert_eq!(14, dst.fold(0, 5));
+    /// ```
+    pub fn fold(&self, l: usize, r: usize) -> S::S {
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+        assert!(l < r, "l must be less than r (l: {}, r: {})", l, r);
+
+        let r = r - 1;
+        if l == r {
+            return self.v[l].clone();
+        }
+        // `l` and `r` first differ at bit `k`, so they lie on both sides of the middle of a level-`k` block.
+        let k = (l ^ r).ilog2() as usize;
+        S::op(&self.table[k][l], &self.table[k][r])
+    }
+}
@@ -0,0 +1,650 @@
+//! A module that defines Fenwick trees (binary indexed trees) over a commutative group.
+//! 可換群上の Fenwick tree (binary indexed tree) を定義するモジュールである.
+//!
+//! Every tree requires `G::op` to be commutative, as for `AddMonoid` and `XorMonoid`,
+//! and uses `G::inv` to turn prefix folds into range folds.
+//! 全ての木は `AddMonoid` や `XorMonoid` のように `G::op` が可換であることを要求し,
+//! `G::inv` を用いて接頭辞の畳み込みを区間の畳み込みに変換する.
+
+use crate::algebra::group::Group;
+
+fn check_index(len: usize, i: usize) {
+    if i >= len {
+        panic!(
+            "index out of bounds: the len is {} but the index is {}",
+            len, i
+        );
+    }
+}
+
+fn check_end(len: usize, r: usize) {
+    if r > len {
+        panic!(
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r, len
+        );
+    }
+}
+
+// Returns `x` combined with itself `k` times by doubling.
+fn times<G>(x: &G::S, mut k: usize) -> G::S
+where
+    G: Group,
+    G::S: Clone,
+{
+    let (mut result, mut base) = (G::id(), x.clone());
+    while k > 0 {
+        if k & 1 == 1 {
+            result = G::op(&result, &base);
+        }
+        base = G::op(&base, &base);
+        k >>= 1;
+    }
+    result
+}
+
+/// A Fenwick tree that supports point updates and range folds on a commutative group.
+/// 可換群上で一点更新と区間の畳み込みをサポートする Fenwick tree である.
+#[derive(Clone)]
+pub struct FenwickTree<G>
+where
+    G: Group,
+{
+    // `data[i - 1]` is the fold of `[i - (i & -i), i)` for `i` in `1..=len`.
+    data: Vec<G::S>,
+}
+
+impl<G> FenwickTree<G>
+where
+    G: Group,
+    G::S: Clone,
+{
+    /// Creates a new `FenwickTree` with `n` elements, all of which are the identity.
+    /// 全てが単位元である `n` 個の要素を持つ新しい `FenwickTree` を生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// `FenwickTree<G>`: A new instance of `FenwickTree`.
+    ///                   `FenwickTree` の新しいインスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree};
+    /// let ft = FenwickTree::<AddMonoid>::new(5);
+    /// assert_eq!(0, ft.sum(0, 5));
+    /// ```
+    pub fn new(n: usize) -> Self {
+        FenwickTree {
+            data: vec![G::id(); n],
+        }
+    }
+
+    /// Creates a new `FenwickTree` from a sequence.
+    /// 列から新しい `FenwickTree` を生成する.
+    ///
+    /// # Args
+    /// - `v`: The initial elements.
+    ///   初期の要素.
+    ///
+    /// # Returns
+    /// `FenwickTree<G>`: A new instance of `FenwickTree`.
+    ///                   `FenwickTree` の新しいインスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the length of `v`.
+    ///   ここで N は `v` の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree};
+    /// let ft = FenwickTree::<AddMonoid>::from_slice(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(6, ft.sum(1, 4));
+    /// ```
+    pub fn from_slice(v: &[G::S]) -> Self {
+        let mut data = v.to_vec();
+        for i in 1..=data.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= data.len() {
+                data[parent - 1] = G::op(&data[parent - 1], &data[i - 1]);
+            }
+        }
+        FenwickTree { data }
+    }
+
+    /// Returns the number of elements.
+    /// 要素数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements.
+    ///          要素数.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    /// 要素が存在しない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether there are no elements.
+    ///         要素が存在しないかどうか.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Combines `x` into the element at index `i`.
+    /// インデックス `i` の要素に `x` を結合する.
+    ///
+    /// # Args
+    /// - `i`: The index.
+    ///   インデックス.
+    /// - `x`: The value to combine.
+    ///   結合する値.
+    ///
+    /// # Panics
+    /// Panics if `i >= self.len()`.
+    /// `i >= self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree};
+    /// let mut ft = FenwickTree::<AddMonoid>::new(3);
+    /// ft.add(1, 5);
+    /// ft.add(1, -2);
+    /// assert_eq!(3, ft.sum(1, 2));
+    /// ```
+    pub fn add(&mut self, i: usize, x: G::S) {
+        check_index(self.len(), i);
+        let mut i = i + 1;
+        while i <= self.len() {
+            self.data[i - 1] = G::op(&self.data[i - 1], &x);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Folds the prefix `[0, r)`.
+    /// 接頭辞 `[0, r)` を畳み込む.
+    ///
+    /// # Args
+    /// - `r`: The end index of the prefix (exclusive).
+    ///   接頭辞の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `G::S`: The fold of `[0, r)`, which is the identity if `r == 0`.
+    ///         `[0, r)` の畳み込み. `r == 0` なら単位元.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::XorMonoid, ds::fenwick::FenwickTree};
+    /// let ft = FenwickTree::<XorMonoid>::from_slice(&[1, 2, 4]);
+    /// assert_eq!(3, ft.prefix(2));
+    /// ```
+    pub fn prefix(&self, r: usize) -> G::S {
+        check_end(self.len(), r);
+        let mut result = G::id();
+        let mut r = r;
+        while r > 0 {
+            result = G::op(&result, &self.data[r - 1]);
+            r &= r - 1;
+        }
+        result
+    }
+
+    /// Folds the range `[l, r)`.
+    /// 区間 `[l, r)` を畳み込む.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `G::S`: The fold of `[l, r)`, which is the identity if the range is empty.
+    ///         `[l, r)` の畳み込み. 区間が空なら単位元.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree};
+    /// let ft = FenwickTree::<AddMonoid>::from_slice(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(10, ft.sum(2, 5));
+    /// ```
+    pub fn sum(&self, l: usize, r: usize) -> G::S {
+        if l >= r {
+            return G::id();
+        }
+        check_end(self.len(), r);
+        G::op(&self.prefix(r), &G::inv(&self.prefix(l)))
+    }
+
+    /// Finds the shortest prefix whose fold is at least `target` by binary lifting.
+    /// ダブリングにより, 畳み込みが `target` 以上となる最短の接頭辞を求める.
+    ///
+    /// # Args
+    /// - `target`: The lower bound of the fold.
+    ///   畳み込みの下限.
+    ///
+    /// # Returns
+    /// `Option<usize>`: The minimum `r` such that `prefix(r) >= target`, or `None` if no such `r` exists.
+    ///                  `prefix(r) >= target` を満たす最小の `r`. 存在しなければ `None`.
+    ///
+    /// # Constraints
+    /// - `prefix` is non-decreasing, e.g. every element is non-negative for `AddMonoid`.
+    ///   `prefix` が単調非減少である. 例えば `AddMonoid` では全ての要素が非負である.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree};
+    /// let ft = FenwickTree::<AddMonoid>::from_slice(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(Some(0), ft.lower_bound(&0));
+    /// assert_eq!(Some(3), ft.lower_bound(&5));
+    /// assert_eq!(Some(5), ft.lower_bound(&14));
+    /// assert_eq!(None, ft.lower_bound(&15));
+    /// ```
+    pub fn lower_bound(&self, target: &G::S) -> Option<usize>
+    where
+        G::S: Ord,
+    {
+        if G::id() >= *target {
+            return Some(0);
+        }
+
+        // Find the longest prefix whose fold is still less than `target`.
+        let (mut pos, mut acc) = (0, G::id());
+        let mut step = if self.is_empty() {
+            0
+        } else {
+            1 << self.len().ilog2()
+        };
+        while step > 0 {
+            if pos + step <= self.len() {
+                let next = G::op(&acc, &self.data[pos + step - 1]);
+                if next < *target {
+                    pos += step;
+                    acc = next;
+                }
+            }
+            step >>= 1;
+        }
+        (pos < self.len()).then_some(pos + 1)
+    }
+}
+
+/// A pair of Fenwick trees that supports range updates and range folds on a commutative group.
+/// The fold of `[0, r)` is kept as `r * a(r) - b(r)`, where `a` and `b` are prefix folds of the two trees.
+///
+/// 可換群上で区間更新と区間の畳み込みをサポートする Fenwick tree の組である.
+/// `[0, r)` の畳み込みを, 二つの木の接頭辞の畳み込み `a`, `b` を用いて `r * a(r) - b(r)` として保持する.
+#[derive(Clone)]
+pub struct RangeAddFenwickTree<G>
+where
+    G: Group,
+    G::S: Clone,
+{
+    a: FenwickTree<G>,
+    b: FenwickTree<G>,
+}
+
+impl<G> RangeAddFenwickTree<G>
+where
+    G: Group,
+    G::S: Clone,
+{
+    /// Creates a new `RangeAddFenwickTree` with `n` elements, all of which are the identity.
+    /// 全てが単位元である `n` 個の要素を持つ新しい `RangeAddFenwickTree` を生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// `RangeAddFenwickTree<G>`: A new instance of `RangeAddFenwickTree`.
+    ///                           `RangeAddFenwickTree` の新しいインスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::RangeAddFenwickTree};
+    /// let ft = RangeAddFenwickTree::<AddMonoid>::new(5);
+    /// assert_eq!(0, ft.sum(0, 5));
+    /// ```
+    pub fn new(n: usize) -> Self {
+        RangeAddFenwickTree {
+            a: FenwickTree::new(n + 1),
+            b: FenwickTree::new(n + 1),
+        }
+    }
+
+    /// Returns the number of elements.
+    /// 要素数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements.
+    ///          要素数.
+    pub fn len(&self) -> usize {
+        self.a.len() - 1
+    }
+
+    /// Returns `true` if there are no elements.
+    /// 要素が存在しない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether there are no elements.
+    ///         要素が存在しないかどうか.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Combines `x` into every element in the range `[l, r)`.
+    /// 区間 `[l, r)` の全ての要素に `x` を結合する.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    /// - `x`: The value to combine.
+    ///   結合する値.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::RangeAddFenwickTree};
+    /// let mut ft = RangeAddFenwickTree::<AddMonoid>::new(5);
+    /// ft.add(1, 4, 2);
+    /// assert_eq!(4, ft.sum(0, 3));
+    /// ```
+    pub fn add(&mut self, l: usize, r: usize, x: G::S) {
+        if l >= r {
+            return;
+        }
+        check_end(self.len(), r);
+
+        let neg = G::inv(&x);
+        self.a.add(l, x.clone());
+        self.a.add(r, neg.clone());
+        self.b.add(l, times::<G>(&x, l));
+        self.b.add(r, times::<G>(&neg, r));
+    }
+
+    /// Folds the prefix `[0, r)`.
+    /// 接頭辞 `[0, r)` を畳み込む.
+    ///
+    /// # Args
+    /// - `r`: The end index of the prefix (exclusive).
+    ///   接頭辞の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `G::S`: The fold of `[0, r)`, which is the identity if `r == 0`.
+    ///         `[0, r)` の畳み込み. `r == 0` なら単位元.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    pub fn prefix(&self, r: usize) -> G::S {
+        check_end(self.len(), r);
+        let scaled = times::<G>(&self.a.prefix(r), r);
+        G::op(&scaled, &G::inv(&self.b.prefix(r)))
+    }
+
+    /// Folds the range `[l, r)`.
+    /// 区間 `[l, r)` を畳み込む.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `G::S`: The fold of `[l, r)`, which is the identity if the range is empty.
+    ///         `[l, r)` の畳み込み. 区間が空なら単位元.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::RangeAddFenwickTree};
+    /// let mut ft = RangeAddFenwickTree::<AddMonoid>::new(5);
+    /// ft.add(0, 5, 1);
+    /// ft.add(2, 4, 10);
+    /// assert_eq!(23, ft.sum(1, 4));
+    /// ```
+    pub fn sum(&self, l: usize, r: usize) -> G::S {
+        if l >= r {
+            return G::id();
+        }
+        check_end(self.len(), r);
+        G::op(&self.prefix(r), &G::inv(&self.prefix(l)))
+    }
+}
+
+/// A two-dimensional Fenwick tree that supports point updates and rectangle folds on a commutative group.
+/// 可換群上で一点更新と長方形の畳み込みをサポートする二次元 Fenwick tree である.
+#[derive(Clone)]
+pub struct FenwickTree2D<G>
+where
+    G: Group,
+{
+    h: usize,
+    w: usize,
+    // `data[(i - 1) * w + (j - 1)]` is the fold of the rectangle ending at `(i, j)` in the usual Fenwick layout.
+    data: Vec<G::S>,
+}
+
+impl<G> FenwickTree2D<G>
+where
+    G: Group,
+    G::S: Clone,
+{
+    /// Creates a new `FenwickTree2D` with `h` rows and `w` columns of the identity.
+    /// 全てが単位元である `h` 行 `w` 列の新しい `FenwickTree2D` を生成する.
+    ///
+    /// # Args
+    /// - `h`: The number of rows.
+    ///   行数.
+    /// - `w`: The number of columns.
+    ///   列数.
+    ///
+    /// # Returns
+    /// `FenwickTree2D<G>`: A new instance of `FenwickTree2D`.
+    ///                     `FenwickTree2D` の新しいインスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(HW), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree2D};
+    /// let ft = FenwickTree2D::<AddMonoid>::new(2, 3);
+    /// assert_eq!((2, 3), ft.shape());
+    /// ```
+    pub fn new(h: usize, w: usize) -> Self {
+        FenwickTree2D {
+            h,
+            w,
+            data: vec![G::id(); h * w],
+        }
+    }
+
+    /// Returns the numbers of rows and columns.
+    /// 行数と列数を返す.
+    ///
+    /// # Returns
+    /// `(usize, usize)`: The numbers of rows and columns.
+    ///                   行数と列数.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.h, self.w)
+    }
+
+    /// Combines `x` into the element at `(i, j)`.
+    /// `(i, j)` の要素に `x` を結合する.
+    ///
+    /// # Args
+    /// - `i`: The row.
+    ///   行.
+    /// - `j`: The column.
+    ///   列.
+    /// - `x`: The value to combine.
+    ///   結合する値.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is out of bounds.
+    /// `i` または `j` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log H log W), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree2D};
+    /// let mut ft = FenwickTree2D::<AddMonoid>::new(2, 3);
+    /// ft.add(1, 2, 5);
+    /// assert_eq!(5, ft.prefix(2, 3));
+    /// ```
+    pub fn add(&mut self, i: usize, j: usize, x: G::S) {
+        check_index(self.h, i);
+        check_index(self.w, j);
+        let mut a = i + 1;
+        while a <= self.h {
+            let mut b = j + 1;
+            while b <= self.w {
+                let k = (a - 1) * self.w + (b - 1);
+                self.data[k] = G::op(&self.data[k], &x);
+                b += b & b.wrapping_neg();
+            }
+            a += a & a.wrapping_neg();
+        }
+    }
+
+    /// Folds the rectangle of rows `[0, i)` and columns `[0, j)`.
+    /// 行 `[0, i)`, 列 `[0, j)` の長方形を畳み込む.
+    ///
+    /// # Args
+    /// - `i`: The end row (exclusive).
+    ///   終了行 (含まない).
+    /// - `j`: The end column (exclusive).
+    ///   終了列 (含まない).
+    ///
+    /// # Returns
+    /// `G::S`: The fold of the rectangle.
+    ///         長方形の畳み込み.
+    ///
+    /// # Panics
+    /// Panics if `i > h` or `j > w`.
+    /// `i > h` または `j > w` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log H log W), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    pub fn prefix(&self, i: usize, j: usize) -> G::S {
+        check_end(self.h, i);
+        check_end(self.w, j);
+        let mut result = G::id();
+        let mut a = i;
+        while a > 0 {
+            let mut b = j;
+            while b > 0 {
+                result = G::op(&result, &self.data[(a - 1) * self.w + (b - 1)]);
+                b &= b - 1;
+            }
+            a &= a - 1;
+        }
+        result
+    }
+
+    /// Folds the rectangle of rows `[i1, i2)` and columns `[j1, j2)`.
+    /// 行 `[i1, i2)`, 列 `[j1, j2)` の長方形を畳み込む.
+    ///
+    /// # Args
+    /// - `i1`: The start row (inclusive).
+    ///   開始行 (含む).
+    /// - `j1`: The start column (inclusive).
+    ///   開始列 (含む).
+    /// - `i2`: The end row (exclusive).
+    ///   終了行 (含まない).
+    /// - `j2`: The end column (exclusive).
+    ///   終了列 (含まない).
+    ///
+    /// # Returns
+    /// `G::S`: The fold of the rectangle, which is the identity if it is empty.
+    ///         長方形の畳み込み. 空なら単位元.
+    ///
+    /// # Panics
+    /// Panics if `i2 > h` or `j2 > w`.
+    /// `i2 > h` または `j2 > w` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log H log W), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::fenwick::FenwickTree2D};
+    /// let mut ft = FenwickTree2D::<AddMonoid>::new(3, 3);
+    /// ft.add(0, 0, 1);
+    /// ft.add(1, 1, 10);
+    /// ft.add(2, 2, 100);
+    /// assert_eq!(110, ft.sum(1, 1, 3, 3));
+    /// ```
+    pub fn sum(&self, i1: usize, j1: usize, i2: usize, j2: usize) -> G::S {
+        if i1 >= i2 || j1 >= j2 {
+            return G::id();
+        }
+        let outer = G::op(&self.prefix(i2, j2), &self.prefix(i1, j1));
+        let inner = G::op(&self.prefix(i1, j2), &self.prefix(i2, j1));
+        G::op(&outer, &G::inv(&inner))
+    }
+}
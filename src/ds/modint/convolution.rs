@@ -0,0 +1,378 @@
+//! Provides number theoretic transforms over NTT-friendly primes and convolution under an
+//! arbitrary modulus, reconstructed from three primes with Garner's algorithm.
+//! NTT に適した素数上の number theoretic transform と, 3 つの素数から Garner のアルゴリズムで
+//! 復元する任意の法での畳み込みを提供する.
+
+/// The first prime used by the three-prime convolution (45 * 2^{24} + 1).
+/// 3 素数による畳み込みで用いる 1 つ目の素数 (45 * 2^{24} + 1).
+const MOD1: u32 = 754974721;
+/// The second prime used by the three-prime convolution (5 * 2^{25} + 1).
+/// 3 素数による畳み込みで用いる 2 つ目の素数 (5 * 2^{25} + 1).
+const MOD2: u32 = 167772161;
+/// The third prime used by the three-prime convolution (7 * 2^{26} + 1).
+/// 3 素数による畳み込みで用いる 3 つ目の素数 (7 * 2^{26} + 1).
+const MOD3: u32 = 469762049;
+
+/// The product of the three primes. Exact results must stay below this value.
+/// 3 つの素数の積. 厳密な結果はこの値未満でなければならない.
+pub const MOD123: u128 = MOD1 as u128 * MOD2 as u128 * MOD3 as u128;
+
+/// The maximum supported transform length of the three-prime convolution.
+/// 3 素数による畳み込みがサポートする変換の最大長.
+pub const MAX_NTT_LEN: usize = 1 << 24;
+
+/// Twiddle factors of the radix-2 butterflies for an NTT-friendly prime.
+/// NTT に適した素数に対する radix-2 butterfly の回転因子である.
+pub(crate) struct NttTables {
+    rate: [u32; 30],
+    irate: [u32; 30],
+}
+
+impl NttTables {
+    /// Precomputes the twiddle factors for the prime `p` with the primitive root `g`.
+    /// 原始根 `g` を持つ素数 `p` に対する回転因子を事前計算する.
+    pub(crate) const fn new(p: u32, g: u32) -> Self {
+        let rank2 = (p - 1).trailing_zeros() as usize;
+        let mut root = [0_u32; 32];
+        let mut iroot = [0_u32; 32];
+        root[rank2] = pow_mod(g, (p - 1) >> rank2, p);
+        iroot[rank2] = pow_mod(root[rank2], p - 2, p);
+        let mut i = rank2;
+        while i > 0 {
+            root[i - 1] = mul_mod(root[i], root[i], p);
+            iroot[i - 1] = mul_mod(iroot[i], iroot[i], p);
+            i -= 1;
+        }
+
+        let mut rate = [0_u32; 30];
+        let mut irate = [0_u32; 30];
+        let (mut prod, mut iprod) = (1, 1);
+        let mut i = 0;
+        while i + 2 <= rank2 {
+            rate[i] = mul_mod(root[i + 2], prod, p);
+            irate[i] = mul_mod(iroot[i + 2], iprod, p);
+            prod = mul_mod(prod, iroot[i + 2], p);
+            iprod = mul_mod(iprod, root[i + 2], p);
+            i += 1;
+        }
+        NttTables { rate, irate }
+    }
+}
+
+const TABLES1: NttTables = NttTables::new(MOD1, 11);
+const TABLES2: NttTables = NttTables::new(MOD2, 3);
+const TABLES3: NttTables = NttTables::new(MOD3, 3);
+
+#[inline]
+const fn mul_mod(lhs: u32, rhs: u32, p: u32) -> u32 {
+    ((lhs as u64 * rhs as u64) % p as u64) as u32
+}
+
+const fn pow_mod(mut base: u32, mut n: u32, p: u32) -> u32 {
+    let mut res = 1 % p;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = mul_mod(res, base, p);
+        }
+        base = mul_mod(base, base, p);
+        n >>= 1;
+    }
+    res
+}
+
+#[inline]
+fn add_mod<const P: u32>(lhs: u32, rhs: u32) -> u32 {
+    let sum = lhs + rhs;
+    if sum >= P { sum - P } else { sum }
+}
+
+#[inline]
+fn sub_mod<const P: u32>(lhs: u32, rhs: u32) -> u32 {
+    if lhs >= rhs { lhs - rhs } else { lhs + P - rhs }
+}
+
+/// Runs the forward radix-2 butterflies over the prime `P`.
+/// 素数 `P` 上で順方向の radix-2 butterfly を実行する.
+///
+/// `a.len()` must be a power of two that `P` supports, and `rate` must be the forward table of `P`.
+/// `a.len()` は `P` が対応する 2 の冪でなければならず, `rate` は `P` の順方向の表でなければならない.
+pub(crate) fn butterfly<const P: u32>(a: &mut [u32], rate: &[u32]) {
+    let h = a.len().trailing_zeros();
+
+    for len in 0..h {
+        let p = 1 << (h - len - 1);
+        let mut rot = 1;
+        let step = 1 << (h - len);
+        for (s, chunk) in a.chunks_mut(step).enumerate() {
+            let ptr = chunk.as_mut_ptr();
+            for i in 0..p {
+                unsafe {
+                    let l = *ptr.add(i);
+                    let r = mul_mod(*ptr.add(i + p), rot, P);
+                    *ptr.add(i) = add_mod::<P>(l, r);
+                    *ptr.add(i + p) = sub_mod::<P>(l, r);
+                }
+            }
+            rot = mul_mod(rot, rate[s.trailing_ones() as usize], P);
+        }
+    }
+}
+
+/// Runs the inverse radix-2 butterflies over the prime `P` without scaling by `1 / a.len()`.
+/// `1 / a.len()` 倍のスケーリングを行わずに, 素数 `P` 上で逆方向の radix-2 butterfly を実行する.
+///
+/// `a.len()` must be a power of two that `P` supports, and `irate` must be the inverse table of `P`.
+/// `a.len()` は `P` が対応する 2 の冪でなければならず, `irate` は `P` の逆方向の表でなければならない.
+pub(crate) fn butterfly_inv<const P: u32>(a: &mut [u32], irate: &[u32]) {
+    let h = a.len().trailing_zeros();
+
+    for len in (1..=h).rev() {
+        let mut irot = 1;
+        let p = 1 << (h - len);
+        let step = 1 << (h - len + 1);
+        for (s, chunk) in a.chunks_mut(step).enumerate() {
+            let ptr = chunk.as_mut_ptr();
+            for i in 0..p {
+                unsafe {
+                    let l = *ptr.add(i);
+                    let r = *ptr.add(i + p);
+                    *ptr.add(i) = add_mod::<P>(l, r);
+                    *ptr.add(i + p) = mul_mod(sub_mod::<P>(l, r), irot, P);
+                }
+            }
+            irot = mul_mod(irot, irate[s.trailing_ones() as usize], P);
+        }
+    }
+}
+
+// Convolves two sequences already reduced modulo the prime P.
+fn convolution_prime<const P: u32>(a: &[u32], b: &[u32], tables: &NttTables) -> Vec<u32> {
+    let s = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= 32 {
+        let mut res = vec![0; s];
+        for (i, &ai) in a.iter().enumerate() {
+            for (j, &bj) in b.iter().enumerate() {
+                res[i + j] = add_mod::<P>(res[i + j], mul_mod(ai, bj, P));
+            }
+        }
+        return res;
+    }
+
+    let t = s.next_power_of_two();
+    let mut fa = a.to_vec();
+    fa.resize(t, 0);
+    let mut fb = b.to_vec();
+    fb.resize(t, 0);
+
+    butterfly::<P>(&mut fa, &tables.rate);
+    butterfly::<P>(&mut fb, &tables.rate);
+    fa.iter_mut()
+        .zip(fb.iter())
+        .for_each(|(x, y)| *x = mul_mod(*x, *y, P));
+    butterfly_inv::<P>(&mut fa, &tables.irate);
+    let t_inv = pow_mod(t as u32, P - 2, P);
+    fa.truncate(s);
+    fa.iter_mut().for_each(|x| *x = mul_mod(*x, t_inv, P));
+    fa
+}
+
+// Convolves over the three primes and reconstructs each coefficient in [0, MOD123) by Garner's algorithm.
+// `reduce(x, p)` must return `x mod p`.
+fn convolution_crt<T, F>(a: &[T], b: &[T], reduce: F) -> Vec<u128>
+where
+    F: Fn(&T, u32) -> u32,
+{
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let t = (a.len() + b.len() - 1).next_power_of_two();
+    assert!(
+        t <= MAX_NTT_LEN,
+        "Convolution length {} exceeds supported maximum {}",
+        t,
+        MAX_NTT_LEN
+    );
+
+    let residues = |v: &[T], p: u32| v.iter().map(|x| reduce(x, p)).collect::<Vec<_>>();
+    let c1 = convolution_prime::<MOD1>(&residues(a, MOD1), &residues(b, MOD1), &TABLES1);
+    let c2 = convolution_prime::<MOD2>(&residues(a, MOD2), &residues(b, MOD2), &TABLES2);
+    let c3 = convolution_prime::<MOD3>(&residues(a, MOD3), &residues(b, MOD3), &TABLES3);
+
+    let inv_m1_mod_m2 = pow_mod(MOD1 % MOD2, MOD2 - 2, MOD2);
+    let inv_m1m2_mod_m3 = pow_mod(mul_mod(MOD1 % MOD3, MOD2 % MOD3, MOD3), MOD3 - 2, MOD3);
+
+    c1.iter()
+        .zip(c2.iter())
+        .zip(c3.iter())
+        .map(|((&r1, &r2), &r3)| {
+            // x = r1 + m1 * t2 + m1 * m2 * t3 with t2 in [0, m2) and t3 in [0, m3).
+            let t2 = mul_mod(sub_mod::<MOD2>(r2, r1 % MOD2), inv_m1_mod_m2, MOD2);
+            let x12 = r1 as u64 + MOD1 as u64 * t2 as u64;
+            let t3 = mul_mod(
+                sub_mod::<MOD3>(r3, (x12 % MOD3 as u64) as u32),
+                inv_m1m2_mod_m3,
+                MOD3,
+            );
+            x12 as u128 + MOD1 as u128 * MOD2 as u128 * t3 as u128
+        })
+        .collect()
+}
+
+/// Computes the convolution of two sequences modulo an arbitrary `m`.
+/// 任意の `m` を法として 2 つの列の畳み込みを計算する.
+///
+/// # Args
+/// - `a`: The first input sequence. Elements need not be reduced modulo `m`.
+///   最初の入力列. 要素は `m` で還元されていなくてもよい.
+/// - `b`: The second input sequence. Elements need not be reduced modulo `m`.
+///   2 番目の入力列. 要素は `m` で還元されていなくてもよい.
+/// - `m`: The modulus.
+///   法.
+///
+/// # Returns
+/// `Vec<u32>`: Convolution result modulo `m`. Empty if either input is empty.
+///             法 `m` での畳み込み結果. いずれかの入力が空の場合は空となる.
+///
+/// # Constraints
+/// - `min(a.len(), b.len()) * (m - 1)^2` must be less than `MOD123` (about 5.9 * 10^{25}).
+///   This always holds for `m <= 2^{31}` and `min(a.len(), b.len()) <= 2^{23}`.
+/// - `min(a.len(), b.len()) * (m - 1)^2` は `MOD123` (約 5.9 * 10^{25}) 未満でなければならない.
+///   これは `m <= 2^{31}` かつ `min(a.len(), b.len()) <= 2^{23}` であれば常に成り立つ.
+///
+/// # Panics
+/// - Panics if `m == 0`.
+///   `m == 0` の場合にパニックする.
+/// - Panics if `(a.len() + b.len() - 1).next_power_of_two()` exceeds `MAX_NTT_LEN`.
+///   `(a.len() + b.len() - 1).next_power_of_two()` が `MAX_NTT_LEN` を超える場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O((N + M) log K) where N and M are input lengths and
+///   K is the next power of two of `N + M - 1`.
+///   時間計算量は O((N + M) log K) で, K は `N + M - 1`
+///   以上の最小の 2 の冪.
+/// - Space complexity: O(K).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::convolution::convolution_mod;
+///
+/// let a = vec![1_000_000_006, 2];
+/// let b = vec![3, 1_000_000_000];
+/// // (-1 + 2x)(3 - 7x) = -3 + 13x - 14x^2
+/// assert_eq!(
+///     vec![1_000_000_004, 13, 999_999_993],
+///     convolution_mod(&a, &b, 1_000_000_007)
+/// );
+/// ```
+pub fn convolution_mod(a: &[u32], b: &[u32], m: u32) -> Vec<u32> {
+    assert!(m >= 1, "The modulus must be at least 1");
+    convolution_crt(a, b, |&x, p| (x % m) % p)
+        .into_iter()
+        .map(|x| (x % m as u128) as u32)
+        .collect()
+}
+
+/// Computes the exact convolution of two sequences of `u64`.
+/// `u64` の 2 つの列の厳密な畳み込みを計算する.
+///
+/// # Args
+/// - `a`: The first input sequence.
+///   最初の入力列.
+/// - `b`: The second input sequence.
+///   2 番目の入力列.
+///
+/// # Returns
+/// `Vec<u64>`: The exact convolution result. Empty if either input is empty.
+///             厳密な畳み込み結果. いずれかの入力が空の場合は空となる.
+///
+/// # Constraints
+/// - Every coefficient of the exact result must fit in `u64`.
+///   A coefficient in `[2^{64}, MOD123)` is detected and panics, but one of at least `MOD123`
+///   is only known modulo `MOD123` and may go undetected. Detection is therefore guaranteed
+///   whenever `min(a.len(), b.len()) * max(a) * max(b) < MOD123`.
+/// - 厳密な結果の全ての係数は `u64` に収まらなければならない.
+///   `[2^{64}, MOD123)` の係数は検出されてパニックするが, `MOD123` 以上の係数は
+///   `MOD123` を法とした値しか分からないため検出されない場合がある. したがって
+///   `min(a.len(), b.len()) * max(a) * max(b) < MOD123` であれば必ず検出される.
+///
+/// # Panics
+/// - Panics if a reconstructed coefficient exceeds `u64::MAX`.
+///   復元された係数が `u64::MAX` を超える場合にパニックする.
+/// - Panics if `(a.len() + b.len() - 1).next_power_of_two()` exceeds `MAX_NTT_LEN`.
+///   `(a.len() + b.len() - 1).next_power_of_two()` が `MAX_NTT_LEN` を超える場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O((N + M) log K) where N and M are input lengths and
+///   K is the next power of two of `N + M - 1`.
+///   時間計算量は O((N + M) log K) で, K は `N + M - 1`
+///   以上の最小の 2 の冪.
+/// - Space complexity: O(K).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::convolution::convolution_u64;
+///
+/// let a = vec![1 << 40, 3];
+/// let b = vec![1 << 20, 5];
+/// assert_eq!(vec![1 << 60, (5 << 40) + (3 << 20), 15], convolution_u64(&a, &b));
+/// ```
+pub fn convolution_u64(a: &[u64], b: &[u64]) -> Vec<u64> {
+    convolution_crt(a, b, |&x, p| (x % p as u64) as u32)
+        .into_iter()
+        .map(|x| u64::try_from(x).expect("Convolution coefficient overflows u64"))
+        .collect()
+}
+
+/// Computes the exact convolution of two sequences of `i64` as `i128`.
+/// `i64` の 2 つの列の厳密な畳み込みを `i128` として計算する.
+///
+/// # Args
+/// - `a`: The first input sequence.
+///   最初の入力列.
+/// - `b`: The second input sequence.
+///   2 番目の入力列.
+///
+/// # Returns
+/// `Vec<i128>`: The exact convolution result. Empty if either input is empty.
+///              厳密な畳み込み結果. いずれかの入力が空の場合は空となる.
+///
+/// # Constraints
+/// - Every coefficient `c` of the exact result must satisfy `|c| < MOD123 / 2`.
+/// - 厳密な結果の全ての係数 `c` は `|c| < MOD123 / 2` を満たさなければならない.
+///
+/// # Panics
+/// - Panics if `(a.len() + b.len() - 1).next_power_of_two()` exceeds `MAX_NTT_LEN`.
+///   `(a.len() + b.len() - 1).next_power_of_two()` が `MAX_NTT_LEN` を超える場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O((N + M) log K) where N and M are input lengths and
+///   K is the next power of two of `N + M - 1`.
+///   時間計算量は O((N + M) log K) で, K は `N + M - 1`
+///   以上の最小の 2 の冪.
+/// - Space complexity: O(K).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::convolution::convolution_i128;
+///
+/// let a = vec![-(1 << 40), 3];
+/// let b = vec![1 << 40, -5];
+/// assert_eq!(
+///     vec![-(1_i128 << 80), (5_i128 << 40) + (3_i128 << 40), -15],
+///     convolution_i128(&a, &b)
+/// );
+/// ```
+pub fn convolution_i128(a: &[i64], b: &[i64]) -> Vec<i128> {
+    convolution_crt(a, b, |&x, p| x.rem_euclid(p as i64) as u32)
+        .into_iter()
+        // Values in the upper half of [0, MOD123) represent negative coefficients.
+        .map(|x| {
+            if x > MOD123 / 2 {
+                x as i128 - MOD123 as i128
+            } else {
+                x as i128
+            }
+        })
+        .collect()
+}
@@ -1,6 +1,11 @@
 //! Provides convolution modulo 998244353 using the number theoretic transform.
 //! 998244353 を法として number theoretic transform を用いた畳み込みを提供する.
 
+use super::{
+    convolution::{butterfly, butterfly_inv},
+    modint998244353::ModInt998244353,
+};
+
 /// The modulus used by this convolution implementation.
 /// この畳み込み実装で用いる法.
 pub const MOD: u32 = 998244353;
@@ -34,17 +39,16 @@ fn add_mod(lhs: u32, rhs: u32) -> u32 {
 }
 
 #[inline]
-fn sub_mod(lhs: u32, rhs: u32) -> u32 {
-    if lhs >= rhs {
-        lhs - rhs
-    } else {
-        lhs + MOD - rhs
-    }
+fn mul_mod(lhs: u32, rhs: u32) -> u32 {
+    ((lhs as u64 * rhs as u64) % MOD as u64) as u32
 }
 
+// Views a slice of `ModInt998244353` as its reduced `u32` values without copying.
 #[inline]
-fn mul_mod(lhs: u32, rhs: u32) -> u32 {
-    ((lhs as u64 * rhs as u64) % MOD as u64) as u32
+fn as_raw_mut(a: &mut [ModInt998244353]) -> &mut [u32] {
+    // SAFETY: `StaticModInt` is `#[repr(transparent)]` over a `u32` kept in `[0, MOD)`,
+    // and the transforms only ever write values in `[0, MOD)` back.
+    unsafe { std::slice::from_raw_parts_mut(a.as_mut_ptr() as *mut u32, a.len()) }
 }
 
 /// Performs an in-place number theoretic transform (NTT) on the given buffer.
@@ -52,7 +56,7 @@ fn mul_mod(lhs: u32, rhs: u32) -> u32 {
 ///
 /// # Args
 /// - `a`: A slice of coefficients whose length is a power of two.
-///        長さが 2 の冪となる係数列.
+///   長さが 2 の冪となる係数列.
 ///
 /// # Returns
 /// `()`: This function mutates `a` in place.
@@ -72,9 +76,9 @@ fn mul_mod(lhs: u32, rhs: u32) -> u32 {
 ///
 /// # Complexity
 /// - Time complexity: O(N log N), where N is `a.len()`.
-///                    ここで N は `a.len()` である.
+///   ここで N は `a.len()` である.
 /// - Space complexity: O(1).
-///                     追加の領域は O(1).
+///   追加の領域は O(1).
 ///
 /// # Examples
 /// ```rust
@@ -107,25 +111,7 @@ pub fn ntt(a: &mut [u32]) {
         MAX_NTT_LEN
     );
 
-    let h = n.trailing_zeros();
-
-    for len in 0..h {
-        let p = 1 << (h - len - 1);
-        let mut rot = 1;
-        let step = 1 << (h - len);
-        for (s, chunk) in a.chunks_mut(step).enumerate() {
-            let ptr = chunk.as_mut_ptr();
-            for i in 0..p {
-                unsafe {
-                    let l = *ptr.add(i);
-                    let r = mul_mod(*ptr.add(i + p), rot);
-                    *ptr.add(i) = add_mod(l, r);
-                    *ptr.add(i + p) = sub_mod(l, r);
-                }
-            }
-            rot = mul_mod(rot, NTT_RATE[s.trailing_ones() as usize]);
-        }
-    }
+    butterfly::<MOD>(a, &NTT_RATE);
 }
 
 /// Performs an in-place inverse number theoretic transform (INTT).
@@ -133,7 +119,7 @@ pub fn ntt(a: &mut [u32]) {
 ///
 /// # Args
 /// - `a`: A slice of coefficients whose length is a power of two.
-///        長さが 2 の冪となる係数列.
+///   長さが 2 の冪となる係数列.
 ///
 /// # Returns
 /// `()`: This function mutates `a` in place.
@@ -153,9 +139,9 @@ pub fn ntt(a: &mut [u32]) {
 ///
 /// # Complexity
 /// - Time complexity: O(N log N), where N is `a.len()`.
-///                    ここで N は `a.len()` である.
+///   ここで N は `a.len()` である.
 /// - Space complexity: O(1).
-///                     追加の領域は O(1).
+///   追加の領域は O(1).
 ///
 /// # Examples
 /// ```rust
@@ -188,25 +174,7 @@ pub fn intt(a: &mut [u32]) {
         MAX_NTT_LEN
     );
 
-    let h = n.trailing_zeros();
-
-    for len in (1..=h).rev() {
-        let mut irot = 1;
-        let p = 1 << (h - len);
-        let step = 1 << (h - len + 1);
-        for (s, chunk) in a.chunks_mut(step).enumerate() {
-            let ptr = chunk.as_mut_ptr();
-            for i in 0..p {
-                unsafe {
-                    let l = *ptr.add(i);
-                    let r = *ptr.add(i + p);
-                    *ptr.add(i) = add_mod(l, r);
-                    *ptr.add(i + p) = mul_mod(sub_mod(l, r), irot);
-                }
-            }
-            irot = mul_mod(irot, INTT_RATE[s.trailing_ones() as usize]);
-        }
-    }
+    butterfly_inv::<MOD>(a, &INTT_RATE);
 }
 
 /// Computes the convolution of two sequences modulo 998244353.
@@ -214,9 +182,9 @@ pub fn intt(a: &mut [u32]) {
 ///
 /// # Args
 /// - `a`: The first input sequence with coefficients reduced modulo `MOD`.
-///        法 `MOD` で還元された最初の入力列.
+///   法 `MOD` で還元された最初の入力列.
 /// - `b`: The second input sequence with coefficients reduced modulo `MOD`.
-///        法 `MOD` で還元された 2 番目の入力列.
+///   法 `MOD` で還元された 2 番目の入力列.
 ///
 /// # Returns
 /// `Vec<u32>`: Convolution result modulo `MOD`.
@@ -236,11 +204,11 @@ pub fn intt(a: &mut [u32]) {
 ///
 /// # Complexity
 /// - Time complexity: O((N + M) log K) where N and M are input lengths and
-///                    K is the next power of two of `N + M - 1`.
-///                    時間計算量は O((N + M) log K) で, K は `N + M - 1`
-///                    を超えない最小の 2 の冪.
+///   K is the next power of two of `N + M - 1`.
+///   時間計算量は O((N + M) log K) で, K は `N + M - 1`
+///   を超えない最小の 2 の冪.
 /// - Space complexity: O(K).
-///                     追加領域は O(K).
+///   追加領域は O(K).
 ///
 /// # Examples
 /// ```rust
@@ -297,3 +265,223 @@ pub fn convolution(a: &[u32], b: &[u32]) -> Vec<u32> {
     fa.truncate(s);
     fa
 }
+
+/// Performs an in-place number theoretic transform (NTT) on a slice of `ModInt998244353`.
+/// `ModInt998244353` のスライスに対してインプレースで number theoretic transform を実行する.
+///
+/// This is the typed counterpart of `ntt` and transforms the buffer without copying it.
+/// `ntt` の型付き版であり, 列をコピーせずに変換する.
+///
+/// # Args
+/// - `a`: A slice of coefficients whose length is a power of two.
+///   長さが 2 の冪となる係数列.
+///
+/// # Returns
+/// `()`: This function mutates `a` in place.
+///       この関数は `a` をインプレースで更新する.
+///
+/// # Constraints
+/// - `a.len()` must be a non-zero power of two.
+/// - `a.len()` must not exceed `MAX_NTT_LEN`.
+/// - `a.len()` は 0 ではない 2 の冪でなければならない.
+/// - `a.len()` は `MAX_NTT_LEN` を超えてはならない.
+///
+/// # Panics
+/// - Panics if the length constraint is violated.
+///   制約に違反した場合はパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N log N), where N is `a.len()`.
+///   ここで N は `a.len()` である.
+/// - Space complexity: O(1).
+///   追加の領域は O(1).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::{
+///     convolution998244353::{intt_modint, ntt_modint},
+///     modint998244353::ModInt998244353,
+/// };
+///
+/// let original: Vec<_> = [1, 2, 3, 4].into_iter().map(ModInt998244353::new).collect();
+/// let mut values = original.clone();
+/// ntt_modint(&mut values);
+/// intt_modint(&mut values);
+/// let inv_len = ModInt998244353::new(4).inv().unwrap();
+/// values.iter_mut().for_each(|v| *v *= inv_len);
+/// assert_eq!(original, values);
+/// ```
+pub fn ntt_modint(a: &mut [ModInt998244353]) {
+    ntt(as_raw_mut(a));
+}
+
+/// Performs an in-place inverse number theoretic transform (INTT) on a slice of `ModInt998244353`.
+/// `ModInt998244353` のスライスに対してインプレースで逆 number theoretic transform を実行する.
+///
+/// Like `intt`, the result is not divided by `a.len()`.
+/// `intt` と同様に, 結果は `a.len()` で割られない.
+///
+/// # Args
+/// - `a`: A slice of coefficients whose length is a power of two.
+///   長さが 2 の冪となる係数列.
+///
+/// # Returns
+/// `()`: This function mutates `a` in place.
+///       この関数は `a` をインプレースで更新する.
+///
+/// # Constraints
+/// - `a.len()` must be a non-zero power of two.
+/// - `a.len()` must not exceed `MAX_NTT_LEN`.
+/// - `a.len()` は 0 ではない 2 の冪でなければならない.
+/// - `a.len()` は `MAX_NTT_LEN` を超えてはならない.
+///
+/// # Panics
+/// - Panics if the length constraint is violated.
+///   制約に違反した場合はパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N log N), where N is `a.len()`.
+///   ここで N は `a.len()` である.
+/// - Space complexity: O(1).
+///   追加の領域は O(1).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::{
+///     convolution998244353::{intt_modint, ntt_modint},
+///     modint998244353::ModInt998244353,
+/// };
+///
+/// let original: Vec<_> = [5, 6, 7, 8].into_iter().map(ModInt998244353::new).collect();
+/// let mut values = original.clone();
+/// ntt_modint(&mut values);
+/// intt_modint(&mut values);
+/// let inv_len = ModInt998244353::new(4).inv().unwrap();
+/// values.iter_mut().for_each(|v| *v *= inv_len);
+/// assert_eq!(original, values);
+/// ```
+pub fn intt_modint(a: &mut [ModInt998244353]) {
+    intt(as_raw_mut(a));
+}
+
+/// Computes the convolution of two sequences of `ModInt998244353` naively.
+/// `ModInt998244353` の 2 つの列の畳み込みを愚直に計算する.
+///
+/// This is faster than the NTT when either sequence is short.
+/// いずれかの列が短い場合は NTT よりも高速である.
+///
+/// # Args
+/// - `a`: The first input sequence.
+///   最初の入力列.
+/// - `b`: The second input sequence.
+///   2 番目の入力列.
+///
+/// # Returns
+/// `Vec<ModInt998244353>`: The convolution, which is empty if either input is empty.
+///                         畳み込みの結果. いずれかの入力が空の場合は空となる.
+///
+/// # Complexity
+/// - Time complexity: O(NM), where N and M are the input lengths.
+///   ここで N, M は入力の長さである.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::{
+///     convolution998244353::convolution_naive_modint, modint998244353::ModInt998244353,
+/// };
+///
+/// let a: Vec<_> = [1, 2, 3].into_iter().map(ModInt998244353::new).collect();
+/// let b: Vec<_> = [4, 5, 6].into_iter().map(ModInt998244353::new).collect();
+/// let expected: Vec<_> = [4, 13, 28, 27, 18].into_iter().map(ModInt998244353::new).collect();
+/// assert_eq!(expected, convolution_naive_modint(&a, &b));
+/// ```
+pub fn convolution_naive_modint(
+    a: &[ModInt998244353],
+    b: &[ModInt998244353],
+) -> Vec<ModInt998244353> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut res = vec![ModInt998244353::new(0); a.len() + b.len() - 1];
+    for (i, &ai) in a.iter().enumerate() {
+        for (r, &bj) in res[i..].iter_mut().zip(b) {
+            *r += ai * bj;
+        }
+    }
+    res
+}
+
+/// Computes the convolution of two sequences of `ModInt998244353`.
+/// `ModInt998244353` の 2 つの列の畳み込みを計算する.
+///
+/// Falls back to `convolution_naive_modint` when either input is short.
+/// いずれかの入力が短い場合は `convolution_naive_modint` を用いる.
+///
+/// # Args
+/// - `a`: The first input sequence.
+///   最初の入力列.
+/// - `b`: The second input sequence.
+///   2 番目の入力列.
+///
+/// # Returns
+/// `Vec<ModInt998244353>`: The convolution, which is empty if either input is empty.
+///                         畳み込みの結果. いずれかの入力が空の場合は空となる.
+///
+/// # Constraints
+/// - The total length `a.len() + b.len() - 1` must not exceed `MAX_NTT_LEN`.
+/// - `a.len() + b.len() - 1` は `MAX_NTT_LEN` を超えてはならない.
+///
+/// # Panics
+/// - Panics if the length constraint is violated.
+///   長さ制約に違反した場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O((N + M) log K) where N and M are input lengths and
+///   K is the next power of two of `N + M - 1`.
+///   時間計算量は O((N + M) log K) で, K は `N + M - 1`
+///   を超えない最小の 2 の冪.
+/// - Space complexity: O(K).
+///   追加領域は O(K).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::ds::modint::{
+///     convolution998244353::convolution_modint, modint998244353::ModInt998244353,
+/// };
+///
+/// let a: Vec<_> = [1, 2, 3].into_iter().map(ModInt998244353::new).collect();
+/// let b: Vec<_> = [4, 5, 6].into_iter().map(ModInt998244353::new).collect();
+/// let expected: Vec<_> = [4, 13, 28, 27, 18].into_iter().map(ModInt998244353::new).collect();
+/// assert_eq!(expected, convolution_modint(&a, &b));
+/// ```
+pub fn convolution_modint(a: &[ModInt998244353], b: &[ModInt998244353]) -> Vec<ModInt998244353> {
+    if a.len().min(b.len()) <= 32 {
+        return convolution_naive_modint(a, b);
+    }
+
+    let s = a.len() + b.len() - 1;
+    let t = s.next_power_of_two();
+    assert!(
+        t <= MAX_NTT_LEN,
+        "Convolution length {} exceeds supported maximum {}",
+        t,
+        MAX_NTT_LEN
+    );
+
+    let mut fa = Vec::with_capacity(t);
+    fa.extend_from_slice(a);
+    fa.resize(t, ModInt998244353::new(0));
+    let mut fb = Vec::with_capacity(t);
+    fb.extend_from_slice(b);
+    fb.resize(t, ModInt998244353::new(0));
+
+    ntt_modint(&mut fa);
+    ntt_modint(&mut fb);
+    fa.iter_mut().zip(fb.iter()).for_each(|(x, &y)| *x *= y);
+    intt_modint(&mut fa);
+    let t_inv = ModInt998244353::new_raw(INVS[t.trailing_zeros() as usize]);
+    fa.truncate(s);
+    fa.iter_mut().for_each(|x| *x *= t_inv);
+    fa
+}
@@ -0,0 +1,1170 @@
+//! This module provides a modular integer implementation whose modulus is set at runtime.
+//! このモジュールは, 実行時に法を設定するモジュラー整数実装を提供する.
+
+use std::fmt;
+use std::hint;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering, fence};
+
+/// The number of distinct ids available for `DynamicModInt`.
+/// `DynamicModInt` で利用できる id の個数.
+pub const DYNAMIC_MODINT_ID_LIMIT: usize = 16;
+
+/// The modulus every id starts with.
+/// 各 id の初期状態の法.
+const DEFAULT_MOD: u32 = 998244353;
+
+/// A context for fast modular multiplication by Barrett reduction.
+/// Barrett reduction による高速な剰余乗算のためのコンテキストである.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Barrett {
+    m: u32,
+    // ceil(2^{64} / m), kept as `u64` by wrapping.
+    im: u64,
+}
+
+impl Barrett {
+    /// Creates a new `Barrett` context for the modulus `m`.
+    /// 法 `m` に対する新しい `Barrett` コンテキストを生成する.
+    ///
+    /// # Args
+    /// - `m`: The modulus.
+    ///   法.
+    ///
+    /// # Returns
+    /// `Barrett`: A new context.
+    ///            新しいコンテキスト.
+    ///
+    /// # Panics
+    /// Panics if `m` is not in `[1, 2^{31})`.
+    /// `m` が `[1, 2^{31})` の範囲にない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::Barrett;
+    /// let bt = Barrett::new(1_000_000_007);
+    /// assert_eq!(1_000_000_007, bt.modulus());
+    /// assert_eq!(49, bt.mul(1_000_000_000, 1_000_000_000)); // (-7)^2
+    /// ```
+    pub const fn new(m: u32) -> Self {
+        assert!(1 <= m && m < (1 << 31), "The modulus must be in [1, 2^31)");
+        Barrett {
+            m,
+            im: (u64::MAX / m as u64).wrapping_add(1),
+        }
+    }
+
+    /// Returns the modulus of this context.
+    /// このコンテキストの法を返す.
+    ///
+    /// # Returns
+    /// `u32`: The modulus.
+    ///        法.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn modulus(&self) -> u32 {
+        self.m
+    }
+
+    /// Computes `a * b mod m`.
+    /// `a * b mod m` を計算する.
+    ///
+    /// # Args
+    /// - `a`: The first operand, which must be less than `m`.
+    ///   `m` 未満でなければならない第一オペランド.
+    /// - `b`: The second operand, which must be less than `m`.
+    ///   `m` 未満でなければならない第二オペランド.
+    ///
+    /// # Returns
+    /// `u32`: The product modulo `m`.
+    ///        `m` を法とした積.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        debug_assert!(a < self.m && b < self.m);
+        let z = a as u64 * b as u64;
+        // x is either floor(z / m) or floor(z / m) + 1.
+        let x = ((z as u128 * self.im as u128) >> 64) as u64;
+        let v = z.wrapping_sub(x.wrapping_mul(self.m as u64)) as u32;
+        // Correct the underflow caused by an overestimated quotient.
+        if self.m <= v {
+            v.wrapping_add(self.m)
+        } else {
+            v
+        }
+    }
+}
+
+// The Barrett context of a single id, stored in atomics so that it can live in a `static`.
+// `m` and `im` are published as a pair under a sequence lock: `seq` is odd while a store is in
+// progress, and a load retries until it observes the same even `seq` before and after reading,
+// so a racing `set_modulus` can never pair the new `m` with the old `im`.
+struct AtomicBarrett {
+    seq: AtomicU32,
+    m: AtomicU32,
+    im: AtomicU64,
+}
+
+impl AtomicBarrett {
+    const fn new(bt: Barrett) -> Self {
+        AtomicBarrett {
+            seq: AtomicU32::new(0),
+            m: AtomicU32::new(bt.m),
+            im: AtomicU64::new(bt.im),
+        }
+    }
+
+    fn load(&self) -> Barrett {
+        loop {
+            let s = self.seq.load(Ordering::Acquire);
+            if s.is_multiple_of(2) {
+                let bt = Barrett {
+                    m: self.m.load(Ordering::Relaxed),
+                    im: self.im.load(Ordering::Relaxed),
+                };
+                fence(Ordering::Acquire);
+                if self.seq.load(Ordering::Relaxed) == s {
+                    return bt;
+                }
+            }
+            hint::spin_loop();
+        }
+    }
+
+    fn store(&self, bt: Barrett) {
+        // Take the lock by making `seq` odd, which also serializes concurrent stores.
+        let s = loop {
+            let s = self.seq.load(Ordering::Relaxed);
+            if s.is_multiple_of(2)
+                && self
+                    .seq
+                    .compare_exchange_weak(
+                        s,
+                        s.wrapping_add(1),
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                break s;
+            }
+            hint::spin_loop();
+        };
+        self.m.store(bt.m, Ordering::Relaxed);
+        self.im.store(bt.im, Ordering::Relaxed);
+        self.seq.store(s.wrapping_add(2), Ordering::Release);
+    }
+}
+
+static BARRETTS: [AtomicBarrett; DYNAMIC_MODINT_ID_LIMIT] =
+    [const { AtomicBarrett::new(Barrett::new(DEFAULT_MOD)) }; DYNAMIC_MODINT_ID_LIMIT];
+
+/// Represents a modular integer whose modulus is chosen at runtime.
+/// Each `ID` has its own global modulus (998244353 by default) set by `set_modulus`,
+/// so several moduli can be used at the same time by giving them different ids.
+/// The modulus must be in `[1, 2^{31})`, and `ID` must be less than `DYNAMIC_MODINT_ID_LIMIT`.
+/// 実行時に法を選択するモジュラー整数を表現する.
+/// 各 `ID` は `set_modulus` で設定される固有の大域的な法 (既定値は 998244353) を持つため,
+/// 異なる id を与えることで複数の法を同時に使用できる.
+/// 法は `[1, 2^{31})` の範囲になければならず, `ID` は `DYNAMIC_MODINT_ID_LIMIT` 未満でなければならない.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DynamicModInt<const ID: usize> {
+    val: u32,
+}
+
+impl<const ID: usize> DynamicModInt<ID> {
+    const SLOT: &'static AtomicBarrett = {
+        assert!(
+            ID < DYNAMIC_MODINT_ID_LIMIT,
+            "ID must be less than DYNAMIC_MODINT_ID_LIMIT"
+        );
+        &BARRETTS[ID]
+    };
+
+    // Returns the Barrett context of the current modulus.
+    fn barrett() -> Barrett {
+        Self::SLOT.load()
+    }
+
+    /// Sets the modulus shared by every `DynamicModInt<ID>`.
+    /// Values created before the change keep their raw value and must not be mixed with new ones.
+    /// It is safe to call concurrently with arithmetic on the same `ID`: every operation observes
+    /// either the old or the new modulus as a whole, never a mix of the two.
+    /// 全ての `DynamicModInt<ID>` で共有される法を設定する.
+    /// 変更前に生成された値は生の値を保持するため, 変更後の値と混ぜて使用してはならない.
+    /// 同じ `ID` の演算と並行に呼び出しても安全であり, 各演算は変更前と変更後のどちらかの法を
+    /// 丸ごと観測し, 両者が混ざることはない.
+    ///
+    /// # Args
+    /// - `m`: The new modulus.
+    ///   新しい法.
+    ///
+    /// # Panics
+    /// Panics if `m` is not in `[1, 2^{31})`.
+    /// `m` が `[1, 2^{31})` の範囲にない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<3>;
+    /// Mint::set_modulus(1_000_000_007);
+    /// assert_eq!(1_000_000_007, Mint::modulus());
+    /// assert_eq!(3, (Mint::new(1_000_000_000) + Mint::new(10)).val());
+    /// ```
+    pub fn set_modulus(m: u32) {
+        Self::SLOT.store(Barrett::new(m));
+    }
+
+    /// Returns the current modulus of `DynamicModInt<ID>`.
+    /// `DynamicModInt<ID>` の現在の法を返す.
+    ///
+    /// # Returns
+    /// `u32`: The modulus.
+    ///        法.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// assert_eq!(998244353, DynamicModInt::<0>::modulus());
+    /// ```
+    pub fn modulus() -> u32 {
+        Self::barrett().m
+    }
+
+    /// Creates a new `DynamicModInt` instance from a `u64` value.
+    /// `u64` 値から新しい `DynamicModInt` インスタンスを生成する.
+    ///
+    /// # Args
+    /// - `n`: The `u64` value to be converted to a modular integer.
+    ///   モジュラー整数に変換する `u64` 値.
+    ///
+    /// # Returns
+    /// `Self`: A new `DynamicModInt` instance with its value reduced modulo `MOD`.
+    ///         値が `MOD` で還元された新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let m = Mint::new(1_000_000_000);
+    /// assert_eq!(1755647, m.val());
+    /// ```
+    pub fn new(n: u64) -> Self {
+        DynamicModInt {
+            val: (n % Self::modulus() as u64) as u32,
+        }
+    }
+
+    /// Creates a new `DynamicModInt` instance from a raw `u32` value.
+    /// 生の `u32` 値から新しい `DynamicModInt` インスタンスを生成する.
+    ///
+    /// # Args
+    /// - `n`: The `u32` value, which must be less than `MOD`.
+    ///   `MOD` 未満でなければならない `u32` 値.
+    ///
+    /// # Returns
+    /// `Self`: A new `DynamicModInt` instance.
+    ///         新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than or equal to `MOD`.
+    /// `n` が `MOD` 以上の場合にパニックする.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let m = Mint::new_raw(100);
+    /// assert_eq!(100, m.val());
+    /// // Mint::new_raw(998244353); // This would panic
+    /// ```
+    pub fn new_raw(n: u32) -> Self {
+        assert!(
+            n < Self::modulus(),
+            "Raw value {} must be less than MOD {}",
+            n,
+            Self::modulus()
+        );
+        DynamicModInt { val: n }
+    }
+
+    /// Returns the underlying `u32` value of the modular integer.
+    /// モジュラー整数の基となる `u32` 値を返す.
+    ///
+    /// # Returns
+    /// `u32`: The raw `u32` value of the modular integer.
+    ///         モジュラー整数の生の `u32` 値.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let m = Mint::new(123);
+    /// assert_eq!(123, m.val());
+    /// ```
+    pub fn val(&self) -> u32 {
+        self.val
+    }
+
+    /// Computes the modular multiplicative inverse of the modular integer.
+    /// モジュラー整数のモジュラー乗法逆元を計算する.
+    ///
+    /// This method uses the extended Euclidean algorithm, so it also works for a composite modulus.
+    /// このメソッドは拡張ユークリッドの互除法を使用するため, 合成数の法に対しても動作する.
+    ///
+    /// # Returns
+    /// `Option<Self>`: Returns `Some(inverse)` if `gcd(self.val, MOD) == 1`,
+    ///                 `None` otherwise (as inverse does not exist).
+    ///                 `gcd(self.val, MOD) == 1` の場合に `Some(inverse)` を返し,
+    ///                 そうでない場合に `None` を返す (逆元が存在しないため).
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log MOD).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let m = Mint::new(2);
+    /// let inv_m = m.inv().unwrap();
+    /// assert_eq!(1, (m * inv_m).val());
+    ///
+    /// let zero = Mint::new(0);
+    /// assert!(zero.inv().is_none());
+    ///
+    /// // 4 and 6 are not coprime, so 4 has no inverse modulo 6.
+    /// DynamicModInt::<1>::set_modulus(6);
+    /// assert!(DynamicModInt::<1>::new(4).inv().is_none());
+    /// assert_eq!(5, DynamicModInt::<1>::new(5).inv().unwrap().val());
+    /// ```
+    pub fn inv(&self) -> Option<Self> {
+        // Maintain `a ≡ x * self.val` and `b ≡ y * self.val` (mod MOD) while running Euclid on (a, b).
+        let (mut a, mut b) = (self.val as i64, Self::modulus() as i64);
+        let (mut x, mut y) = (1_i64, 0_i64);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        // Here `a` is gcd(self.val, MOD).
+        if a != 1 {
+            None
+        } else {
+            Some(Self::new(x.rem_euclid(Self::modulus() as i64) as u64))
+        }
+    }
+
+    // Returns the inverse used as a divisor, panicking if it does not exist.
+    fn inv_for_div(&self) -> Self {
+        self.inv().unwrap_or_else(|| {
+            if self.val == 0 {
+                panic!(
+                    "Division by zero is not allowed for ModInt{}",
+                    Self::modulus()
+                )
+            } else {
+                panic!(
+                    "Division by {} is not allowed for ModInt{} since it is not invertible",
+                    self.val,
+                    Self::modulus()
+                )
+            }
+        })
+    }
+
+    /// Computes `self` raised to the power of `n`.
+    /// `self` の `n` 乗を計算する.
+    ///
+    /// # Args
+    /// - `n`: The non-negative exponent.
+    ///   冪指数.
+    ///
+    /// # Returns
+    /// `Self`: The result of `self` raised to the power of `n`.
+    ///         `self` を `n` 乗した結果.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log n), where n is the exponent.
+    ///   ここで n は冪指数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let base = Mint::new(3);
+    /// let result = base.pow(4);
+    /// assert_eq!(81, result.val());
+    /// ```
+    pub fn pow(&self, mut n: usize) -> Self {
+        let mut res = Self::new(1);
+        let mut base = *self;
+
+        // This is a standard binary exponentiation (exponentiation by squaring).
+        while n > 0 {
+            // If the current bit of n is 1, multiply the result by the base.
+            if n % 2 == 1 {
+                res *= base;
+            }
+            // Square the base for the next bit.
+            base *= base;
+            n /= 2;
+        }
+        res
+    }
+}
+
+/// Allows conversion from `u32` to `DynamicModInt`.
+/// `u32` から `DynamicModInt` への変換を可能にする.
+impl<const ID: usize> From<u32> for DynamicModInt<ID> {
+    /// Creates a `DynamicModInt` instance from a `u32` value.
+    /// `u32` の値から `DynamicModInt` インスタンスを生成する.
+    ///
+    /// # Args
+    /// - `num`: The `u32` value to convert.
+    ///   変換する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance equivalent to `num` modulo the current modulus.
+    /// `num` を現在の法で割った余りと等価な, 新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input value.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let val: u32 = 1_000_000_007;
+    /// let m: Mint = val.into();
+    /// assert_eq!(1755654, m.val()); // 1_000_000_007 % 998244353
+    /// ```
+    fn from(num: u32) -> Self {
+        DynamicModInt::new(num as u64)
+    }
+}
+
+/// Allows conversion from `i32` to `DynamicModInt`.
+/// `i32` から `DynamicModInt` への変換を可能にする.
+impl<const ID: usize> From<i32> for DynamicModInt<ID> {
+    /// Creates a `DynamicModInt` instance from an `i32` value, handling negative numbers correctly.
+    /// `i32` の値から `DynamicModInt` インスタンスを生成する. 負数も正しく扱う.
+    ///
+    /// # Args
+    /// - `num`: The `i32` value to convert.
+    ///   変換する `i32` の値.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance. Negative inputs are converted to a positive equivalent in modular arithmetic.
+    /// 新しい `DynamicModInt` インスタンス. 負の入力は, 法演算における正の等価値に変換される.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input value.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let m_pos: Mint = 10i32.into();
+    /// assert_eq!(10, m_pos.val());
+    ///
+    /// let m_neg: Mint = (-10i32).into();
+    /// assert_eq!(998244353 - 10, m_neg.val());
+    /// ```
+    fn from(num: i32) -> Self {
+        // Ensure non-negative value for modular arithmetic.
+        DynamicModInt::new((num as i64).rem_euclid(Self::modulus() as i64) as u64)
+    }
+}
+
+/// Implements the addition operation (`+`) for two `DynamicModInt` instances.
+/// 2つの `DynamicModInt` インスタンスに対する加算演算 (`+`) を実装する.
+impl<const ID: usize> Add for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Adds two `DynamicModInt` instances.
+    /// 2つの `DynamicModInt` インスタンスを加算する.
+    ///
+    /// # Args
+    /// - `self`: The left-hand side operand.
+    ///   左辺のオペランド.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the sum.
+    /// 和を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(998244350);
+    /// let b = Mint::new(10);
+    /// assert_eq!(Mint::new(7), a + b);
+    /// ```
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Implements the addition operation (`+`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する加算演算 (`+`) を実装する.
+impl<const ID: usize> Add<u32> for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Adds a `u32` value to a `DynamicModInt` instance.
+    /// `DynamicModInt` インスタンスに `u32` の値を加算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance.
+    ///   `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` value to add.
+    ///   加算する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the sum.
+    /// 和を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(998244350);
+    /// let b: u32 = 10;
+    /// assert_eq!(Mint::new(7), a + b);
+    /// ```
+    fn add(mut self, rhs: u32) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Implements the addition assignment operation (`+=`) for `DynamicModInt`.
+/// `DynamicModInt` に対する加算代入演算 (`+=`) を実装する.
+impl<const ID: usize> AddAssign for DynamicModInt<ID> {
+    /// Adds another `DynamicModInt` instance to `self`.
+    /// 別の `DynamicModInt` インスタンスを `self` に加算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(998244350);
+    /// let b = Mint::new(10);
+    /// a += b;
+    /// assert_eq!(Mint::new(7), a);
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        let m = Self::modulus();
+        self.val += rhs.val;
+        if self.val >= m {
+            self.val -= m;
+        }
+    }
+}
+
+/// Implements the addition assignment operation (`+=`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する加算代入演算 (`+=`) を実装する.
+impl<const ID: usize> AddAssign<u32> for DynamicModInt<ID> {
+    /// Adds a `u32` value to `self`.
+    /// `u32` の値を `self` に加算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` value to add.
+    ///   加算する `u32` の値.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(998244350);
+    /// a += 10u32;
+    /// assert_eq!(Mint::new(7), a);
+    /// ```
+    fn add_assign(&mut self, rhs: u32) {
+        *self += Self::new_raw(rhs % Self::modulus());
+    }
+}
+
+/// Implements the subtraction operation (`-`) for two `DynamicModInt` instances.
+/// 2つの `DynamicModInt` インスタンスに対する減算演算 (`-`) を実装する.
+impl<const ID: usize> Sub for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Subtracts one `DynamicModInt` instance from another.
+    /// ある `DynamicModInt` インスタンスから別のインスタンスを減算する.
+    ///
+    /// # Args
+    /// - `self`: The left-hand side operand (minuend).
+    ///   左辺のオペランド (被減数).
+    /// - `rhs`: The right-hand side operand (subtrahend).
+    ///   右辺のオペランド (減数).
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the difference.
+    /// 差を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(10);
+    /// let b = Mint::new(20);
+    /// assert_eq!(Mint::new(998244343), a - b);
+    /// ```
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Implements the subtraction operation (`-`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する減算演算 (`-`) を実装する.
+impl<const ID: usize> Sub<u32> for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Subtracts a `u32` value from a `DynamicModInt` instance.
+    /// `DynamicModInt` インスタンスから `u32` の値を減算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance.
+    ///   `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` value to subtract.
+    ///   減算する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the difference.
+    /// 差を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(10);
+    /// let b: u32 = 20;
+    /// assert_eq!(Mint::new(998244343), a - b);
+    /// ```
+    fn sub(mut self, rhs: u32) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Implements the subtraction assignment operation (`-=`) for `DynamicModInt`.
+/// `DynamicModInt` に対する減算代入演算 (`-=`) を実装する.
+impl<const ID: usize> SubAssign for DynamicModInt<ID> {
+    /// Subtracts another `DynamicModInt` instance from `self`.
+    /// 別の `DynamicModInt` インスタンスを `self` から減算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(10);
+    /// let b = Mint::new(20);
+    /// a -= b;
+    /// assert_eq!(Mint::new(998244343), a);
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.val >= rhs.val {
+            self.val -= rhs.val;
+        } else {
+            self.val += Self::modulus() - rhs.val;
+        }
+    }
+}
+
+/// Implements the subtraction assignment operation (`-=`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する減算代入演算 (`-=`) を実装する.
+impl<const ID: usize> SubAssign<u32> for DynamicModInt<ID> {
+    /// Subtracts a `u32` value from `self`.
+    /// `u32` の値を `self` から減算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` value to subtract.
+    ///   減算する `u32` の値.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(10);
+    /// a -= 20u32;
+    /// assert_eq!(Mint::new(998244343), a);
+    /// ```
+    fn sub_assign(&mut self, rhs: u32) {
+        *self -= Self::new_raw(rhs % Self::modulus());
+    }
+}
+
+/// Implements the multiplication operation (`*`) for two `DynamicModInt` instances.
+/// 2つの `DynamicModInt` インスタンスに対する乗算演算 (`*`) を実装する.
+impl<const ID: usize> Mul for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Multiplies two `DynamicModInt` instances.
+    /// 2つの `DynamicModInt` インスタンスを乗算する.
+    ///
+    /// # Args
+    /// - `self`: The left-hand side operand.
+    ///   左辺のオペランド.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the product.
+    /// 積を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(100_000);
+    /// let b = Mint::new(100_000);
+    /// assert_eq!(Mint::new(17556470), a * b);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Barrett reduction replaces the 64-bit `%` of the product.
+        Self::new_raw(Self::barrett().mul(self.val, rhs.val))
+    }
+}
+
+/// Implements the multiplication operation (`*`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する乗算演算 (`*`) を実装する.
+impl<const ID: usize> Mul<u32> for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Multiplies a `DynamicModInt` instance by a `u32` value.
+    /// `DynamicModInt` インスタンスに `u32` の値を乗算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance.
+    ///   `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` value to multiply by.
+    ///   乗算する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the product.
+    /// 積を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(100_000);
+    /// let b: u32 = 100_000;
+    /// assert_eq!(Mint::new(17556470), a * b);
+    /// ```
+    fn mul(self, rhs: u32) -> Self::Output {
+        self * Self::from(rhs)
+    }
+}
+
+/// Implements the multiplication assignment operation (`*=`) for `DynamicModInt`.
+/// `DynamicModInt` に対する乗算代入演算 (`*=`) を実装する.
+impl<const ID: usize> MulAssign for DynamicModInt<ID> {
+    /// Multiplies `self` by another `DynamicModInt` instance.
+    /// `self` に別の `DynamicModInt` インスタンスを乗算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(100_000);
+    /// let b = Mint::new(100_000);
+    /// a *= b;
+    /// assert_eq!(Mint::new(17556470), a);
+    /// ```
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements the multiplication assignment operation (`*=`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する乗算代入演算 (`*=`) を実装する.
+impl<const ID: usize> MulAssign<u32> for DynamicModInt<ID> {
+    /// Multiplies `self` by a `u32` value.
+    /// `self` に `u32` の値を乗算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` value to multiply by.
+    ///   乗算する `u32` の値.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(100_000);
+    /// a *= 100_000u32;
+    /// assert_eq!(Mint::new(17556470), a);
+    /// ```
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements the division operation (`/`) for two `DynamicModInt` instances.
+/// 2つの `DynamicModInt` インスタンスに対する除算演算 (`/`) を実装する.
+impl<const ID: usize> Div for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Divides `self` by another `DynamicModInt` instance using modular inverse.
+    /// モジュラ逆数を用いて, `self` を別の `DynamicModInt` インスタンスで除算する.
+    ///
+    /// # Args
+    /// - `self`: The dividend.
+    ///   被除数.
+    /// - `rhs`: The divisor.
+    ///   除数.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the quotient.
+    /// 商を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to the modulus.
+    /// 除数は法と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(20);
+    /// let b = Mint::new(4);
+    /// assert_eq!(Mint::new(5), a / b);
+    /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        // Calculate modular inverse for division.
+        self * rhs.inv_for_div()
+    }
+}
+
+/// Implements the division operation (`/`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する除算演算 (`/`) を実装する.
+impl<const ID: usize> Div<u32> for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Divides a `DynamicModInt` instance by a `u32` value using modular inverse.
+    /// モジュラ逆数を用いて, `DynamicModInt` インスタンスを `u32` の値で除算する.
+    ///
+    /// # Args
+    /// - `self`: The dividend.
+    ///   被除数.
+    /// - `rhs`: The `u32` divisor.
+    ///   `u32` の除数.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the quotient.
+    /// 商を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to the modulus.
+    /// 除数は法と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(20);
+    /// let b: u32 = 4;
+    /// assert_eq!(Mint::new(5), a / b);
+    /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: u32) -> Self::Output {
+        self * Self::new(rhs as u64).inv_for_div()
+    }
+}
+
+/// Implements the division assignment operation (`/=`) for `DynamicModInt`.
+/// `DynamicModInt` に対する除算代入演算 (`/=`) を実装する.
+impl<const ID: usize> DivAssign for DynamicModInt<ID> {
+    /// Divides `self` by another `DynamicModInt` instance.
+    /// `self` を別の `DynamicModInt` インスタンスで除算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The divisor.
+    ///   除数.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to the modulus.
+    /// 除数は法と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(20);
+    /// let b = Mint::new(4);
+    /// a /= b;
+    /// assert_eq!(Mint::new(5), a);
+    /// ```
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Implements the division assignment operation (`/=`) for `DynamicModInt` and `u32`.
+/// `DynamicModInt` と `u32` に対する除算代入演算 (`/=`) を実装する.
+impl<const ID: usize> DivAssign<u32> for DynamicModInt<ID> {
+    /// Divides `self` by a `u32` value.
+    /// `self` を `u32` の値で除算する.
+    ///
+    /// # Args
+    /// - `self`: The `DynamicModInt` instance to be modified.
+    ///   変更される `DynamicModInt` インスタンス.
+    /// - `rhs`: The `u32` divisor.
+    ///   `u32` の除数.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to the modulus.
+    /// 除数は法と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let mut a = Mint::new(20);
+    /// a /= 4u32;
+    /// assert_eq!(Mint::new(5), a);
+    /// ```
+    fn div_assign(&mut self, rhs: u32) {
+        *self = *self / rhs;
+    }
+}
+
+/// Implements the unary negation operation (`-`) for `DynamicModInt`.
+/// `DynamicModInt` に対する単項否定演算 (`-`) を実装する.
+impl<const ID: usize> Neg for DynamicModInt<ID> {
+    type Output = Self;
+
+    /// Computes the unary negation of a `DynamicModInt` instance.
+    /// `DynamicModInt` インスタンスの単項否定 (符号反転) を計算する.
+    ///
+    /// # Args
+    /// - `self`: The value to negate.
+    ///   符号反転する値.
+    ///
+    /// # Returns
+    /// A new `DynamicModInt` instance representing the negated value.
+    /// 符号反転された値を表す新しい `DynamicModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input value.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::dynamic_modint::DynamicModInt;
+    /// type Mint = DynamicModInt<0>; // The default modulus is 998244353.
+    /// let a = Mint::new(10);
+    /// assert_eq!(Mint::new(998244343), -a);
+    ///
+    /// let zero = Mint::new(0);
+    /// assert_eq!(zero, -zero);
+    /// ```
+    fn neg(self) -> Self::Output {
+        if self.val == 0 {
+            Self::new_raw(0)
+        } else {
+            Self::new_raw(Self::modulus() - self.val)
+        }
+    }
+}
+
+impl<const ID: usize> fmt::Display for DynamicModInt<ID> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
@@ -0,0 +1,970 @@
+//! This module provides a modular integer implementation for a modulus fixed at compile time.
+//! このモジュールは, コンパイル時に固定された法に対するモジュラー整数実装を提供する.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Represents a modular integer for the modulus `M` given as a const generic parameter.
+/// The modulus does not have to be prime, but it must be at least 1.
+/// const generic パラメータとして与えられる法 `M` のモジュラー整数を表現する.
+/// 法は素数である必要はないが, 1 以上でなければならない.
+///
+/// The layout is guaranteed to be that of a `u32` holding the reduced value,
+/// so a slice of `StaticModInt<M>` can be viewed as a slice of `u32` in `[0, M)`.
+/// メモリ配置は還元済みの値を持つ `u32` と同一であることが保証されるため,
+/// `StaticModInt<M>` のスライスは `[0, M)` の `u32` のスライスとして扱える.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct StaticModInt<const M: u32> {
+    val: u32,
+}
+
+impl<const M: u32> StaticModInt<M> {
+    /// The modulus for modular arithmetic operations.
+    /// モジュラー演算の法.
+    const MOD: u32 = {
+        assert!(M >= 1, "The modulus must be at least 1");
+        M
+    };
+
+    /// Returns the modulus `M`.
+    /// 法 `M` を返す.
+    ///
+    /// # Returns
+    /// `u32`: The modulus.
+    ///        法.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// assert_eq!(1_000_000_007, StaticModInt::<1_000_000_007>::modulus());
+    /// ```
+    pub fn modulus() -> u32 {
+        Self::MOD
+    }
+
+    /// Creates a new `StaticModInt` instance from a `u64` value.
+    /// `u64` 値から新しい `StaticModInt` インスタンスを生成する.
+    ///
+    /// # Args
+    /// - `n`: The `u64` value to be converted to a modular integer.
+    ///   モジュラー整数に変換する `u64` 値.
+    ///
+    /// # Returns
+    /// `Self`: A new `StaticModInt` instance with its value reduced modulo `MOD`.
+    ///         値が `MOD` で還元された新しい `StaticModInt` インスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let m = Mint::new(1_000_000_000);
+    /// assert_eq!(1755647, m.val());
+    /// ```
+    pub fn new(n: u64) -> Self {
+        StaticModInt {
+            val: (n % Self::MOD as u64) as u32,
+        }
+    }
+
+    /// Creates a new `StaticModInt` instance from a raw `u32` value.
+    /// 生の `u32` 値から新しい `StaticModInt` インスタンスを生成する.
+    ///
+    /// # Args
+    /// - `n`: The `u32` value, which must be less than `MOD`.
+    ///   `MOD` 未満でなければならない `u32` 値.
+    ///
+    /// # Returns
+    /// `Self`: A new `StaticModInt` instance.
+    ///         新しい `StaticModInt` インスタンス.
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than or equal to `MOD`.
+    /// `n` が `MOD` 以上の場合にパニックする.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let m = Mint::new_raw(100);
+    /// assert_eq!(100, m.val());
+    /// // Mint::new_raw(998244353); // This would panic
+    /// ```
+    pub fn new_raw(n: u32) -> Self {
+        assert!(
+            n < Self::MOD,
+            "Raw value {} must be less than MOD {}",
+            n,
+            Self::MOD
+        );
+        StaticModInt { val: n }
+    }
+
+    /// Returns the underlying `u32` value of the modular integer.
+    /// モジュラー整数の基となる `u32` 値を返す.
+    ///
+    /// # Returns
+    /// `u32`: The raw `u32` value of the modular integer.
+    ///         モジュラー整数の生の `u32` 値.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let m = Mint::new(123);
+    /// assert_eq!(123, m.val());
+    /// ```
+    pub fn val(&self) -> u32 {
+        self.val
+    }
+
+    /// Computes the modular multiplicative inverse of the modular integer.
+    /// モジュラー整数のモジュラー乗法逆元を計算する.
+    ///
+    /// This method uses the extended Euclidean algorithm, so it also works for a composite modulus.
+    /// このメソッドは拡張ユークリッドの互除法を使用するため, 合成数の法に対しても動作する.
+    ///
+    /// # Returns
+    /// `Option<Self>`: Returns `Some(inverse)` if `gcd(self.val, MOD) == 1`,
+    ///                 `None` otherwise (as inverse does not exist).
+    ///                 `gcd(self.val, MOD) == 1` の場合に `Some(inverse)` を返し,
+    ///                 そうでない場合に `None` を返す (逆元が存在しないため).
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log MOD).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let m = Mint::new(2);
+    /// let inv_m = m.inv().unwrap();
+    /// assert_eq!(1, (m * inv_m).val());
+    ///
+    /// let zero = Mint::new(0);
+    /// assert!(zero.inv().is_none());
+    ///
+    /// // 4 and 6 are not coprime, so 4 has no inverse modulo 6.
+    /// assert!(StaticModInt::<6>::new(4).inv().is_none());
+    /// assert_eq!(5, StaticModInt::<6>::new(5).inv().unwrap().val());
+    /// ```
+    pub fn inv(&self) -> Option<Self> {
+        // Maintain `a ≡ x * self.val` and `b ≡ y * self.val` (mod MOD) while running Euclid on (a, b).
+        let (mut a, mut b) = (self.val as i64, Self::MOD as i64);
+        let (mut x, mut y) = (1_i64, 0_i64);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        // Here `a` is gcd(self.val, MOD).
+        if a != 1 {
+            None
+        } else {
+            Some(Self::new(x.rem_euclid(Self::MOD as i64) as u64))
+        }
+    }
+
+    // Returns the inverse used as a divisor, panicking if it does not exist.
+    fn inv_for_div(&self) -> Self {
+        self.inv().unwrap_or_else(|| {
+            if self.val == 0 {
+                panic!("Division by zero is not allowed for ModInt{}", M)
+            } else {
+                panic!(
+                    "Division by {} is not allowed for ModInt{} since it is not invertible",
+                    self.val, M
+                )
+            }
+        })
+    }
+
+    /// Computes `self` raised to the power of `n`.
+    /// `self` の `n` 乗を計算する.
+    ///
+    /// # Args
+    /// - `n`: The non-negative exponent.
+    ///   冪指数.
+    ///
+    /// # Returns
+    /// `Self`: The result of `self` raised to the power of `n`.
+    ///         `self` を `n` 乗した結果.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log n), where n is the exponent.
+    ///   ここで n は冪指数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let base = Mint::new(3);
+    /// let result = base.pow(4);
+    /// assert_eq!(81, result.val());
+    /// ```
+    pub fn pow(&self, mut n: usize) -> Self {
+        let mut res = Self::new(1);
+        let mut base = *self;
+
+        // This is a standard binary exponentiation (exponentiation by squaring).
+        while n > 0 {
+            // If the current bit of n is 1, multiply the result by the base.
+            if n % 2 == 1 {
+                res *= base;
+            }
+            // Square the base for the next bit.
+            base *= base;
+            n /= 2;
+        }
+        res
+    }
+}
+
+/// Allows conversion from `u32` to `StaticModInt`.
+/// `u32` から `StaticModInt` への変換を可能にする.
+impl<const M: u32> From<u32> for StaticModInt<M> {
+    /// Creates a `StaticModInt` instance from a `u32` value.
+    /// `u32` の値から `StaticModInt` インスタンスを生成する.
+    ///
+    /// # Args
+    /// - `num`: The `u32` value to convert.
+    ///   変換する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance equivalent to `num` modulo `M`.
+    /// `num` を `M` で割った余りと等価な, 新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input value.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let val: u32 = 1_000_000_007;
+    /// let m: Mint = val.into();
+    /// assert_eq!(1755654, m.val()); // 1_000_000_007 % 998244353
+    /// ```
+    fn from(num: u32) -> Self {
+        StaticModInt::new(num as u64)
+    }
+}
+
+/// Allows conversion from `i32` to `StaticModInt`.
+/// `i32` から `StaticModInt` への変換を可能にする.
+impl<const M: u32> From<i32> for StaticModInt<M> {
+    /// Creates a `StaticModInt` instance from an `i32` value, handling negative numbers correctly.
+    /// `i32` の値から `StaticModInt` インスタンスを生成する. 負数も正しく扱う.
+    ///
+    /// # Args
+    /// - `num`: The `i32` value to convert.
+    ///   変換する `i32` の値.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance. Negative inputs are converted to a positive equivalent in modular arithmetic.
+    /// 新しい `StaticModInt` インスタンス. 負の入力は, 法演算における正の等価値に変換される.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input value.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let m_pos: Mint = 10i32.into();
+    /// assert_eq!(10, m_pos.val());
+    ///
+    /// let m_neg: Mint = (-10i32).into();
+    /// assert_eq!(998244353 - 10, m_neg.val());
+    /// ```
+    fn from(num: i32) -> Self {
+        // Ensure non-negative value for modular arithmetic.
+        StaticModInt::new((num as i64).rem_euclid(Self::MOD as i64) as u64)
+    }
+}
+
+/// Implements the addition operation (`+`) for two `StaticModInt` instances.
+/// 2つの `StaticModInt` インスタンスに対する加算演算 (`+`) を実装する.
+impl<const M: u32> Add for StaticModInt<M> {
+    type Output = Self;
+
+    /// Adds two `StaticModInt` instances.
+    /// 2つの `StaticModInt` インスタンスを加算する.
+    ///
+    /// # Args
+    /// - `self`: The left-hand side operand.
+    ///   左辺のオペランド.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the sum.
+    /// 和を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(998244350);
+    /// let b = Mint::new(10);
+    /// assert_eq!(Mint::new(7), a + b);
+    /// ```
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Implements the addition operation (`+`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する加算演算 (`+`) を実装する.
+impl<const M: u32> Add<u32> for StaticModInt<M> {
+    type Output = Self;
+
+    /// Adds a `u32` value to a `StaticModInt` instance.
+    /// `StaticModInt` インスタンスに `u32` の値を加算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance.
+    ///   `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` value to add.
+    ///   加算する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the sum.
+    /// 和を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(998244350);
+    /// let b: u32 = 10;
+    /// assert_eq!(Mint::new(7), a + b);
+    /// ```
+    fn add(mut self, rhs: u32) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Implements the addition assignment operation (`+=`) for `StaticModInt`.
+/// `StaticModInt` に対する加算代入演算 (`+=`) を実装する.
+impl<const M: u32> AddAssign for StaticModInt<M> {
+    /// Adds another `StaticModInt` instance to `self`.
+    /// 別の `StaticModInt` インスタンスを `self` に加算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(998244350);
+    /// let b = Mint::new(10);
+    /// a += b;
+    /// assert_eq!(Mint::new(7), a);
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        // Widen to `u64` so that the sum cannot overflow for moduli close to `u32::MAX`.
+        let sum = self.val as u64 + rhs.val as u64;
+        self.val = if sum >= Self::MOD as u64 {
+            (sum - Self::MOD as u64) as u32
+        } else {
+            sum as u32
+        };
+    }
+}
+
+/// Implements the addition assignment operation (`+=`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する加算代入演算 (`+=`) を実装する.
+impl<const M: u32> AddAssign<u32> for StaticModInt<M> {
+    /// Adds a `u32` value to `self`.
+    /// `u32` の値を `self` に加算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` value to add.
+    ///   加算する `u32` の値.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(998244350);
+    /// a += 10u32;
+    /// assert_eq!(Mint::new(7), a);
+    /// ```
+    fn add_assign(&mut self, rhs: u32) {
+        *self += Self::new_raw(rhs % Self::MOD);
+    }
+}
+
+/// Implements the subtraction operation (`-`) for two `StaticModInt` instances.
+/// 2つの `StaticModInt` インスタンスに対する減算演算 (`-`) を実装する.
+impl<const M: u32> Sub for StaticModInt<M> {
+    type Output = Self;
+
+    /// Subtracts one `StaticModInt` instance from another.
+    /// ある `StaticModInt` インスタンスから別のインスタンスを減算する.
+    ///
+    /// # Args
+    /// - `self`: The left-hand side operand (minuend).
+    ///   左辺のオペランド (被減数).
+    /// - `rhs`: The right-hand side operand (subtrahend).
+    ///   右辺のオペランド (減数).
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the difference.
+    /// 差を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(10);
+    /// let b = Mint::new(20);
+    /// assert_eq!(Mint::new(998244343), a - b);
+    /// ```
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Implements the subtraction operation (`-`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する減算演算 (`-`) を実装する.
+impl<const M: u32> Sub<u32> for StaticModInt<M> {
+    type Output = Self;
+
+    /// Subtracts a `u32` value from a `StaticModInt` instance.
+    /// `StaticModInt` インスタンスから `u32` の値を減算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance.
+    ///   `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` value to subtract.
+    ///   減算する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the difference.
+    /// 差を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(10);
+    /// let b: u32 = 20;
+    /// assert_eq!(Mint::new(998244343), a - b);
+    /// ```
+    fn sub(mut self, rhs: u32) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Implements the subtraction assignment operation (`-=`) for `StaticModInt`.
+/// `StaticModInt` に対する減算代入演算 (`-=`) を実装する.
+impl<const M: u32> SubAssign for StaticModInt<M> {
+    /// Subtracts another `StaticModInt` instance from `self`.
+    /// 別の `StaticModInt` インスタンスを `self` から減算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(10);
+    /// let b = Mint::new(20);
+    /// a -= b;
+    /// assert_eq!(Mint::new(998244343), a);
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.val >= rhs.val {
+            self.val -= rhs.val;
+        } else {
+            self.val += Self::MOD - rhs.val;
+        }
+    }
+}
+
+/// Implements the subtraction assignment operation (`-=`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する減算代入演算 (`-=`) を実装する.
+impl<const M: u32> SubAssign<u32> for StaticModInt<M> {
+    /// Subtracts a `u32` value from `self`.
+    /// `u32` の値を `self` から減算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` value to subtract.
+    ///   減算する `u32` の値.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(10);
+    /// a -= 20u32;
+    /// assert_eq!(Mint::new(998244343), a);
+    /// ```
+    fn sub_assign(&mut self, rhs: u32) {
+        *self -= Self::new_raw(rhs % Self::MOD);
+    }
+}
+
+/// Implements the multiplication operation (`*`) for two `StaticModInt` instances.
+/// 2つの `StaticModInt` インスタンスに対する乗算演算 (`*`) を実装する.
+impl<const M: u32> Mul for StaticModInt<M> {
+    type Output = Self;
+
+    /// Multiplies two `StaticModInt` instances.
+    /// 2つの `StaticModInt` インスタンスを乗算する.
+    ///
+    /// # Args
+    /// - `self`: The left-hand side operand.
+    ///   左辺のオペランド.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the product.
+    /// 積を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(100_000);
+    /// let b = Mint::new(100_000);
+    /// assert_eq!(Mint::new(17556470), a * b);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        StaticModInt::new((self.val as u64) * (rhs.val as u64))
+    }
+}
+
+/// Implements the multiplication operation (`*`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する乗算演算 (`*`) を実装する.
+impl<const M: u32> Mul<u32> for StaticModInt<M> {
+    type Output = Self;
+
+    /// Multiplies a `StaticModInt` instance by a `u32` value.
+    /// `StaticModInt` インスタンスに `u32` の値を乗算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance.
+    ///   `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` value to multiply by.
+    ///   乗算する `u32` の値.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the product.
+    /// 積を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(100_000);
+    /// let b: u32 = 100_000;
+    /// assert_eq!(Mint::new(17556470), a * b);
+    /// ```
+    fn mul(self, rhs: u32) -> Self::Output {
+        StaticModInt::new((self.val as u64) * (rhs as u64))
+    }
+}
+
+/// Implements the multiplication assignment operation (`*=`) for `StaticModInt`.
+/// `StaticModInt` に対する乗算代入演算 (`*=`) を実装する.
+impl<const M: u32> MulAssign for StaticModInt<M> {
+    /// Multiplies `self` by another `StaticModInt` instance.
+    /// `self` に別の `StaticModInt` インスタンスを乗算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The right-hand side operand.
+    ///   右辺のオペランド.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(100_000);
+    /// let b = Mint::new(100_000);
+    /// a *= b;
+    /// assert_eq!(Mint::new(17556470), a);
+    /// ```
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements the multiplication assignment operation (`*=`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する乗算代入演算 (`*=`) を実装する.
+impl<const M: u32> MulAssign<u32> for StaticModInt<M> {
+    /// Multiplies `self` by a `u32` value.
+    /// `self` に `u32` の値を乗算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` value to multiply by.
+    ///   乗算する `u32` の値.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input values.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(100_000);
+    /// a *= 100_000u32;
+    /// assert_eq!(Mint::new(17556470), a);
+    /// ```
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements the division operation (`/`) for two `StaticModInt` instances.
+/// 2つの `StaticModInt` インスタンスに対する除算演算 (`/`) を実装する.
+impl<const M: u32> Div for StaticModInt<M> {
+    type Output = Self;
+
+    /// Divides `self` by another `StaticModInt` instance using modular inverse.
+    /// モジュラ逆数を用いて, `self` を別の `StaticModInt` インスタンスで除算する.
+    ///
+    /// # Args
+    /// - `self`: The dividend.
+    ///   被除数.
+    /// - `rhs`: The divisor.
+    ///   除数.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the quotient.
+    /// 商を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to `M`.
+    /// 除数は `M` と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(20);
+    /// let b = Mint::new(4);
+    /// assert_eq!(Mint::new(5), a / b);
+    /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        // Calculate modular inverse for division.
+        self * rhs.inv_for_div()
+    }
+}
+
+/// Implements the division operation (`/`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する除算演算 (`/`) を実装する.
+impl<const M: u32> Div<u32> for StaticModInt<M> {
+    type Output = Self;
+
+    /// Divides a `StaticModInt` instance by a `u32` value using modular inverse.
+    /// モジュラ逆数を用いて, `StaticModInt` インスタンスを `u32` の値で除算する.
+    ///
+    /// # Args
+    /// - `self`: The dividend.
+    ///   被除数.
+    /// - `rhs`: The `u32` divisor.
+    ///   `u32` の除数.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the quotient.
+    /// 商を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to `M`.
+    /// 除数は `M` と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(20);
+    /// let b: u32 = 4;
+    /// assert_eq!(Mint::new(5), a / b);
+    /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: u32) -> Self::Output {
+        self * Self::new(rhs as u64).inv_for_div()
+    }
+}
+
+/// Implements the division assignment operation (`/=`) for `StaticModInt`.
+/// `StaticModInt` に対する除算代入演算 (`/=`) を実装する.
+impl<const M: u32> DivAssign for StaticModInt<M> {
+    /// Divides `self` by another `StaticModInt` instance.
+    /// `self` を別の `StaticModInt` インスタンスで除算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The divisor.
+    ///   除数.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to `M`.
+    /// 除数は `M` と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(20);
+    /// let b = Mint::new(4);
+    /// a /= b;
+    /// assert_eq!(Mint::new(5), a);
+    /// ```
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Implements the division assignment operation (`/=`) for `StaticModInt` and `u32`.
+/// `StaticModInt` と `u32` に対する除算代入演算 (`/=`) を実装する.
+impl<const M: u32> DivAssign<u32> for StaticModInt<M> {
+    /// Divides `self` by a `u32` value.
+    /// `self` を `u32` の値で除算する.
+    ///
+    /// # Args
+    /// - `self`: The `StaticModInt` instance to be modified.
+    ///   変更される `StaticModInt` インスタンス.
+    /// - `rhs`: The `u32` divisor.
+    ///   `u32` の除数.
+    ///
+    /// # Constraints
+    /// The divisor must be coprime to `M`.
+    /// 除数は `M` と互いに素でなければならない.
+    ///
+    /// # Panics
+    /// Panics if the divisor `rhs` is not invertible (e.g. zero).
+    /// 除数 `rhs` が逆元を持たない場合 (ゼロの場合など) にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log M), where M is the modulus.
+    ///   ここで M は法である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let mut a = Mint::new(20);
+    /// a /= 4u32;
+    /// assert_eq!(Mint::new(5), a);
+    /// ```
+    fn div_assign(&mut self, rhs: u32) {
+        *self = *self / rhs;
+    }
+}
+
+/// Implements the unary negation operation (`-`) for `StaticModInt`.
+/// `StaticModInt` に対する単項否定演算 (`-`) を実装する.
+impl<const M: u32> Neg for StaticModInt<M> {
+    type Output = Self;
+
+    /// Computes the unary negation of a `StaticModInt` instance.
+    /// `StaticModInt` インスタンスの単項否定 (符号反転) を計算する.
+    ///
+    /// # Args
+    /// - `self`: The value to negate.
+    ///   符号反転する値.
+    ///
+    /// # Returns
+    /// A new `StaticModInt` instance representing the negated value.
+    /// 符号反転された値を表す新しい `StaticModInt` インスタンス.
+    ///
+    /// # Constraints
+    /// There are no constraints on the input value.
+    /// 入力値に関する制約はない.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::modint::static_modint::StaticModInt;
+    /// type Mint = StaticModInt<998244353>;
+    /// let a = Mint::new(10);
+    /// assert_eq!(Mint::new(998244343), -a);
+    ///
+    /// let zero = Mint::new(0);
+    /// assert_eq!(zero, -zero);
+    /// ```
+    fn neg(self) -> Self::Output {
+        if self.val == 0 {
+            Self::new_raw(0)
+        } else {
+            Self::new_raw(Self::MOD - self.val)
+        }
+    }
+}
+
+impl<const M: u32> fmt::Display for StaticModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use super::rollback_union_find::RollbackUnionFind;
+
+/// Answers connectivity queries over a sequence of edge insertions and deletions offline.
+/// Each edge is alive over an interval of queries, which is split over a segment tree on time
+/// and applied to a `RollbackUnionFind` while traversing it.
+///
+/// 辺の追加と削除の列に対する連結性クエリをオフラインで処理する.
+/// 各辺はクエリの区間にわたって存在し, その区間を時間上のセグメント木に分割して,
+/// 木を走査しながら `RollbackUnionFind` に適用する.
+#[derive(Clone)]
+pub struct OfflineDynamicConnectivity {
+    n: usize,
+
+    // The queried pairs in order.
+    queries: Vec<(usize, usize)>,
+
+    // The alive edges with the query index at which each copy was added.
+    alive: HashMap<(usize, usize), Vec<usize>>,
+
+    // Edges with the half-open interval of queries during which they are alive.
+    intervals: Vec<(usize, usize, usize, usize)>,
+}
+
+impl OfflineDynamicConnectivity {
+    /// Creates a new solver for a graph with `n` vertices and no edges.
+    /// `n` 頂点で辺を持たないグラフに対する新しいソルバーを生成する.
+    ///
+    /// # Args
+    /// * `n`: The number of vertices.
+    ///   頂点数.
+    ///
+    /// # Returns
+    /// A new `OfflineDynamicConnectivity` instance.
+    /// 新しい `OfflineDynamicConnectivity` インスタンスを返す.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::offline_dynamic_connectivity::OfflineDynamicConnectivity;
+    /// let mut dc = OfflineDynamicConnectivity::new(3);
+    /// dc.add_edge(0, 1);
+    /// dc.query(0, 1);
+    /// dc.remove_edge(1, 0);
+    /// dc.query(0, 1);
+    /// assert_eq!(vec![true, false], dc.solve());
+    /// ```
+    pub fn new(n: usize) -> Self {
+        OfflineDynamicConnectivity {
+            n,
+            queries: Vec::new(),
+            alive: HashMap::new(),
+            intervals: Vec::new(),
+        }
+    }
+
+    fn key(&self, u: usize, v: usize) -> (usize, usize) {
+        if u >= self.n || v >= self.n {
+            panic!(
+                "Index out of bounds for edge: u={}, v={}, len={}",
+                u, v, self.n
+            );
+        }
+
+        (u.min(v), u.max(v))
+    }
+
+    /// Adds an undirected edge `(u, v)`. Parallel edges are allowed.
+    /// 無向辺 `(u, v)` を追加する. 多重辺も許される.
+    ///
+    /// # Args
+    /// * `u`: An endpoint of the edge.
+    ///   辺の端点.
+    /// * `v`: The other endpoint of the edge.
+    ///   辺のもう一方の端点.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` are out of bounds.
+    /// `u` または `v` が範囲外の場合にパニックする.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        let key = self.key(u, v);
+        self.alive.entry(key).or_default().push(self.queries.len());
+    }
+
+    /// Removes one copy of the undirected edge `(u, v)`.
+    /// 無向辺 `(u, v)` を一つ削除する.
+    ///
+    /// # Args
+    /// * `u`: An endpoint of the edge.
+    ///   辺の端点.
+    /// * `v`: The other endpoint of the edge.
+    ///   辺のもう一方の端点.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` are out of bounds, or if the edge does not exist.
+    /// `u` または `v` が範囲外の場合, または辺が存在しない場合にパニックする.
+    pub fn remove_edge(&mut self, u: usize, v: usize) {
+        let key = self.key(u, v);
+        let start = self
+            .alive
+            .get_mut(&key)
+            .and_then(|starts| starts.pop())
+            .unwrap_or_else(|| panic!("Edge ({}, {}) does not exist", u, v));
+        self.intervals
+            .push((key.0, key.1, start, self.queries.len()));
+    }
+
+    /// Records a query asking whether `u` and `v` are connected in the current graph.
+    /// 現在のグラフで `u` と `v` が連結かどうかを問うクエリを記録する.
+    ///
+    /// # Args
+    /// * `u`: The first vertex.
+    ///   最初の頂点.
+    /// * `v`: The second vertex.
+    ///   二番目の頂点.
+    ///
+    /// # Returns
+    /// The index of this query in the result of `solve`.
+    /// `solve` の結果におけるこのクエリのインデックス.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` are out of bounds.
+    /// `u` または `v` が範囲外の場合にパニックする.
+    pub fn query(&mut self, u: usize, v: usize) -> usize {
+        let key = self.key(u, v);
+        self.queries.push(key);
+        self.queries.len() - 1
+    }
+
+    /// Answers all recorded queries.
+    /// 記録された全てのクエリに答える.
+    ///
+    /// # Returns
+    /// `Vec<bool>`: The answers in the order of the queries.
+    ///              クエリの順に並んだ答え.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(Q + (M log Q + Q) log N), where Q is the number of queries,
+    ///   M is the number of added edges and N is the number of vertices.
+    ///   ここで Q はクエリ数, M は追加された辺の数, N は頂点数である.
+    pub fn solve(mut self) -> Vec<bool> {
+        let q = self.queries.len();
+        if q == 0 {
+            return Vec::new();
+        }
+
+        // Edges that are never removed stay alive until the last query.
+        for (&(u, v), starts) in self.alive.iter() {
+            for &start in starts {
+                self.intervals.push((u, v, start, q));
+            }
+        }
+
+        // Distribute each interval over the O(log Q) nodes of a segment tree on queries.
+        let size = q.next_power_of_two();
+        let mut edges_at = vec![Vec::new(); 2 * size];
+        for &(u, v, l, r) in self.intervals.iter() {
+            let (mut l, mut r) = (l + size, r + size);
+            while l < r {
+                if l & 1 == 1 {
+                    edges_at[l].push((u, v));
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    edges_at[r].push((u, v));
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+        }
+
+        let mut uf = RollbackUnionFind::new(self.n);
+        let mut res = vec![false; q];
+        self.dfs(1, size, &edges_at, &mut uf, &mut res);
+        res
+    }
+
+    fn dfs(
+        &self,
+        node: usize,
+        size: usize,
+        edges_at: &[Vec<(usize, usize)>],
+        uf: &mut RollbackUnionFind,
+        res: &mut [bool],
+    ) {
+        // Skip the padding leaves beyond the last query.
+        if node >= size && node - size >= self.queries.len() {
+            return;
+        }
+
+        let snapshot = uf.snapshot();
+        for &(u, v) in edges_at[node].iter() {
+            uf.union(u, v);
+        }
+        if node >= size {
+            let (u, v) = self.queries[node - size];
+            res[node - size] = uf.is_same(u, v);
+        } else {
+            self.dfs(2 * node, size, edges_at, uf, res);
+            self.dfs(2 * node + 1, size, edges_at, uf, res);
+        }
+        uf.rollback(snapshot);
+    }
+}
@@ -0,0 +1,358 @@
+/// A partially persistent Union-Find that can answer queries about any past state.
+/// Each `union` call advances the time by one, and the state at time `t` is the one
+/// right after the first `t` calls. Every link records the time it was made,
+/// so no path compression is performed and union by size keeps the trees shallow.
+///
+/// 過去の任意の状態についてのクエリに答えられる, 部分永続 Union-Find である.
+/// `union` を呼び出すごとに時刻が 1 進み, 時刻 `t` の状態は最初の `t` 回の呼び出しの直後の状態である.
+/// 各リンクは作られた時刻を記録するため経路圧縮は行わず, サイズによる統合で木を浅く保つ.
+#[derive(Clone)]
+pub struct PersistentUnionFind {
+    parent: Vec<usize>,
+
+    // The time at which each element stopped being a root, or `usize::MAX` for roots.
+    linked_at: Vec<usize>,
+
+    // The history of `(time, size)` of each element while it is a root.
+    sizes: Vec<Vec<(usize, usize)>>,
+
+    // The number of `union` calls so far.
+    now: usize,
+}
+
+impl PersistentUnionFind {
+    /// Creates a new `PersistentUnionFind` instance with `n` elements at time 0.
+    /// Initially, each element is in its own set.
+    ///
+    /// 時刻 0 で `n` 個の要素を持つ新しい `PersistentUnionFind` インスタンスを生成する.
+    /// 初期状態では, 各要素がそれぞれ独立した集合に属する.
+    ///
+    /// # Args
+    /// * `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// A new `PersistentUnionFind` instance.
+    /// 新しい `PersistentUnionFind` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    /// - Space complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::persistent_union_find::PersistentUnionFind;
+    /// let uf = PersistentUnionFind::new(5);
+    /// assert_eq!(0, uf.now());
+    /// ```
+    pub fn new(n: usize) -> Self {
+        PersistentUnionFind {
+            parent: (0..n).collect::<Vec<_>>(),
+            linked_at: vec![usize::MAX; n],
+            sizes: vec![vec![(0, 1)]; n],
+            now: 0,
+        }
+    }
+
+    /// Returns the number of elements managed by this `PersistentUnionFind`.
+    /// `PersistentUnionFind` が管理する要素数を返す.
+    ///
+    /// # Returns
+    /// The total number of elements.
+    /// 全要素数.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if this `PersistentUnionFind` manages no elements.
+    /// `PersistentUnionFind` が要素を一つも管理していない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `true` if `self.len() == 0`, otherwise `false`.
+    /// `self.len() == 0` であれば `true` を, そうでなければ `false` を返す.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the current time, which is the number of `union` calls so far.
+    /// 現在の時刻, すなわちこれまでの `union` の呼び出し回数を返す.
+    ///
+    /// # Returns
+    /// The current time.
+    /// 現在の時刻.
+    pub fn now(&self) -> usize {
+        self.now
+    }
+
+    fn check_index(&self, x: usize) {
+        if x >= self.len() {
+            panic!(
+                "Index {} is out of bounds for PersistentUnionFind with size {}",
+                x,
+                self.len()
+            );
+        }
+    }
+
+    /// Finds the root of the set containing element `x` at time `t`.
+    /// 時刻 `t` において要素 `x` を含む集合の根を見つける.
+    ///
+    /// # Args
+    /// * `x`: The element to find the root of.
+    ///   根を探す対象の要素.
+    /// * `t`: The time. Times after `now()` are treated as `now()`.
+    ///   時刻. `now()` より後の時刻は `now()` として扱われる.
+    ///
+    /// # Returns
+    /// The root of the set at time `t`.
+    /// 時刻 `t` において属する集合の根を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    pub fn find_at(&self, mut x: usize, t: usize) -> usize {
+        self.check_index(x);
+
+        while self.linked_at[x] <= t {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Finds the root of the set containing element `x` at the current time.
+    /// 現在の時刻において要素 `x` を含む集合の根を見つける.
+    ///
+    /// # Args
+    /// * `x`: The element to find the root of.
+    ///   根を探す対象の要素.
+    ///
+    /// # Returns
+    /// The root of the set.
+    /// 属する集合の根を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    pub fn find(&self, x: usize) -> usize {
+        self.find_at(x, self.now)
+    }
+
+    /// Merges the sets containing elements `x` and `y`, advancing the time by one.
+    /// 要素 `x` と要素 `y` を含む集合をマージし, 時刻を 1 進める.
+    ///
+    /// # Args
+    /// * `x`: An element in the first set.
+    ///   最初の集合に含まれる要素.
+    /// * `y`: An element in the second set.
+    ///   二番目の集合に含まれる要素.
+    ///
+    /// # Returns
+    /// `true` if two different sets were merged, otherwise `false`.
+    /// The time advances in either case.
+    /// 異なる二つの集合がマージされた場合は `true` を, そうでなければ `false` を返す.
+    /// いずれの場合も時刻は進む.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let len = self.len();
+        if x >= len || y >= len {
+            panic!(
+                "Index out of bounds for union: x={}, y={}, len={}",
+                x, y, len
+            );
+        }
+
+        let (mut root_x, mut root_y) = (self.find(x), self.find(y));
+        self.now += 1;
+        if root_x == root_y {
+            return false;
+        }
+
+        // Union by size: Attach the smaller tree to the root of the larger tree.
+        let size_x = self.sizes[root_x].last().unwrap().1;
+        let size_y = self.sizes[root_y].last().unwrap().1;
+        if size_x < size_y {
+            std::mem::swap(&mut root_x, &mut root_y);
+        }
+        self.parent[root_y] = root_x;
+        self.linked_at[root_y] = self.now;
+        self.sizes[root_x].push((self.now, size_x + size_y));
+        true
+    }
+
+    /// Checks if elements `x` and `y` are in the same set at time `t`.
+    /// 時刻 `t` において要素 `x` と `y` が同じ集合に属するかどうかを判定する.
+    ///
+    /// # Args
+    /// * `x`: The first element.
+    ///   最初の要素.
+    /// * `y`: The second element.
+    ///   二番目の要素.
+    /// * `t`: The time. Times after `now()` are treated as `now()`.
+    ///   時刻. `now()` より後の時刻は `now()` として扱われる.
+    ///
+    /// # Returns
+    /// `true` if `x` and `y` are in the same set at time `t`, otherwise `false`.
+    /// 時刻 `t` において `x` と `y` が同じ集合に属する場合は `true` を, そうでなければ `false` を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::persistent_union_find::PersistentUnionFind;
+    /// let mut uf = PersistentUnionFind::new(3);
+    /// uf.union(0, 1);
+    /// uf.union(1, 2);
+    /// assert!(!uf.is_same_at(0, 2, 1));
+    /// assert!(uf.is_same_at(0, 2, 2));
+    /// ```
+    pub fn is_same_at(&self, x: usize, y: usize, t: usize) -> bool {
+        self.find_at(x, t) == self.find_at(y, t)
+    }
+
+    /// Checks if elements `x` and `y` are in the same set at the current time.
+    /// 現在の時刻において要素 `x` と `y` が同じ集合に属するかどうかを判定する.
+    ///
+    /// # Args
+    /// * `x`: The first element.
+    ///   最初の要素.
+    /// * `y`: The second element.
+    ///   二番目の要素.
+    ///
+    /// # Returns
+    /// `true` if `x` and `y` are in the same set, otherwise `false`.
+    /// `x` と `y` が同じ集合に属する場合は `true` を, そうでなければ `false` を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    pub fn is_same(&self, x: usize, y: usize) -> bool {
+        self.is_same_at(x, y, self.now)
+    }
+
+    /// Returns the size of the set containing element `x` at time `t`.
+    /// 時刻 `t` において要素 `x` を含む集合のサイズを返す.
+    ///
+    /// # Args
+    /// * `x`: The element.
+    ///   対象の要素.
+    /// * `t`: The time. Times after `now()` are treated as `now()`.
+    ///   時刻. `now()` より後の時刻は `now()` として扱われる.
+    ///
+    /// # Returns
+    /// The size of the set at time `t`.
+    /// 時刻 `t` における集合のサイズ.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::persistent_union_find::PersistentUnionFind;
+    /// let mut uf = PersistentUnionFind::new(3);
+    /// uf.union(0, 1);
+    /// uf.union(1, 2);
+    /// assert_eq!(1, uf.get_size_at(2, 0));
+    /// assert_eq!(2, uf.get_size_at(0, 1));
+    /// assert_eq!(3, uf.get_size_at(0, 2));
+    /// ```
+    pub fn get_size_at(&self, x: usize, t: usize) -> usize {
+        let sizes = &self.sizes[self.find_at(x, t)];
+        // The last record made at or before `t`; the first one is made at time 0.
+        sizes[sizes.partition_point(|&(time, _)| time <= t) - 1].1
+    }
+
+    /// Returns the size of the set containing element `x` at the current time.
+    /// 現在の時刻において要素 `x` を含む集合のサイズを返す.
+    ///
+    /// # Args
+    /// * `x`: The element.
+    ///   対象の要素.
+    ///
+    /// # Returns
+    /// The size of the set.
+    /// 集合のサイズ.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    pub fn get_size(&self, x: usize) -> usize {
+        self.sizes[self.find(x)].last().unwrap().1
+    }
+
+    /// Returns the first time at which elements `x` and `y` are in the same set.
+    /// 要素 `x` と `y` が初めて同じ集合に属する時刻を返す.
+    ///
+    /// # Args
+    /// * `x`: The first element.
+    ///   最初の要素.
+    /// * `y`: The second element.
+    ///   二番目の要素.
+    ///
+    /// # Returns
+    /// `Some(t)` with the smallest `t` such that `is_same_at(x, y, t)`, or `None` if they are
+    /// not connected yet. `Some(0)` is returned if `x == y`.
+    /// `is_same_at(x, y, t)` を満たす最小の `t` を `Some(t)` として返す. まだ連結でなければ `None` を返す.
+    /// `x == y` の場合は `Some(0)` を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::persistent_union_find::PersistentUnionFind;
+    /// let mut uf = PersistentUnionFind::new(4);
+    /// uf.union(0, 1);
+    /// uf.union(2, 3);
+    /// uf.union(1, 2);
+    /// assert_eq!(Some(3), uf.first_connected_time(0, 3));
+    /// assert_eq!(Some(1), uf.first_connected_time(1, 0));
+    /// ```
+    pub fn first_connected_time(&self, mut x: usize, mut y: usize) -> Option<usize> {
+        if !self.is_same(x, y) {
+            return None;
+        }
+
+        // Link times increase towards the root, so always climbing from the side linked earlier
+        // meets at the lowest common ancestor, and the last link climbed is the latest one.
+        let mut res = 0;
+        while x != y {
+            if self.linked_at[x] < self.linked_at[y] {
+                res = self.linked_at[x];
+                x = self.parent[x];
+            } else {
+                res = self.linked_at[y];
+                y = self.parent[y];
+            }
+        }
+        Some(res)
+    }
+}
@@ -0,0 +1,282 @@
+/// A Union-Find that can undo its merges in reverse order.
+/// It uses union by size without path compression, so `find` never mutates the structure.
+///
+/// マージを逆順に取り消すことができる Union-Find である.
+/// 経路圧縮を行わずにサイズによる統合のみを用いるため, `find` は構造を変更しない.
+#[derive(Clone)]
+pub struct RollbackUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+
+    // One entry per call of `union`: the root attached to another root, or `None` for a no-op.
+    history: Vec<Option<usize>>,
+}
+
+impl RollbackUnionFind {
+    /// Creates a new `RollbackUnionFind` instance with `n` elements.
+    /// Initially, each element is in its own set.
+    ///
+    /// `n` 個の要素を持つ新しい `RollbackUnionFind` インスタンスを生成する.
+    /// 初期状態では, 各要素がそれぞれ独立した集合に属する.
+    ///
+    /// # Args
+    /// * `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// A new `RollbackUnionFind` instance.
+    /// 新しい `RollbackUnionFind` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    /// - Space complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::rollback_union_find::RollbackUnionFind;
+    /// let uf = RollbackUnionFind::new(5);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        RollbackUnionFind {
+            parent: (0..n).collect::<Vec<_>>(),
+            size: vec![1; n],
+            history: Vec::new(),
+        }
+    }
+
+    /// Returns the number of elements managed by this `RollbackUnionFind`.
+    /// `RollbackUnionFind` が管理する要素数を返す.
+    ///
+    /// # Returns
+    /// The total number of elements.
+    /// 全要素数.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if this `RollbackUnionFind` manages no elements.
+    /// `RollbackUnionFind` が要素を一つも管理していない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `true` if `self.len() == 0`, otherwise `false`.
+    /// `self.len() == 0` であれば `true` を, そうでなければ `false` を返す.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Finds the root of the set containing element `x` without path compression.
+    /// 経路圧縮を行わずに, 要素 `x` を含む集合の根を見つける.
+    ///
+    /// # Args
+    /// * `x`: The element to find the root of.
+    ///   根を探す対象の要素.
+    ///
+    /// # Returns
+    /// The root of the set.
+    /// 属する集合の根を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    pub fn find(&self, mut x: usize) -> usize {
+        if x >= self.len() {
+            panic!(
+                "Index {} is out of bounds for RollbackUnionFind with size {}",
+                x,
+                self.len()
+            );
+        }
+
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing elements `x` and `y`, recording the operation in the history.
+    /// This method uses union by size to keep the tree depth logarithmic.
+    ///
+    /// 要素 `x` と要素 `y` を含む集合をマージし, 操作を履歴に記録する.
+    /// このメソッドはサイズによる統合 (union by size) を用いて木の深さを対数に保つ.
+    ///
+    /// # Args
+    /// * `x`: An element in the first set.
+    ///   最初の集合に含まれる要素.
+    /// * `y`: An element in the second set.
+    ///   二番目の集合に含まれる要素.
+    ///
+    /// # Returns
+    /// `true` if two different sets were merged, otherwise `false`.
+    /// Either way, one entry is added to the history.
+    /// 異なる二つの集合がマージされた場合は `true` を, そうでなければ `false` を返す.
+    /// いずれの場合も履歴に一つの項目が追加される.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let len = self.len();
+        if x >= len || y >= len {
+            panic!(
+                "Index out of bounds for union: x={}, y={}, len={}",
+                x, y, len
+            );
+        }
+
+        let (mut root_x, mut root_y) = (self.find(x), self.find(y));
+        if root_x == root_y {
+            self.history.push(None);
+            return false;
+        }
+
+        // Union by size: Attach the smaller tree to the root of the larger tree.
+        if self.size[root_x] < self.size[root_y] {
+            std::mem::swap(&mut root_x, &mut root_y);
+        }
+        self.parent[root_y] = root_x;
+        self.size[root_x] += self.size[root_y];
+        self.history.push(Some(root_y));
+        true
+    }
+
+    /// Checks if elements `x` and `y` are in the same set.
+    /// 要素 `x` と `y` が同じ集合に属するかどうかを判定する.
+    ///
+    /// # Args
+    /// * `x`: The first element.
+    ///   最初の要素.
+    /// * `y`: The second element.
+    ///   二番目の要素.
+    ///
+    /// # Returns
+    /// `true` if `x` and `y` are in the same set, otherwise `false`.
+    /// `x` と `y` が同じ集合に属する場合は `true` を, そうでなければ `false` を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    pub fn is_same(&self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Returns the size of the set containing element `x`.
+    /// 要素 `x` を含む集合のサイズを返す.
+    ///
+    /// # Args
+    /// * `x`: The element.
+    ///   対象の要素.
+    ///
+    /// # Returns
+    /// The size of the set.
+    /// 集合のサイズ.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    pub fn get_size(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// Returns the number of `union` calls recorded in the history.
+    /// 履歴に記録された `union` の呼び出し回数を返す.
+    ///
+    /// # Returns
+    /// The length of the history.
+    /// 履歴の長さ.
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns a snapshot of the current state, which can be passed to `rollback`.
+    /// 現在の状態のスナップショットを返す. これは `rollback` に渡すことができる.
+    ///
+    /// # Returns
+    /// The current length of the history.
+    /// 現在の履歴の長さ.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes the most recent `union` call.
+    /// 直近の `union` の呼び出しを取り消す.
+    ///
+    /// # Returns
+    /// `true` if a call was undone, or `false` if the history is empty.
+    /// 呼び出しを取り消した場合は `true` を, 履歴が空の場合は `false` を返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::rollback_union_find::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::new(3);
+    /// uf.union(0, 1);
+    /// assert!(uf.undo());
+    /// assert!(!uf.is_same(0, 1));
+    /// assert!(!uf.undo());
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(Some(child)) => {
+                let root = self.parent[child];
+                self.parent[child] = child;
+                self.size[root] -= self.size[child];
+                true
+            }
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// Undoes `union` calls until the history length becomes `to`.
+    /// 履歴の長さが `to` になるまで `union` の呼び出しを取り消す.
+    ///
+    /// # Args
+    /// * `to`: A snapshot returned by `snapshot`.
+    ///   `snapshot` が返したスナップショット.
+    ///
+    /// # Panics
+    /// Panics if `to` is greater than the current history length.
+    /// `to` が現在の履歴の長さより大きい場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(K), where K is the number of undone calls.
+    ///   ここで K は取り消す呼び出しの回数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::rollback_union_find::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::new(4);
+    /// uf.union(0, 1);
+    /// let snapshot = uf.snapshot();
+    /// uf.union(1, 2);
+    /// uf.union(2, 3);
+    /// uf.rollback(snapshot);
+    /// assert!(uf.is_same(0, 1));
+    /// assert!(!uf.is_same(1, 2));
+    /// ```
+    pub fn rollback(&mut self, to: usize) {
+        if to > self.history.len() {
+            panic!(
+                "Cannot roll back to {} since the history length is {}",
+                to,
+                self.history.len()
+            );
+        }
+
+        while self.history.len() > to {
+            self.undo();
+        }
+    }
+}
@@ -0,0 +1,588 @@
+//! A lazy-propagation segment tree for range actions and range queries.
+//! 遅延伝播 `segment tree` の実装であり, 区間作用と range query をサポートする.
+
+use super::super::super::algebra::{action::Action, monoid::Monoid};
+
+/// A segment tree that supports applying a map to a range and folding a range.
+/// Values form the monoid `M`, and the maps form the action monoid `F` acting on `M`.
+/// 区間への写像の作用と区間の `fold` をサポートする `segment tree` である.
+/// 値はモノイド `M` をなし, 写像は `M` に作用するモノイド `F` をなす.
+#[derive(Clone)]
+pub struct LazySegmentTree<M, F>
+where
+    M: Monoid,
+    F: Action<M>,
+{
+    len: usize,
+    size: usize,
+    log: u32,
+    data: Vec<M::S>,
+    lazy: Vec<F::S>,
+}
+
+impl<M, F> LazySegmentTree<M, F>
+where
+    M: Monoid,
+    M::S: Clone,
+    F: Action<M>,
+    F::S: Clone,
+{
+    /// Creates a new `LazySegmentTree` with `n` elements, all initialized to `M::id()`.
+    /// For `AddWithLenMonoid` the identity is `(0, 0)`, whose length is 0, so a range addition
+    /// has no effect until each leaf is set to `(v, 1)`; use `from_slice` to build such a tree in O(n).
+    ///
+    /// 全要素が `M::id()` で初期化された, `n` 個の要素を持つ `LazySegmentTree` を生成する.
+    /// `AddWithLenMonoid` の単位元は長さ 0 の `(0, 0)` であるため, 各葉を `(v, 1)` にセットするまで
+    /// 区間加算は効果を持たない. そのような木を O(n) で構築するには `from_slice` を用いる.
+    ///
+    /// # Args
+    /// - `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// `LazySegmentTree<M, F>`: Returns a newly created segment tree instance.
+    ///                          新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(n), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    /// assert_eq!(seg.len(), 5);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        // Leaves are laid out on a complete binary tree whose leaf count is a power of two.
+        let size = n.next_power_of_two();
+        LazySegmentTree::<M, F> {
+            len: n,
+            size,
+            log: size.trailing_zeros(),
+            data: vec![M::id(); 2 * size],
+            lazy: vec![F::id(); size],
+        }
+    }
+
+    /// Creates a new `LazySegmentTree` whose elements are the values of `v`.
+    /// `v` の値を要素とする `LazySegmentTree` を生成する.
+    ///
+    /// # Args
+    /// - `v`: The initial elements.
+    ///   初期の要素.
+    ///
+    /// # Returns
+    /// `LazySegmentTree<M, F>`: Returns a newly created segment tree instance.
+    ///                          新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n), where `n` is the length of `v`.
+    ///   ここで `n` は `v` の長さである.
+    /// - Space complexity: O(n), where `n` is the length of `v`.
+    ///   ここで `n` は `v` の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::from_slice(&[(1, 1); 5]);
+    /// seg.apply(1, 4, 10);
+    /// assert_eq!(seg.fold(0, 5), (35, 5));
+    /// ```
+    pub fn from_slice(v: &[M::S]) -> Self {
+        let mut seg = Self::new(v.len());
+        seg.data[seg.size..seg.size + v.len()].clone_from_slice(v);
+        for k in (1..seg.size).rev() {
+            seg.pull(k);
+        }
+        seg
+    }
+
+    /// Returns the number of elements of this segment tree.
+    /// この `segment tree` の要素数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements.
+    ///          要素数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this segment tree has no elements.
+    /// この `segment tree` が要素を持たない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: `true` if `self.len() == 0`, otherwise `false`.
+    ///         `self.len() == 0` の場合は `true`, そうでなければ `false`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Recomputes node k from its two children.
+    fn pull(&mut self, k: usize) {
+        self.data[k] = M::op(&self.data[2 * k], &self.data[2 * k + 1]);
+    }
+
+    // Applies f to node k and, if k is an internal node, records it as pending for the children.
+    fn all_apply(&mut self, k: usize, f: &F::S) {
+        self.data[k] = F::act(f, &self.data[k]);
+        if k < self.size {
+            self.lazy[k] = F::op(&self.lazy[k], f);
+        }
+    }
+
+    // Pushes the pending map of node k down to its two children.
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.lazy[k], F::id());
+        self.all_apply(2 * k, &f);
+        self.all_apply(2 * k + 1, &f);
+    }
+
+    /// Sets the value at index `idx` to `x`.
+    /// Unlike `SegmentTreeDense::set`, the change is reflected immediately.
+    /// インデックス `idx` の値を `x` にセットする.
+    /// `SegmentTreeDense::set` と異なり, 変更は直ちに反映される.
+    ///
+    /// # Args
+    /// - `idx`: The index to set.
+    ///   セット対象のインデックス.
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if `idx` >= `self.len()`.
+    /// `idx` が `self.len()` 以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(3);
+    /// seg.set(0, (1, 1));
+    /// seg.set(1, (2, 1));
+    /// seg.set(2, (3, 1));
+    /// assert_eq!(seg.fold(0, 3), (6, 3));
+    /// ```
+    pub fn set(&mut self, idx: usize, x: M::S) {
+        assert!(
+            idx < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            idx
+        );
+        let idx = idx + self.size;
+        // Resolve pending maps on the path from the root before overwriting the leaf.
+        for i in (1..=self.log).rev() {
+            self.push(idx >> i);
+        }
+        self.data[idx] = x;
+        for i in 1..=self.log {
+            self.pull(idx >> i);
+        }
+    }
+
+    /// Gets the value at index `idx`.
+    /// インデックス `idx` の値を取得する.
+    ///
+    /// # Args
+    /// - `idx`: The index to retrieve.
+    ///   値を取得するインデックス.
+    ///
+    /// # Returns
+    /// `M::S`: The value at `idx`.
+    ///         `idx` の値.
+    ///
+    /// # Panics
+    /// Panics if `idx` >= `self.len()`.
+    /// `idx` が `self.len()` 以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AssignAction, monoid::MinMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<MinMonoid, AssignAction>::new(3);
+    /// seg.set(1, 10);
+    /// seg.apply(0, 2, Some(7));
+    /// assert_eq!(seg.get(1), 7);
+    /// ```
+    pub fn get(&mut self, idx: usize) -> M::S {
+        assert!(
+            idx < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            idx
+        );
+        let idx = idx + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(idx >> i);
+        }
+        self.data[idx].clone()
+    }
+
+    /// Performs a range fold (query) on the interval `[l, r)`.
+    /// 区間 `[l, r)` 上の値に対して `fold` (畳み込み) を行う `query` を実行する.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   `query` 区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   `query` 区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `M::S`: The folded result of the interval `[l, r)`. It is the identity element `M::id()` if the range is empty.
+    ///         区間 `[l, r)` の畳み込み結果. 区間が空の場合, 単位元 `M::id()` となる.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    /// for i in 0..5 {
+    ///     seg.set(i, (i as i64, 1));
+    /// }
+    /// assert_eq!(seg.fold(1, 4), (6, 3));
+    /// ```
+    pub fn fold(&mut self, l: usize, r: usize) -> M::S {
+        if l >= r {
+            return M::id();
+        }
+
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        // Resolve pending maps on the boundaries so that every visited node is up to date.
+        for i in (1..=self.log).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+
+        let mut sum_l = M::id();
+        let mut sum_r = M::id();
+        while l < r {
+            if l & 1 == 1 {
+                sum_l = M::op(&sum_l, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                sum_r = M::op(&self.data[r], &sum_r);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+
+        M::op(&sum_l, &sum_r)
+    }
+
+    /// Applies the map `f` to every element in the interval `[l, r)`.
+    /// 区間 `[l, r)` の全要素に写像 `f` を作用させる.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    /// - `f`: The map to apply.
+    ///   作用させる写像.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    /// for i in 0..5 {
+    ///     seg.set(i, (0, 1));
+    /// }
+    /// seg.apply(1, 4, 10);
+    /// assert_eq!(seg.fold(0, 5), (30, 5));
+    /// assert_eq!(seg.get(3), (10, 1));
+    /// ```
+    pub fn apply(&mut self, l: usize, r: usize, f: F::S) {
+        if l >= r {
+            return;
+        }
+
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+
+        let (l, r) = (l + self.size, r + self.size);
+        for i in (1..=self.log).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+
+        // Apply `f` to the canonical cover of `[l, r)`.
+        let (mut a, mut b) = (l, r);
+        while a < b {
+            if a & 1 == 1 {
+                self.all_apply(a, &f);
+                a += 1;
+            }
+            if b & 1 == 1 {
+                b -= 1;
+                self.all_apply(b, &f);
+            }
+            a >>= 1;
+            b >>= 1;
+        }
+
+        // Recompute the ancestors of the boundaries.
+        for i in 1..=self.log {
+            if (l >> i) << i != l {
+                self.pull(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.pull((r - 1) >> i);
+            }
+        }
+    }
+
+    /// Finds the maximum `r` in `[l, self.len()]` such that `f` applied to the fold result
+    /// from `[l, r)` is `true`. Returns `self.len()` if no further extension is possible.
+    /// 区間 `[l, self.len()]` 内で, `[l, r)` の `fold` 結果に対して述語 `f` が `true` を返すような
+    /// 最大の `r` を探索する. 条件を満たす `r` がこれ以上存在しない場合は, `self.len()` を返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range.
+    ///   範囲の開始インデックス.
+    /// - `f`: A function that takes a reference to `M::S` and returns a boolean.
+    ///   `M::S` への参照を受け取り, 真偽値を返す関数.
+    ///
+    /// # Returns
+    /// `usize`: The maximum `r` such that `f(fold(l, r))` is `true`.
+    ///          `f(fold(l, r))` が `true` となる最大の `r`.
+    ///
+    /// # Panics
+    /// Panics if `f(&M::id())` is false or `l > self.len()`.
+    /// `f(&M::id())` が `false` の場合, または `l > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    /// for i in 0..5 {
+    ///     seg.set(i, (1, 1));
+    /// }
+    /// seg.apply(0, 5, 2);
+    /// // Every element is 3, so [1, 4) sums to 9 and [1, 5) sums to 12.
+    /// assert_eq!(seg.max_right(1, |&(sum, _)| sum < 10), 4);
+    /// ```
+    pub fn max_right<P>(&mut self, l: usize, f: P) -> usize
+    where
+        P: Fn(&M::S) -> bool,
+    {
+        assert!(
+            f(&M::id()),
+            "predicate must be true for the identity element"
+        );
+        assert!(
+            l <= self.len(),
+            "index out of bounds: l must be less than or equal to the len (l: {}, len: {})",
+            l,
+            self.len()
+        );
+
+        if l == self.len() {
+            return self.len();
+        }
+
+        let mut l = l + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(l >> i);
+        }
+        let mut sum = M::id();
+        loop {
+            // Move up while l is a left child.
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            // If the predicate fails with this node, descend to find the boundary.
+            if !f(&M::op(&sum, &self.data[l])) {
+                while l < self.size {
+                    self.push(l);
+                    l *= 2;
+                    let t = M::op(&sum, &self.data[l]);
+                    if f(&t) {
+                        sum = t;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            sum = M::op(&sum, &self.data[l]);
+            l += 1;
+            // Stop once the whole tree to the right has been consumed.
+            if l.is_power_of_two() {
+                break;
+            }
+        }
+        self.len()
+    }
+
+    /// Finds the minimum `l` in `[0, r]` such that `f` applied to the fold result
+    /// from `[l, r)` is `true`. Returns `0` if it cannot move further left.
+    /// 区間 `[0, r]` 内で, `[l, r)` の `fold` 結果に対して述語 `f` が `true` を返すような
+    /// 最小の `l` を探索する. 条件を満たす `l` がこれ以上存在しない場合は, `0` を返す.
+    ///
+    /// # Args
+    /// - `r`: The end index of the range.
+    ///   範囲の終了インデックス.
+    /// - `f`: A function that takes a reference to `M::S` and returns a boolean.
+    ///   `M::S` への参照を受け取り, 真偽値を返す関数.
+    ///
+    /// # Returns
+    /// `usize`: The minimum `l` such that `f(fold(l, r))` is `true`.
+    ///          `f(fold(l, r))` が `true` となる最小の `l`.
+    ///
+    /// # Panics
+    /// Panics if `f(&M::id())` is false or `r > self.len()`.
+    /// `f(&M::id())` が `false` の場合, または `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{
+    ///     algebra::{action::AddAction, monoid::AddWithLenMonoid},
+    ///     ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+    /// };
+    /// let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    /// for i in 0..5 {
+    ///     seg.set(i, (1, 1));
+    /// }
+    /// seg.apply(0, 5, 2);
+    /// // Every element is 3, so [1, 4) sums to 9 and [0, 4) sums to 12.
+    /// assert_eq!(seg.min_left(4, |&(sum, _)| sum < 10), 1);
+    /// ```
+    pub fn min_left<P>(&mut self, r: usize, f: P) -> usize
+    where
+        P: Fn(&M::S) -> bool,
+    {
+        assert!(
+            f(&M::id()),
+            "predicate must be true for the identity element"
+        );
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+
+        if r == 0 {
+            return 0;
+        }
+
+        let mut r = r + self.size;
+        for i in (1..=self.log).rev() {
+            self.push((r - 1) >> i);
+        }
+        let mut sum = M::id();
+        loop {
+            r -= 1;
+            // Move up while r is a right child.
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            // If the predicate fails with this node, descend to find the boundary.
+            if !f(&M::op(&self.data[r], &sum)) {
+                while r < self.size {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let t = M::op(&self.data[r], &sum);
+                    if f(&t) {
+                        sum = t;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            sum = M::op(&self.data[r], &sum);
+            // Stop once the whole tree to the left has been consumed.
+            if r.is_power_of_two() {
+                break;
+            }
+        }
+        0
+    }
+}
@@ -0,0 +1,277 @@
+//! A merge-sort tree for point updates and rectangle folds on a sparse set of points.
+//! 疎な点集合上で一点更新と長方形の畳み込みを行う `merge-sort tree` である.
+
+use super::{super::super::algebra::monoid::Monoid, segment_tree_dense::SegmentTreeDense};
+
+/// A merge-sort tree over points whose coordinates may be as large as `i64`.
+/// All points are registered with `add_point` before `build`, and afterwards their values can be
+/// updated and folded over rectangles. The points are sorted by `x` and laid out on a segment tree,
+/// each of whose nodes keeps its points sorted by `y` together with a `SegmentTreeDense` over them.
+///
+/// 座標が `i64` 程度まで大きくなりうる点の上の `merge-sort tree` である.
+/// 全ての点を `build` の前に `add_point` で登録し, その後に値の更新と長方形上の畳み込みを行う.
+/// 点は `x` でソートされて `segment tree` 上に並べられ, 各ノードは自身の点を `y` でソートした列と,
+/// その上の `SegmentTreeDense` を保持する.
+#[derive(Clone)]
+pub struct MergeSortTree<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    // The distinct points sorted by `(x, y)`; the leaves of the outer tree.
+    points: Vec<(i64, i64)>,
+    built: bool,
+    // `ys[k]` holds `(y, leaf)` of the points under the node `k`, sorted.
+    ys: Vec<Vec<(i64, usize)>>,
+    trees: Vec<SegmentTreeDense<M>>,
+}
+
+impl<M> Default for MergeSortTree<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> MergeSortTree<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    /// Creates a new `MergeSortTree` without any points.
+    /// 点を持たない `MergeSortTree` を生成する.
+    ///
+    /// # Returns
+    /// `MergeSortTree<M>`: Returns a newly created instance.
+    ///                     新しいインスタンスを返す.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::merge_sort_tree};
+    /// let mut mst = merge_sort_tree::MergeSortTree::<monoid::AddMonoid>::new();
+    /// mst.add_point(1_000_000_000, -1_000_000_000);
+    /// mst.build();
+    /// assert_eq!(mst.len(), 1);
+    /// ```
+    pub fn new() -> Self {
+        MergeSortTree {
+            points: Vec::new(),
+            built: false,
+            ys: Vec::new(),
+            trees: Vec::new(),
+        }
+    }
+
+    /// Returns the number of distinct points.
+    /// 異なる点の個数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of distinct points, counted after `build`.
+    ///          異なる点の個数. `build` の後に数えられる.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if there are no points.
+    /// 点が存在しない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether there are no points.
+    ///         点が存在しないかどうか.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Registers the point `(x, y)`, whose value starts at `M::id()`.
+    /// Registering the same point more than once has no further effect.
+    ///
+    /// 値が `M::id()` から始まる点 `(x, y)` を登録する.
+    /// 同じ点を複数回登録しても追加の効果はない.
+    ///
+    /// # Args
+    /// - `x`: The x-coordinate.
+    ///   x 座標.
+    /// - `y`: The y-coordinate.
+    ///   y 座標.
+    ///
+    /// # Panics
+    /// Panics if called after `build`.
+    /// `build` の後に呼び出された場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: Amortized O(1).
+    ///   ならし O(1).
+    pub fn add_point(&mut self, x: i64, y: i64) {
+        assert!(!self.built, "Points cannot be added after build");
+        self.points.push((x, y));
+    }
+
+    /// Builds the tree over the registered points.
+    /// 登録された点の上に木を構築する.
+    ///
+    /// # Panics
+    /// Panics if called more than once.
+    /// 二回以上呼び出された場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the number of points.
+    ///   ここで N は点の個数である.
+    /// - Space complexity: O(N log N).
+    pub fn build(&mut self) {
+        assert!(!self.built, "The tree has already been built");
+        self.built = true;
+        self.points.sort_unstable();
+        self.points.dedup();
+
+        // Leaves are at `n + i`, so every node `k` has the children `2k` and `2k + 1`.
+        let n = self.points.len();
+        self.ys = vec![Vec::new(); 2 * n];
+        for (i, &(_, y)) in self.points.iter().enumerate() {
+            self.ys[n + i].push((y, i));
+        }
+        for k in (1..n).rev() {
+            let (left, right) = (&self.ys[2 * k], &self.ys[2 * k + 1]);
+            let mut merged = Vec::with_capacity(left.len() + right.len());
+            let (mut a, mut b) = (0, 0);
+            while a < left.len() || b < right.len() {
+                if b == right.len() || (a < left.len() && left[a] < right[b]) {
+                    merged.push(left[a]);
+                    a += 1;
+                } else {
+                    merged.push(right[b]);
+                    b += 1;
+                }
+            }
+            self.ys[k] = merged;
+        }
+        self.trees = self
+            .ys
+            .iter()
+            .map(|ys| SegmentTreeDense::new(ys.len()))
+            .collect();
+    }
+
+    fn check_built(&self) {
+        assert!(self.built, "The tree must be built before queries");
+    }
+
+    /// Updates the value at the point `(x, y)` to `v`.
+    /// 点 `(x, y)` の値を `v` に更新する.
+    ///
+    /// # Args
+    /// - `x`: The x-coordinate.
+    ///   x 座標.
+    /// - `y`: The y-coordinate.
+    ///   y 座標.
+    /// - `v`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if the tree is not built, or `(x, y)` has not been registered.
+    /// 木が構築されていない場合, または `(x, y)` が登録されていない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log^2 N), where N is the number of points.
+    ///   ここで N は点の個数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::merge_sort_tree};
+    /// let mut mst = merge_sort_tree::MergeSortTree::<monoid::AddMonoid>::new();
+    /// mst.add_point(3, 5);
+    /// mst.add_point(7, 1);
+    /// mst.build();
+    /// mst.update(3, 5, 10);
+    /// mst.update(7, 1, 20);
+    /// mst.update(3, 5, 1);
+    /// assert_eq!(mst.rect_fold(0, 10, 0, 10), 21);
+    /// ```
+    pub fn update(&mut self, x: i64, y: i64, v: M::S) {
+        self.check_built();
+        let leaf = self
+            .points
+            .binary_search(&(x, y))
+            .unwrap_or_else(|_| panic!("The point ({}, {}) has not been added", x, y));
+
+        let mut k = self.points.len() + leaf;
+        while k > 0 {
+            let pos = self.ys[k].binary_search(&(y, leaf)).unwrap();
+            self.trees[k].update(pos, v.clone());
+            k >>= 1;
+        }
+    }
+
+    /// Folds the values of the points in the rectangle `[x1, x2) x [y1, y2)`.
+    /// 長方形 `[x1, x2) x [y1, y2)` に含まれる点の値を畳み込む.
+    ///
+    /// # Args
+    /// - `x1`: The start of the x-range (inclusive).
+    ///   x の範囲の開始 (含む).
+    /// - `x2`: The end of the x-range (exclusive).
+    ///   x の範囲の終了 (含まない).
+    /// - `y1`: The start of the y-range (inclusive).
+    ///   y の範囲の開始 (含む).
+    /// - `y2`: The end of the y-range (exclusive).
+    ///   y の範囲の終了 (含まない).
+    ///
+    /// # Returns
+    /// `M::S`: The fold of the values, which is `M::id()` if no point lies in the rectangle.
+    ///         値の畳み込み. 長方形内に点がなければ `M::id()`.
+    ///
+    /// # Constraints
+    /// - `M::op` is commutative, since the points are not folded in a fixed order.
+    ///   点は決まった順で畳み込まれないため, `M::op` が可換である.
+    ///
+    /// # Panics
+    /// Panics if the tree is not built.
+    /// 木が構築されていない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log^2 N), where N is the number of points.
+    ///   ここで N は点の個数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::merge_sort_tree};
+    /// let mut mst = merge_sort_tree::MergeSortTree::<monoid::MaxMonoid>::new();
+    /// for (x, y, _) in [(1, 1, 3), (1_000_000_000, 2, 9), (5, 1_000_000_000, 4)] {
+    ///     mst.add_point(x, y);
+    /// }
+    /// mst.build();
+    /// for (x, y, v) in [(1, 1, 3), (1_000_000_000, 2, 9), (5, 1_000_000_000, 4)] {
+    ///     mst.update(x, y, v);
+    /// }
+    /// assert_eq!(mst.rect_fold(0, 10, 0, 1_000_000_001), 4);
+    /// assert_eq!(mst.rect_fold(0, 1_000_000_001, 0, 10), 9);
+    /// ```
+    pub fn rect_fold(&self, x1: i64, x2: i64, y1: i64, y2: i64) -> M::S {
+        self.check_built();
+        let n = self.points.len();
+        let mut l = n + self.points.partition_point(|&(x, _)| x < x1);
+        let mut r = n + self.points.partition_point(|&(x, _)| x < x2);
+        let mut sum = M::id();
+        while l < r {
+            if l & 1 == 1 {
+                sum = M::op(&sum, &self.fold_node(l, y1, y2));
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                sum = M::op(&sum, &self.fold_node(r, y1, y2));
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        sum
+    }
+
+    // Folds the points of the node `k` whose y-coordinates are in `[y1, y2)`.
+    fn fold_node(&self, k: usize, y1: i64, y2: i64) -> M::S {
+        let lo = self.ys[k].partition_point(|&(y, _)| y < y1);
+        let hi = self.ys[k].partition_point(|&(y, _)| y < y2);
+        self.trees[k].fold(lo, hi)
+    }
+}
@@ -0,0 +1,570 @@
+//! A fully persistent segment tree whose updates return new versions sharing structure with the old ones.
+//! 更新のたびに, 古い版と構造を共有する新しい版を返す完全永続 `segment tree` である.
+
+use super::super::super::algebra::{group::Group, monoid::Monoid};
+
+// The arena index of the node standing for a subtree whose elements are all the identity.
+// Its children point to itself, so it can be shared by every version.
+const EMPTY: usize = 0;
+
+#[derive(Clone)]
+struct Node<S> {
+    value: S,
+    left: usize,
+    right: usize,
+}
+
+/// A handle to a version of a `PersistentSegmentTree`.
+/// It is only meaningful for the tree that returned it.
+///
+/// `PersistentSegmentTree` のある版へのハンドルである.
+/// これを返した木に対してのみ意味を持つ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Version(usize);
+
+/// A fully persistent segment tree over `n` elements.
+/// Every version is kept alive, and an update copies only the O(log n) nodes on one path.
+///
+/// `n` 個の要素上の完全永続 `segment tree` である.
+/// 全ての版が保持され, 一回の更新では一つの経路上の O(log n) 個のノードのみを複製する.
+#[derive(Clone)]
+pub struct PersistentSegmentTree<M>
+where
+    M: Monoid,
+{
+    len: usize,
+    nodes: Vec<Node<M::S>>,
+}
+
+impl<M> PersistentSegmentTree<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    /// Creates a new `PersistentSegmentTree` with `n` elements.
+    /// `n` 個の要素を持つ `PersistentSegmentTree` を生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// `PersistentSegmentTree<M>`: Returns a newly created segment tree instance.
+    ///                             新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// let seg = persistent_segment_tree::PersistentSegmentTree::<monoid::AddMonoid>::new(5);
+    /// let v0 = seg.initial();
+    /// assert_eq!(seg.fold(v0, 0, 5), 0);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        PersistentSegmentTree::<M> {
+            len: n,
+            nodes: vec![Node {
+                value: M::id(),
+                left: EMPTY,
+                right: EMPTY,
+            }],
+        }
+    }
+
+    /// Returns the number of elements.
+    /// 要素数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements.
+    ///          要素数.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    /// 要素が存在しない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether there are no elements.
+    ///         要素が存在しないかどうか.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of nodes allocated over all versions.
+    /// 全ての版を通じて確保されたノード数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of allocated nodes.
+    ///          確保されたノード数.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the version in which every element is the identity.
+    /// 全ての要素が単位元である版を返す.
+    ///
+    /// # Returns
+    /// `Version`: The initial version.
+    ///            初期の版.
+    pub fn initial(&self) -> Version {
+        Version(EMPTY)
+    }
+
+    fn push(&mut self, value: M::S, left: usize, right: usize) -> usize {
+        self.nodes.push(Node { value, left, right });
+        self.nodes.len() - 1
+    }
+
+    fn check_index(&self, idx: usize) {
+        assert!(
+            idx < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            idx
+        );
+    }
+
+    /// Creates a new version holding the elements of `v`.
+    /// `v` の要素を持つ新しい版を生成する.
+    ///
+    /// # Args
+    /// - `v`: The elements.
+    ///   要素.
+    ///
+    /// # Returns
+    /// `Version`: The new version.
+    ///            新しい版.
+    ///
+    /// # Panics
+    /// Panics if `v.len() != self.len()`.
+    /// `v.len() != self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// let mut seg = persistent_segment_tree::PersistentSegmentTree::<monoid::AddMonoid>::new(3);
+    /// let v = seg.build(&[1, 2, 3]);
+    /// assert_eq!(seg.fold(v, 1, 3), 5);
+    /// ```
+    pub fn build(&mut self, v: &[M::S]) -> Version {
+        assert_eq!(
+            v.len(),
+            self.len(),
+            "The number of values must equal the len (values: {}, len: {})",
+            v.len(),
+            self.len()
+        );
+        if v.is_empty() {
+            return self.initial();
+        }
+        Version(self.build_rec(v))
+    }
+
+    fn build_rec(&mut self, v: &[M::S]) -> usize {
+        if v.len() == 1 {
+            return self.push(v[0].clone(), EMPTY, EMPTY);
+        }
+        let (l, r) = v.split_at(v.len() / 2);
+        let left = self.build_rec(l);
+        let right = self.build_rec(r);
+        let value = M::op(&self.nodes[left].value, &self.nodes[right].value);
+        self.push(value, left, right)
+    }
+
+    /// Returns a new version in which the value at index `idx` is `x`.
+    /// The given version is left unchanged.
+    ///
+    /// インデックス `idx` の値を `x` とした新しい版を返す.
+    /// 与えられた版は変更されない.
+    ///
+    /// # Args
+    /// - `version`: The version to update.
+    ///   更新元の版.
+    /// - `idx`: The index to update.
+    ///   更新対象のインデックス.
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Returns
+    /// `Version`: The new version.
+    ///            新しい版.
+    ///
+    /// # Panics
+    /// Panics if `idx >= self.len()`.
+    /// `idx >= self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    /// - Space complexity: O(log(n)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// let mut seg = persistent_segment_tree::PersistentSegmentTree::<monoid::AddMonoid>::new(4);
+    /// let v0 = seg.build(&[1, 2, 3, 4]);
+    /// let v1 = seg.update(v0, 2, 10);
+    /// assert_eq!(seg.fold(v0, 0, 4), 10);
+    /// assert_eq!(seg.fold(v1, 0, 4), 17);
+    /// ```
+    pub fn update(&mut self, version: Version, idx: usize, x: M::S) -> Version {
+        self.check_index(idx);
+        Version(self.update_rec(version.0, 0, self.len, idx, x))
+    }
+
+    fn update_rec(&mut self, node: usize, lo: usize, hi: usize, idx: usize, x: M::S) -> usize {
+        if hi - lo == 1 {
+            return self.push(x, EMPTY, EMPTY);
+        }
+        let m = lo + (hi - lo) / 2;
+        let (mut left, mut right) = (self.nodes[node].left, self.nodes[node].right);
+        if idx < m {
+            left = self.update_rec(left, lo, m, idx, x);
+        } else {
+            right = self.update_rec(right, m, hi, idx, x);
+        }
+        let value = M::op(&self.nodes[left].value, &self.nodes[right].value);
+        self.push(value, left, right)
+    }
+
+    /// Gets the value at index `idx` in `version`.
+    /// `version` におけるインデックス `idx` の値を取得する.
+    ///
+    /// # Args
+    /// - `version`: The version to read.
+    ///   参照する版.
+    /// - `idx`: The index to retrieve.
+    ///   値を取得するインデックス.
+    ///
+    /// # Returns
+    /// `M::S`: The value at `idx`.
+    ///         `idx` の値.
+    ///
+    /// # Panics
+    /// Panics if `idx >= self.len()`.
+    /// `idx >= self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    pub fn get(&self, version: Version, idx: usize) -> M::S {
+        self.check_index(idx);
+        let (mut node, mut lo, mut hi) = (version.0, 0, self.len);
+        while hi - lo > 1 {
+            let m = lo + (hi - lo) / 2;
+            if idx < m {
+                node = self.nodes[node].left;
+                hi = m;
+            } else {
+                node = self.nodes[node].right;
+                lo = m;
+            }
+        }
+        self.nodes[node].value.clone()
+    }
+
+    /// Performs a range fold (query) on the interval `[l, r)` of `version`.
+    /// `version` の区間 `[l, r)` 上の値に対して `fold` (畳み込み) を行う.
+    ///
+    /// # Args
+    /// - `version`: The version to read.
+    ///   参照する版.
+    /// - `l`: The start index of the range (inclusive).
+    ///   `query` 区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   `query` 区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `M::S`: The folded result of the interval `[l, r)`. It is the identity element `M::id()` if the range is empty.
+    ///         区間 `[l, r)` の畳み込み結果. 区間が空の場合, 単位元 `M::id()` となる.
+    ///
+    /// # Panics
+    /// Panics if `r > self.len()`.
+    /// `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// let mut seg = persistent_segment_tree::PersistentSegmentTree::<monoid::MaxMonoid>::new(5);
+    /// let v0 = seg.build(&[5, 1, 4, 2, 3]);
+    /// let v1 = seg.update(v0, 1, 9);
+    /// assert_eq!(seg.fold(v0, 1, 4), 4);
+    /// assert_eq!(seg.fold(v1, 1, 4), 9);
+    /// ```
+    pub fn fold(&self, version: Version, l: usize, r: usize) -> M::S {
+        if l >= r {
+            return M::id();
+        }
+
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+
+        self.fold_rec(version.0, 0, self.len, l, r)
+    }
+
+    fn fold_rec(&self, node: usize, lo: usize, hi: usize, l: usize, r: usize) -> M::S {
+        if node == EMPTY || r <= lo || hi <= l {
+            return M::id();
+        }
+        if l <= lo && hi <= r {
+            return self.nodes[node].value.clone();
+        }
+        let m = lo + (hi - lo) / 2;
+        M::op(
+            &self.fold_rec(self.nodes[node].left, lo, m, l, r),
+            &self.fold_rec(self.nodes[node].right, m, hi, l, r),
+        )
+    }
+
+    /// Finds the maximum `r` in `[l, self.len()]` such that `f` applied to the fold result
+    /// from `[l, r)` of `version` is `true`.
+    /// `version` において, `[l, r)` の `fold` 結果に対して述語 `f` が `true` を返すような
+    /// 区間 `[l, self.len()]` 内の最大の `r` を探索する.
+    ///
+    /// # Args
+    /// - `version`: The version to read.
+    ///   参照する版.
+    /// - `l`: The start index of the range.
+    ///   範囲の開始インデックス.
+    /// - `f`: A monotone predicate that takes a reference to `M::S` and returns a boolean.
+    ///   `M::S` への参照を受け取り, 真偽値を返す単調な述語.
+    ///
+    /// # Returns
+    /// `usize`: The maximum `r` such that `f(fold(version, l, r))` is `true`.
+    ///          `f(fold(version, l, r))` が `true` となる最大の `r`.
+    ///
+    /// # Panics
+    /// Panics if `f(&M::id())` is false or `l > self.len()`.
+    /// `f(&M::id())` が `false` の場合, または `l > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// let mut seg = persistent_segment_tree::PersistentSegmentTree::<monoid::AddMonoid>::new(5);
+    /// let v = seg.build(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(seg.max_right(v, 1, |&sum| sum < 10), 4);
+    /// ```
+    pub fn max_right<F>(&self, version: Version, l: usize, f: F) -> usize
+    where
+        F: Fn(&M::S) -> bool,
+    {
+        assert!(
+            f(&M::id()),
+            "predicate must be true for the identity element"
+        );
+        assert!(
+            l <= self.len(),
+            "index out of bounds: l must be less than or equal to the len (l: {}, len: {})",
+            l,
+            self.len()
+        );
+
+        let mut sum = M::id();
+        self.max_right_rec(version.0, 0, self.len, l, &f, &mut sum)
+            .unwrap_or(self.len)
+    }
+
+    // Returns the boundary if it lies in `[lo, hi)`, otherwise folds the part right of `l` into `sum`.
+    fn max_right_rec<F>(
+        &self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        l: usize,
+        f: &F,
+        sum: &mut M::S,
+    ) -> Option<usize>
+    where
+        F: Fn(&M::S) -> bool,
+    {
+        // The empty node folds to the identity, which keeps `f(sum)` true.
+        if node == EMPTY || hi <= l {
+            return None;
+        }
+        if l <= lo {
+            let t = M::op(sum, &self.nodes[node].value);
+            if f(&t) {
+                *sum = t;
+                return None;
+            }
+            if hi - lo == 1 {
+                return Some(lo);
+            }
+        }
+        let m = lo + (hi - lo) / 2;
+        self.max_right_rec(self.nodes[node].left, lo, m, l, f, sum)
+            .or_else(|| self.max_right_rec(self.nodes[node].right, m, hi, l, f, sum))
+    }
+
+    /// Returns the indices at which the values of two versions differ, in increasing order.
+    /// Subtrees shared by both versions are skipped, so the cost depends only on the changed part.
+    ///
+    /// 二つの版で値が異なるインデックスを昇順に返す.
+    /// 両方の版で共有される部分木は読み飛ばされるため, 計算量は変更された部分のみに依存する.
+    ///
+    /// # Args
+    /// - `a`: The first version.
+    ///   一つ目の版.
+    /// - `b`: The second version.
+    ///   二つ目の版.
+    ///
+    /// # Returns
+    /// `Vec<usize>`: The indices whose values differ.
+    ///               値が異なるインデックス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(K log(n)), where K is the number of nodes that are not shared
+    ///   by the two versions, which is at most the number of updates
+    ///   between them if one derives from the other.
+    ///   ここで K は二つの版で共有されていないノード数であり,
+    ///   一方が他方から派生した場合はその間の更新回数以下である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// let mut seg = persistent_segment_tree::PersistentSegmentTree::<monoid::AddMonoid>::new(6);
+    /// let v0 = seg.build(&[0, 1, 2, 3, 4, 5]);
+    /// let v1 = seg.update(v0, 4, 7);
+    /// let v2 = seg.update(v1, 1, 1);
+    /// let v3 = seg.update(v2, 0, 8);
+    /// assert_eq!(seg.diff(v0, v3), vec![0, 4]);
+    /// ```
+    pub fn diff(&self, a: Version, b: Version) -> Vec<usize>
+    where
+        M::S: PartialEq,
+    {
+        let mut result = Vec::new();
+        if !self.is_empty() {
+            self.diff_rec(a.0, b.0, 0, self.len, &mut result);
+        }
+        result
+    }
+
+    fn diff_rec(&self, a: usize, b: usize, lo: usize, hi: usize, result: &mut Vec<usize>)
+    where
+        M::S: PartialEq,
+    {
+        if a == b {
+            return;
+        }
+        if hi - lo == 1 {
+            if self.nodes[a].value != self.nodes[b].value {
+                result.push(lo);
+            }
+            return;
+        }
+        let m = lo + (hi - lo) / 2;
+        self.diff_rec(self.nodes[a].left, self.nodes[b].left, lo, m, result);
+        self.diff_rec(self.nodes[a].right, self.nodes[b].right, m, hi, result);
+    }
+}
+
+impl<G> PersistentSegmentTree<G>
+where
+    G: Group,
+    G::S: Clone,
+{
+    /// Finds the maximum `r` in `[0, self.len()]` such that `f` is `true` for the difference
+    /// `fold(new, 0, r) - fold(old, 0, r)` between two versions.
+    /// With a count monoid, where version `i` counts the values among the first `i` elements,
+    /// this finds the k-th smallest value in a range of the sequence.
+    ///
+    /// 二つの版の差 `fold(new, 0, r) - fold(old, 0, r)` に対して `f` が `true` となるような
+    /// 区間 `[0, self.len()]` 内の最大の `r` を探索する.
+    /// 版 `i` が列の先頭 `i` 要素に含まれる値を数える個数のモノイドでは, 列の区間で k 番目に小さい値を求めることになる.
+    ///
+    /// # Args
+    /// - `old`: The version to subtract.
+    ///   差し引く版.
+    /// - `new`: The version to subtract from.
+    ///   差し引かれる版.
+    /// - `f`: A monotone predicate that takes a reference to `G::S` and returns a boolean.
+    ///   `G::S` への参照を受け取り, 真偽値を返す単調な述語.
+    ///
+    /// # Returns
+    /// `usize`: The maximum `r` such that `f` holds for the difference over `[0, r)`.
+    ///          `[0, r)` 上の差に対して `f` が成り立つ最大の `r`.
+    ///
+    /// # Constraints
+    /// - `G::op` is commutative.
+    ///   `G::op` が可換である.
+    ///
+    /// # Panics
+    /// Panics if `f(&G::id())` is false.
+    /// `f(&G::id())` が `false` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::persistent_segment_tree};
+    /// // Version `i + 1` counts the values in `a[..=i]`, which are at most 4.
+    /// let a = [3, 1, 4, 1, 2];
+    /// let mut seg = persistent_segment_tree::PersistentSegmentTree::<monoid::AddMonoid>::new(5);
+    /// let mut versions = vec![seg.initial()];
+    /// for &x in a.iter() {
+    ///     let last = *versions.last().unwrap();
+    ///     let count = seg.get(last, x);
+    ///     versions.push(seg.update(last, x, count + 1));
+    /// }
+    /// // The sorted values of `a[1..4]` are [1, 1, 4], so the one at index 2 is 4.
+    /// let k = 2;
+    /// assert_eq!(seg.max_right_diff(versions[1], versions[4], |&c| c <= k), 4);
+    /// ```
+    pub fn max_right_diff<F>(&self, old: Version, new: Version, f: F) -> usize
+    where
+        F: Fn(&G::S) -> bool,
+    {
+        assert!(
+            f(&G::id()),
+            "predicate must be true for the identity element"
+        );
+
+        let (mut a, mut b, mut lo, mut hi) = (old.0, new.0, 0, self.len);
+        let mut sum = G::id();
+        let diff = |a: usize, b: usize| G::op(&self.nodes[b].value, &G::inv(&self.nodes[a].value));
+        if hi == 0 || f(&G::op(&sum, &diff(a, b))) {
+            return self.len;
+        }
+
+        // The boundary lies in `[lo, hi)`, and `sum` is the difference over `[0, lo)`.
+        while hi - lo > 1 {
+            let m = lo + (hi - lo) / 2;
+            let (al, bl) = (self.nodes[a].left, self.nodes[b].left);
+            let t = G::op(&sum, &diff(al, bl));
+            if f(&t) {
+                sum = t;
+                a = self.nodes[a].right;
+                b = self.nodes[b].right;
+                lo = m;
+            } else {
+                a = al;
+                b = bl;
+                hi = m;
+            }
+        }
+        lo
+    }
+}
@@ -0,0 +1,317 @@
+//! A two-dimensional segment tree for point updates and rectangle folds on a dense grid.
+//! 密なグリッド上で一点更新と長方形の畳み込みを行う二次元 `segment tree` である.
+
+use super::super::super::algebra::monoid::Monoid;
+
+/// A two-dimensional segment tree over an `h` by `w` grid.
+/// Rows form an outer segment tree whose nodes are segment trees over the columns,
+/// both laid out bottom-up with the leaves at `h + i` and `w + j`.
+///
+/// `h` 行 `w` 列のグリッド上の二次元 `segment tree` である.
+/// 行が外側の `segment tree` をなし, その各ノードが列上の `segment tree` となる.
+/// どちらも葉が `h + i`, `w + j` に位置するボトムアップの配置である.
+#[derive(Clone)]
+pub struct SegmentTree2D<M>
+where
+    M: Monoid,
+{
+    h: usize,
+    w: usize,
+    // `data[a * 2w + b]` is the node `b` of the column tree held by the row node `a`.
+    data: Vec<M::S>,
+}
+
+impl<M> SegmentTree2D<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    /// Creates a new `SegmentTree2D` with `h` rows and `w` columns, all initialized to `M::id()`.
+    /// 全要素が `M::id()` で初期化された, `h` 行 `w` 列の `SegmentTree2D` を生成する.
+    ///
+    /// # Args
+    /// - `h`: The number of rows.
+    ///   行数.
+    /// - `w`: The number of columns.
+    ///   列数.
+    ///
+    /// # Returns
+    /// `SegmentTree2D<M>`: Returns a newly created segment tree instance.
+    ///                     新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(HW), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    /// - Space complexity: O(HW).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_2d};
+    /// let seg = segment_tree_2d::SegmentTree2D::<monoid::AddMonoid>::new(2, 3);
+    /// assert_eq!(seg.shape(), (2, 3));
+    /// ```
+    pub fn new(h: usize, w: usize) -> Self {
+        SegmentTree2D::<M> {
+            h,
+            w,
+            data: vec![M::id(); 4 * h * w],
+        }
+    }
+
+    /// Returns the numbers of rows and columns.
+    /// 行数と列数を返す.
+    ///
+    /// # Returns
+    /// `(usize, usize)`: The numbers of rows and columns.
+    ///                   行数と列数.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.h, self.w)
+    }
+
+    fn at(&self, a: usize, b: usize) -> usize {
+        a * 2 * self.w + b
+    }
+
+    fn check_index(&self, i: usize, j: usize) {
+        assert!(
+            i < self.h && j < self.w,
+            "index out of bounds: the shape is ({}, {}) but the index is ({}, {})",
+            self.h,
+            self.w,
+            i,
+            j
+        );
+    }
+
+    /// Sets the value at `(i, j)` to `x`.
+    /// The update is lazy; use `build` or `update` to propagate changes to parent nodes.
+    /// `(i, j)` の値を `x` にセットする.
+    /// この更新は遅延実行されるため, 上位ノードへ変更を反映するには `build` または `update` を使用する.
+    ///
+    /// # Args
+    /// - `i`: The row.
+    ///   行.
+    /// - `j`: The column.
+    ///   列.
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if `i >= h` or `j >= w`.
+    /// `i >= h` または `j >= w` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn set(&mut self, i: usize, j: usize, x: M::S) {
+        self.check_index(i, j);
+        let k = self.at(self.h + i, self.w + j);
+        self.data[k] = x;
+    }
+
+    /// Builds the segment tree by propagating the leaves' values up to their parent nodes.
+    /// 葉に設定された値を親ノードへ伝播させて, `segment tree` を構築する.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(HW), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_2d};
+    /// let mut seg = segment_tree_2d::SegmentTree2D::<monoid::AddMonoid>::new(2, 2);
+    /// seg.set(0, 0, 1);
+    /// seg.set(1, 1, 2);
+    /// seg.build();
+    /// assert_eq!(seg.fold(0, 2, 0, 2), 3);
+    /// ```
+    pub fn build(&mut self) {
+        // Build the column trees of the leaf rows, then merge rows node by node.
+        for a in self.h..2 * self.h {
+            for b in (1..self.w).rev() {
+                self.pull_column(a, b);
+            }
+        }
+        for a in (1..self.h).rev() {
+            for b in 1..2 * self.w {
+                self.pull_row(a, b);
+            }
+        }
+    }
+
+    fn pull_column(&mut self, a: usize, b: usize) {
+        let (l, r) = (self.at(a, 2 * b), self.at(a, 2 * b + 1));
+        let k = self.at(a, b);
+        self.data[k] = M::op(&self.data[l], &self.data[r]);
+    }
+
+    fn pull_row(&mut self, a: usize, b: usize) {
+        let (l, r) = (self.at(2 * a, b), self.at(2 * a + 1, b));
+        let k = self.at(a, b);
+        self.data[k] = M::op(&self.data[l], &self.data[r]);
+    }
+
+    /// Updates the value at `(i, j)` to `x` and propagates this change up the tree.
+    /// `(i, j)` の値を `x` に更新し, 上位ノードへ変更を反映する.
+    ///
+    /// # Args
+    /// - `i`: The row.
+    ///   行.
+    /// - `j`: The column.
+    ///   列.
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if `i >= h` or `j >= w`.
+    /// `i >= h` または `j >= w` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log H log W), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_2d};
+    /// let mut seg = segment_tree_2d::SegmentTree2D::<monoid::MaxMonoid>::new(3, 3);
+    /// seg.update(1, 2, 5);
+    /// seg.update(2, 0, 7);
+    /// assert_eq!(seg.fold(0, 2, 0, 3), 5);
+    /// assert_eq!(seg.fold(0, 3, 0, 3), 7);
+    /// ```
+    pub fn update(&mut self, i: usize, j: usize, x: M::S) {
+        self.set(i, j, x);
+        let (mut a, leaf) = (self.h + i, self.w + j);
+        let mut b = leaf >> 1;
+        while b > 0 {
+            self.pull_column(a, b);
+            b >>= 1;
+        }
+        a >>= 1;
+        while a > 0 {
+            let mut b = leaf;
+            while b > 0 {
+                self.pull_row(a, b);
+                b >>= 1;
+            }
+            a >>= 1;
+        }
+    }
+
+    /// Gets the value at `(i, j)`.
+    /// `(i, j)` の値を取得する.
+    ///
+    /// # Args
+    /// - `i`: The row.
+    ///   行.
+    /// - `j`: The column.
+    ///   列.
+    ///
+    /// # Returns
+    /// `M::S`: The value at `(i, j)`.
+    ///         `(i, j)` の値.
+    ///
+    /// # Panics
+    /// Panics if `i >= h` or `j >= w`.
+    /// `i >= h` または `j >= w` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn get(&self, i: usize, j: usize) -> M::S {
+        self.check_index(i, j);
+        self.data[self.at(self.h + i, self.w + j)].clone()
+    }
+
+    /// Folds the rectangle of rows `[i1, i2)` and columns `[j1, j2)`.
+    /// 行 `[i1, i2)`, 列 `[j1, j2)` の長方形を畳み込む.
+    ///
+    /// # Args
+    /// - `i1`: The start row (inclusive).
+    ///   開始行 (含む).
+    /// - `i2`: The end row (exclusive).
+    ///   終了行 (含まない).
+    /// - `j1`: The start column (inclusive).
+    ///   開始列 (含む).
+    /// - `j2`: The end column (exclusive).
+    ///   終了列 (含まない).
+    ///
+    /// # Returns
+    /// `M::S`: The fold of the rectangle, which is `M::id()` if it is empty.
+    ///         長方形の畳み込み. 空なら `M::id()`.
+    ///
+    /// # Constraints
+    /// - `M::op` is commutative, since the elements are not folded in a fixed order.
+    ///   要素は決まった順で畳み込まれないため, `M::op` が可換である.
+    ///
+    /// # Panics
+    /// Panics if the rectangle is not empty and `i2 > h` or `j2 > w`.
+    /// 長方形が空でなく, `i2 > h` または `j2 > w` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log H log W), where H and W are the numbers of rows and columns.
+    ///   ここで H, W はそれぞれ行数, 列数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_2d};
+    /// let mut seg = segment_tree_2d::SegmentTree2D::<monoid::AddMonoid>::new(3, 3);
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         seg.set(i, j, (3 * i + j) as i64);
+    ///     }
+    /// }
+    /// seg.build();
+    /// // 4 + 5 + 7 + 8
+    /// assert_eq!(seg.fold(1, 3, 1, 3), 24);
+    /// ```
+    pub fn fold(&self, i1: usize, i2: usize, j1: usize, j2: usize) -> M::S {
+        if i1 >= i2 || j1 >= j2 {
+            return M::id();
+        }
+
+        assert!(
+            i2 <= self.h && j2 <= self.w,
+            "index out of bounds: the rectangle [{}, {}) x [{}, {}) is not within the shape ({}, {})",
+            i1,
+            i2,
+            j1,
+            j2,
+            self.h,
+            self.w
+        );
+
+        let (mut l, mut r) = (i1 + self.h, i2 + self.h);
+        let mut sum = M::id();
+        while l < r {
+            if l & 1 == 1 {
+                sum = M::op(&sum, &self.fold_row(l, j1, j2));
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                sum = M::op(&sum, &self.fold_row(r, j1, j2));
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        sum
+    }
+
+    // Folds the columns `[j1, j2)` of the column tree held by the row node `a`.
+    fn fold_row(&self, a: usize, j1: usize, j2: usize) -> M::S {
+        let (mut l, mut r) = (j1 + self.w, j2 + self.w);
+        let mut sum = M::id();
+        while l < r {
+            if l & 1 == 1 {
+                sum = M::op(&sum, &self.data[self.at(a, l)]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                sum = M::op(&sum, &self.data[self.at(a, r)]);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        sum
+    }
+}
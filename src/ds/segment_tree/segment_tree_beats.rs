@@ -0,0 +1,552 @@
+//! A segment tree beats (Ji Driver segment tree) for range chmin, chmax, add and assign on `i64`.
+//! `i64` 上の区間 chmin, chmax, 加算, 代入をサポートする `segment tree beats` (Ji Driver segment tree) である.
+
+// The identities of the min and max folds.
+const NEG: i64 = i64::MIN;
+const POS: i64 = i64::MAX;
+
+#[derive(Clone)]
+struct Node {
+    // The maximum, the second strict maximum (`None` if every value is the maximum) and the
+    // count of the maximum.
+    max1: i64,
+    max2: Option<i64>,
+    max_cnt: i64,
+    // The minimum, the second strict minimum (`None` if every value is the minimum) and the
+    // count of the minimum.
+    min1: i64,
+    min2: Option<i64>,
+    min_cnt: i64,
+    // The sum modulo 2^64, which is exact whenever the true sum fits in `i64`.
+    sum: i64,
+    len: i64,
+    // The pending addition to the children.
+    lazy: i64,
+}
+
+// Returns the smaller of two optional values, treating `None` as absent.
+fn min_opt(a: Option<i64>, b: Option<i64>) -> Option<i64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        _ => a.or(b),
+    }
+}
+
+impl Node {
+    fn leaf(x: i64) -> Self {
+        Node {
+            max1: x,
+            max2: None,
+            max_cnt: 1,
+            min1: x,
+            min2: None,
+            min_cnt: 1,
+            sum: x,
+            len: 1,
+            lazy: 0,
+        }
+    }
+
+    fn merge(a: &Node, b: &Node) -> Self {
+        // `None` is less than every `Some`, so `Option::max` already ignores a missing value.
+        let (max1, max2, max_cnt) = match a.max1.cmp(&b.max1) {
+            std::cmp::Ordering::Greater => (a.max1, a.max2.max(Some(b.max1)), a.max_cnt),
+            std::cmp::Ordering::Less => (b.max1, Some(a.max1).max(b.max2), b.max_cnt),
+            std::cmp::Ordering::Equal => (a.max1, a.max2.max(b.max2), a.max_cnt + b.max_cnt),
+        };
+        let (min1, min2, min_cnt) = match a.min1.cmp(&b.min1) {
+            std::cmp::Ordering::Less => (a.min1, min_opt(a.min2, Some(b.min1)), a.min_cnt),
+            std::cmp::Ordering::Greater => (b.min1, min_opt(Some(a.min1), b.min2), b.min_cnt),
+            std::cmp::Ordering::Equal => (a.min1, min_opt(a.min2, b.min2), a.min_cnt + b.min_cnt),
+        };
+        Node {
+            max1,
+            max2,
+            max_cnt,
+            min1,
+            min2,
+            min_cnt,
+            sum: a.sum.wrapping_add(b.sum),
+            len: a.len + b.len,
+            lazy: 0,
+        }
+    }
+
+    // Lowers the maximum to `x`, assuming `max2 < x < max1`.
+    fn apply_chmin(&mut self, x: i64) {
+        self.sum = self
+            .sum
+            .wrapping_add(x.wrapping_sub(self.max1).wrapping_mul(self.max_cnt));
+        if self.max1 == self.min1 {
+            self.min1 = x;
+        } else if self.min2 == Some(self.max1) {
+            self.min2 = Some(x);
+        }
+        self.max1 = x;
+    }
+
+    // Raises the minimum to `x`, assuming `min1 < x < min2`.
+    fn apply_chmax(&mut self, x: i64) {
+        self.sum = self
+            .sum
+            .wrapping_add(x.wrapping_sub(self.min1).wrapping_mul(self.min_cnt));
+        if self.min1 == self.max1 {
+            self.max1 = x;
+        } else if self.max2 == Some(self.min1) {
+            self.max2 = Some(x);
+        }
+        self.min1 = x;
+    }
+
+    fn apply_add(&mut self, x: i64) {
+        self.max1 += x;
+        self.max2 = self.max2.map(|v| v + x);
+        self.min1 += x;
+        self.min2 = self.min2.map(|v| v + x);
+        self.sum = self.sum.wrapping_add(x.wrapping_mul(self.len));
+        self.lazy += x;
+    }
+}
+
+/// A segment tree beats over `i64` that supports range `chmin`, `chmax`, `add` and `assign`
+/// together with range `sum`, `min` and `max` queries.
+/// Each node keeps the largest and smallest two distinct values, so a range chmin stops at
+/// nodes where only the maximum changes, which gives amortized O(log^2 n) per operation.
+///
+/// 区間 `chmin`, `chmax`, `add`, `assign` と, 区間 `sum`, `min`, `max` の取得をサポートする
+/// `i64` 上の `segment tree beats` である.
+/// 各ノードは異なる値のうち大きい方と小さい方から二つずつを保持するため, 区間 chmin は最大値のみが変化する
+/// ノードで打ち切られ, 一回の操作はならし O(log^2 n) となる.
+#[derive(Clone)]
+pub struct SegmentTreeBeats {
+    len: usize,
+    nodes: Vec<Node>,
+}
+
+impl SegmentTreeBeats {
+    /// Creates a new `SegmentTreeBeats` with `n` elements, all initialized to 0.
+    /// 全要素が 0 で初期化された, `n` 個の要素を持つ `SegmentTreeBeats` を生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// `SegmentTreeBeats`: Returns a newly created segment tree instance.
+    ///                     新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+    /// let mut seg = SegmentTreeBeats::new(5);
+    /// assert_eq!(seg.sum(0, 5), 0);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        Self::from_slice(&vec![0; n])
+    }
+
+    /// Creates a new `SegmentTreeBeats` from a sequence.
+    /// 列から新しい `SegmentTreeBeats` を生成する.
+    ///
+    /// # Args
+    /// - `v`: The initial elements.
+    ///   初期の要素.
+    ///
+    /// # Returns
+    /// `SegmentTreeBeats`: Returns a newly created segment tree instance.
+    ///                     新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Constraints
+    /// - Every sum of elements fits in `i64` at any time.
+    ///   要素の和が常に `i64` に収まる.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n), where `n` is the length of `v`.
+    ///   ここで `n` は `v` の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+    /// let mut seg = SegmentTreeBeats::from_slice(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(seg.sum(0, 5), 14);
+    /// ```
+    pub fn from_slice(v: &[i64]) -> Self {
+        let mut seg = SegmentTreeBeats {
+            len: v.len(),
+            nodes: vec![Node::leaf(0); 4 * v.len().max(1)],
+        };
+        if !v.is_empty() {
+            seg.build(1, 0, v.len(), v);
+        }
+        seg
+    }
+
+    fn build(&mut self, k: usize, lo: usize, hi: usize, v: &[i64]) {
+        if hi - lo == 1 {
+            self.nodes[k] = Node::leaf(v[lo]);
+            return;
+        }
+        let m = lo + (hi - lo) / 2;
+        self.build(2 * k, lo, m, v);
+        self.build(2 * k + 1, m, hi, v);
+        self.pull(k);
+    }
+
+    /// Returns the number of elements.
+    /// 要素数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements.
+    ///          要素数.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    /// 要素が存在しない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether there are no elements.
+    ///         要素が存在しないかどうか.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn pull(&mut self, k: usize) {
+        self.nodes[k] = Node::merge(&self.nodes[2 * k], &self.nodes[2 * k + 1]);
+    }
+
+    fn push(&mut self, k: usize) {
+        let lazy = self.nodes[k].lazy;
+        let (max1, min1) = (self.nodes[k].max1, self.nodes[k].min1);
+        for c in [2 * k, 2 * k + 1] {
+            let child = &mut self.nodes[c];
+            if lazy != 0 {
+                child.apply_add(lazy);
+            }
+            if child.max1 > max1 {
+                child.apply_chmin(max1);
+            }
+            if child.min1 < min1 {
+                child.apply_chmax(min1);
+            }
+        }
+        self.nodes[k].lazy = 0;
+    }
+
+    fn check_range(&self, r: usize) {
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+    }
+
+    /// Replaces every element `a[i]` in `[l, r)` with `min(a[i], x)`.
+    /// 区間 `[l, r)` の各要素 `a[i]` を `min(a[i], x)` で置き換える.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    /// - `x`: The upper bound.
+    ///   上限.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: Amortized O(log^2(n)), where `n` is the number of elements.
+    ///   ならし O(log^2(n)). ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+    /// let mut seg = SegmentTreeBeats::from_slice(&[3, 1, 4, 1, 5]);
+    /// seg.chmin(0, 5, 3);
+    /// assert_eq!(seg.sum(0, 5), 11);
+    /// ```
+    pub fn chmin(&mut self, l: usize, r: usize, x: i64) {
+        if l >= r {
+            return;
+        }
+        self.check_range(r);
+        self.chmin_rec(1, 0, self.len, l, r, x);
+    }
+
+    fn chmin_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize, x: i64) {
+        if r <= lo || hi <= l || self.nodes[k].max1 <= x {
+            return;
+        }
+        if l <= lo && hi <= r && self.nodes[k].max2.is_none_or(|v| v < x) {
+            self.nodes[k].apply_chmin(x);
+            return;
+        }
+        self.push(k);
+        let m = lo + (hi - lo) / 2;
+        self.chmin_rec(2 * k, lo, m, l, r, x);
+        self.chmin_rec(2 * k + 1, m, hi, l, r, x);
+        self.pull(k);
+    }
+
+    /// Replaces every element `a[i]` in `[l, r)` with `max(a[i], x)`.
+    /// 区間 `[l, r)` の各要素 `a[i]` を `max(a[i], x)` で置き換える.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    /// - `x`: The lower bound.
+    ///   下限.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: Amortized O(log^2(n)), where `n` is the number of elements.
+    ///   ならし O(log^2(n)). ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+    /// let mut seg = SegmentTreeBeats::from_slice(&[3, 1, 4, 1, 5]);
+    /// seg.chmax(0, 5, 3);
+    /// assert_eq!(seg.sum(0, 5), 18);
+    /// ```
+    pub fn chmax(&mut self, l: usize, r: usize, x: i64) {
+        if l >= r {
+            return;
+        }
+        self.check_range(r);
+        self.chmax_rec(1, 0, self.len, l, r, x);
+    }
+
+    fn chmax_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize, x: i64) {
+        if r <= lo || hi <= l || self.nodes[k].min1 >= x {
+            return;
+        }
+        if l <= lo && hi <= r && self.nodes[k].min2.is_none_or(|v| v > x) {
+            self.nodes[k].apply_chmax(x);
+            return;
+        }
+        self.push(k);
+        let m = lo + (hi - lo) / 2;
+        self.chmax_rec(2 * k, lo, m, l, r, x);
+        self.chmax_rec(2 * k + 1, m, hi, l, r, x);
+        self.pull(k);
+    }
+
+    /// Adds `x` to every element in `[l, r)`.
+    /// 区間 `[l, r)` の全要素に `x` を加える.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    /// - `x`: The value to add.
+    ///   加える値.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+    /// let mut seg = SegmentTreeBeats::from_slice(&[3, 1, 4, 1, 5]);
+    /// seg.add(1, 3, 10);
+    /// assert_eq!(seg.max(0, 5), 14);
+    /// ```
+    pub fn add(&mut self, l: usize, r: usize, x: i64) {
+        if l >= r {
+            return;
+        }
+        self.check_range(r);
+        self.add_rec(1, 0, self.len, l, r, x);
+    }
+
+    fn add_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize, x: i64) {
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            self.nodes[k].apply_add(x);
+            return;
+        }
+        self.push(k);
+        let m = lo + (hi - lo) / 2;
+        self.add_rec(2 * k, lo, m, l, r, x);
+        self.add_rec(2 * k + 1, m, hi, l, r, x);
+        self.pull(k);
+    }
+
+    /// Sets every element in `[l, r)` to `x`.
+    /// 区間 `[l, r)` の全要素を `x` にする.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: Amortized O(log^2(n)), where `n` is the number of elements.
+    ///   ならし O(log^2(n)). ここで `n` は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+    /// let mut seg = SegmentTreeBeats::from_slice(&[3, 1, 4, 1, 5]);
+    /// seg.assign(0, 3, 2);
+    /// assert_eq!(seg.sum(0, 5), 12);
+    /// ```
+    pub fn assign(&mut self, l: usize, r: usize, x: i64) {
+        // Clamping from both sides leaves exactly `x`, within the same amortized bound.
+        self.chmin(l, r, x);
+        self.chmax(l, r, x);
+    }
+
+    // Returns `(sum, min, max)` over `[l, r)` intersected with `[lo, hi)`.
+    fn fold_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize) -> (i64, i64, i64) {
+        if r <= lo || hi <= l {
+            return (0, POS, NEG);
+        }
+        if l <= lo && hi <= r {
+            let node = &self.nodes[k];
+            return (node.sum, node.min1, node.max1);
+        }
+        self.push(k);
+        let m = lo + (hi - lo) / 2;
+        let a = self.fold_rec(2 * k, lo, m, l, r);
+        let b = self.fold_rec(2 * k + 1, m, hi, l, r);
+        (a.0.wrapping_add(b.0), a.1.min(b.1), a.2.max(b.2))
+    }
+
+    fn fold(&mut self, l: usize, r: usize) -> (i64, i64, i64) {
+        if l >= r {
+            return (0, POS, NEG);
+        }
+        self.check_range(r);
+        self.fold_rec(1, 0, self.len, l, r)
+    }
+
+    /// Returns the sum of the elements in `[l, r)`.
+    /// 区間 `[l, r)` の要素の和を返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `i64`: The sum modulo `2^{64}`, which is exact if it fits in `i64` and 0 if the range is empty.
+    ///        `2^{64}` を法とした和. `i64` に収まる場合は厳密であり, 区間が空なら 0.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    pub fn sum(&mut self, l: usize, r: usize) -> i64 {
+        self.fold(l, r).0
+    }
+
+    /// Returns the minimum of the elements in `[l, r)`.
+    /// 区間 `[l, r)` の要素の最小値を返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `i64`: The minimum, which is `i64::MAX` if the range is empty.
+    ///        最小値. 区間が空なら `i64::MAX`.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    pub fn min(&mut self, l: usize, r: usize) -> i64 {
+        self.fold(l, r).1
+    }
+
+    /// Returns the maximum of the elements in `[l, r)`.
+    /// 区間 `[l, r)` の要素の最大値を返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `i64`: The maximum, which is `i64::MIN` if the range is empty.
+    ///        最大値. 区間が空なら `i64::MIN`.
+    ///
+    /// # Panics
+    /// Panics if `l < r` and `r > self.len()`.
+    /// `l < r` かつ `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    pub fn max(&mut self, l: usize, r: usize) -> i64 {
+        self.fold(l, r).2
+    }
+
+    /// Gets the element at index `idx`.
+    /// インデックス `idx` の要素を取得する.
+    ///
+    /// # Args
+    /// - `idx`: The index to retrieve.
+    ///   値を取得するインデックス.
+    ///
+    /// # Returns
+    /// `i64`: The element at `idx`.
+    ///        `idx` の要素.
+    ///
+    /// # Panics
+    /// Panics if `idx >= self.len()`.
+    /// `idx >= self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(n)), where `n` is the number of elements.
+    ///   ここで `n` は要素数である.
+    pub fn get(&mut self, idx: usize) -> i64 {
+        assert!(
+            idx < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            idx
+        );
+        self.fold(idx, idx + 1).0
+    }
+}
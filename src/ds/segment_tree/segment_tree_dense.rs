@@ -24,7 +24,7 @@ where
     ///
     /// # Args
     /// - `n`: The size (number of leaves) of the segment tree.
-    ///        `segment tree` のサイズ (葉の数).
+    ///   `segment tree` のサイズ (葉の数).
     ///
     /// # Returns
     /// `SegmentTreeDense<M>`: Returns a newly created segment tree instance.
@@ -36,9 +36,9 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(n), where `n` is the size of the segment tree.
-    ///                          ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     /// - Space complexity: O(n), where `n` is the size of the segment tree.
-    ///                           ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     ///
     /// # Examples
     /// ```rust
@@ -80,6 +80,27 @@ where
         self.len
     }
 
+    /// Returns `true` if this segment tree has no leaves.
+    /// この `segment tree` が葉を持たない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: `true` if `self.len() == 0`, otherwise `false`.
+    ///         `self.len() == 0` の場合は `true`, そうでなければ `false`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_dense};
+    /// let seg = segment_tree_dense::SegmentTreeDense::<monoid::AddMonoid>::new(0);
+    /// assert!(seg.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Sets the value at index `idx` to `x`.
     /// The update is lazy; use `build` or `update` to propagate changes to parent nodes.
     /// インデックス `idx` の値を `x` にセットする.
@@ -87,9 +108,9 @@ where
     ///
     /// # Args
     /// - `idx`: The index to set.
-    ///          セット対象のインデックス.
+    ///   セット対象のインデックス.
     /// - `x`: The new value.
-    ///        新しい値.
+    ///   新しい値.
     ///
     /// # Panics
     /// Panics if `idx` >= `self.len()`.
@@ -131,7 +152,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(n), where `n` is the size of the segment tree.
-    ///                          ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     /// - Space complexity: O(1).
     ///
     /// # Examples
@@ -157,9 +178,9 @@ where
     ///
     /// # Args
     /// - `idx`: The index to update.
-    ///          更新対象のインデックス.
+    ///   更新対象のインデックス.
     /// - `x`: The new value.
-    ///        新しい値.
+    ///   新しい値.
     ///
     /// # Panics
     /// Panics if `idx` >= `self.len()`.
@@ -167,7 +188,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(log(n)), where `n` is the size of the segment tree.
-    ///                               ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     /// - Space complexity: O(1).
     ///
     /// # Examples
@@ -204,7 +225,7 @@ where
     ///
     /// # Args
     /// - `idx`: The index to retrieve.
-    ///          値を取得するインデックス.
+    ///   値を取得するインデックス.
     ///
     /// # Returns
     /// `M::S`: The value at `idx`.
@@ -247,9 +268,9 @@ where
     ///
     /// # Args
     /// - `l`: The start index of the range (inclusive).
-    ///        `query` 区間の開始インデックス (含む).
+    ///   `query` 区間の開始インデックス (含む).
     /// - `r`: The end index of the range (exclusive).
-    ///        `query` 区間の終了インデックス (含まない).
+    ///   `query` 区間の終了インデックス (含まない).
     ///
     /// # Returns
     /// `M::S`: The folded result of the interval `[l, r)`. It is the identity element `M::id()` if the range is empty.
@@ -261,7 +282,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(log(n)), where `n` is the size of the segment tree.
-    ///                               ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     /// - Space complexity: O(1).
     ///
     /// # Examples
@@ -299,10 +320,10 @@ where
 
         // Fold elements within [l, r).
         while l < r {
-            if l % 2 == 0 {
+            if l.is_multiple_of(2) {
                 sum_l = M::op(&sum_l, &self.data[l]);
             }
-            if r % 2 == 0 {
+            if r.is_multiple_of(2) {
                 sum_r = M::op(&self.data[r - 1], &sum_r);
             }
             l /= 2;
@@ -329,9 +350,9 @@ where
     ///
     /// # Args
     /// - `l`: The start index of the range.
-    ///        範囲の開始インデックス.
+    ///   範囲の開始インデックス.
     /// - `f`: A function that takes a reference to `M::S` and returns a boolean.
-    ///        `M::S` への参照を受け取り, 真偽値を返す関数.
+    ///   `M::S` への参照を受け取り, 真偽値を返す関数.
     ///
     /// # Returns
     /// `usize`: The maximum `r` such that `f(fold(l, r))` is `true`.
@@ -343,7 +364,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(log(n)), where `n` is the size of the segment tree.
-    ///                               ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     /// - Space complexity: O(1).
     ///
     /// # Examples
@@ -416,9 +437,9 @@ where
     ///
     /// # Args
     /// - `r`: The end index of the range.
-    ///        範囲の終了インデックス.
+    ///   範囲の終了インデックス.
     /// - `f`: A function that takes a reference to `M::S` and returns a boolean.
-    ///        `M::S` への参照を受け取り, 真偽値を返す関数.
+    ///   `M::S` への参照を受け取り, 真偽値を返す関数.
     ///
     /// # Returns
     /// `usize`: The minimum `l` such that `f(fold(l, r))` is `true`.
@@ -430,7 +451,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(log(n)), where `n` is the size of the segment tree.
-    ///                               ここで `n` は `segment tree` のサイズである.
+    ///   ここで `n` は `segment tree` のサイズである.
     /// - Space complexity: O(1).
     ///
     /// # Examples
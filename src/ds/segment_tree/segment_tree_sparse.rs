@@ -0,0 +1,525 @@
+//! A sparse segment tree that allocates its nodes on demand over a huge index range.
+//! 巨大な添字範囲上で, 必要になったノードのみを確保する疎な `segment tree` である.
+
+use super::super::super::algebra::monoid::Monoid;
+
+// A node of the arena. Missing children stand for subtrees whose elements are all the identity.
+#[derive(Clone)]
+struct Node<S> {
+    value: S,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+// Returns the midpoint of `[lo, hi)` without overflowing even for the whole `i64` range.
+fn mid(lo: i64, hi: i64) -> i64 {
+    ((lo as i128 + hi as i128).div_euclid(2)) as i64
+}
+
+/// A sparse segment tree over the index range `[lo, hi)` of `i64`.
+/// Nodes are kept in an arena and created only on the paths of updated points,
+/// so the memory is O(Q log(hi - lo)) for Q updates.
+///
+/// `i64` の添字範囲 `[lo, hi)` 上の疎な `segment tree` である.
+/// ノードはアリーナに保持され, 更新された点への経路上にのみ生成されるため,
+/// Q 回の更新に対してメモリは O(Q log(hi - lo)) である.
+#[derive(Clone)]
+pub struct SegmentTreeSparse<M>
+where
+    M: Monoid,
+{
+    lo: i64,
+    hi: i64,
+    root: Option<usize>,
+    nodes: Vec<Node<M::S>>,
+}
+
+impl<M> SegmentTreeSparse<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    /// Creates a new `SegmentTreeSparse` over `[lo, hi)`, where every element is the identity.
+    /// 全ての要素が単位元である, `[lo, hi)` 上の `SegmentTreeSparse` を生成する.
+    ///
+    /// # Args
+    /// - `lo`: The start of the index range (inclusive).
+    ///   添字範囲の開始 (含む).
+    /// - `hi`: The end of the index range (exclusive).
+    ///   添字範囲の終了 (含まない).
+    ///
+    /// # Returns
+    /// `SegmentTreeSparse<M>`: Returns a newly created segment tree instance.
+    ///                         新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`.
+    /// `lo > hi` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let seg = segment_tree_sparse::SegmentTreeSparse::<monoid::AddMonoid>::new(-1_000_000_000_000_000_000, 1_000_000_000_000_000_000);
+    /// assert_eq!(seg.fold(0, 10), 0);
+    /// ```
+    pub fn new(lo: i64, hi: i64) -> Self {
+        Self::with_capacity(lo, hi, 0)
+    }
+
+    /// Creates a new `SegmentTreeSparse` over `[lo, hi)` whose arena has room for `capacity` nodes.
+    /// アリーナに `capacity` 個のノードの領域を持つ, `[lo, hi)` 上の `SegmentTreeSparse` を生成する.
+    ///
+    /// # Args
+    /// - `lo`: The start of the index range (inclusive).
+    ///   添字範囲の開始 (含む).
+    /// - `hi`: The end of the index range (exclusive).
+    ///   添字範囲の終了 (含まない).
+    /// - `capacity`: The number of nodes to reserve.
+    ///   予約するノード数.
+    ///
+    /// # Returns
+    /// `SegmentTreeSparse<M>`: Returns a newly created segment tree instance.
+    ///                         新しい `segment tree` のインスタンスを返す.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`.
+    /// `lo > hi` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    /// - Space complexity: O(capacity).
+    pub fn with_capacity(lo: i64, hi: i64, capacity: usize) -> Self {
+        assert!(
+            lo <= hi,
+            "lo must be less than or equal to hi (lo: {}, hi: {})",
+            lo,
+            hi
+        );
+        SegmentTreeSparse::<M> {
+            lo,
+            hi,
+            root: None,
+            nodes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the index range `[lo, hi)` of this segment tree.
+    /// この `segment tree` の添字範囲 `[lo, hi)` を返す.
+    ///
+    /// # Returns
+    /// `(i64, i64)`: The pair `(lo, hi)`.
+    ///               組 `(lo, hi)`.
+    pub fn range(&self) -> (i64, i64) {
+        (self.lo, self.hi)
+    }
+
+    /// Returns the number of nodes allocated in the arena.
+    /// アリーナに確保されたノード数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of allocated nodes.
+    ///          確保されたノード数.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let mut seg = segment_tree_sparse::SegmentTreeSparse::<monoid::AddMonoid>::new(0, 8);
+    /// assert_eq!(seg.num_nodes(), 0);
+    /// seg.update(5, 1);
+    /// // The root, [4, 8), [4, 6) and the leaf 5.
+    /// assert_eq!(seg.num_nodes(), 4);
+    /// ```
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn check_index(&self, idx: i64) {
+        assert!(
+            self.lo <= idx && idx < self.hi,
+            "index out of bounds: the range is [{}, {}) but the index is {}",
+            self.lo,
+            self.hi,
+            idx
+        );
+    }
+
+    fn alloc(&mut self) -> usize {
+        self.nodes.push(Node {
+            value: M::id(),
+            left: None,
+            right: None,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn value(&self, node: Option<usize>) -> M::S {
+        node.map_or_else(M::id, |k| self.nodes[k].value.clone())
+    }
+
+    /// Updates the value at index `idx` to `x`, allocating the nodes on its path if needed.
+    /// インデックス `idx` の値を `x` に更新する. 必要なら経路上のノードを確保する.
+    ///
+    /// # Args
+    /// - `idx`: The index to update.
+    ///   更新対象のインデックス.
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if `idx` is not in `[lo, hi)`.
+    /// `idx` が `[lo, hi)` に含まれない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(hi - lo)).
+    /// - Space complexity: O(log(hi - lo)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let mut seg = segment_tree_sparse::SegmentTreeSparse::<monoid::AddMonoid>::new(0, 1_000_000_000_000_000_000);
+    /// seg.update(3, 1);
+    /// seg.update(999_999_999_999_999_999, 10);
+    /// assert_eq!(seg.fold(0, 1_000_000_000_000_000_000), 11);
+    /// seg.update(3, 5);
+    /// assert_eq!(seg.fold(0, 1_000_000_000_000_000_000), 15);
+    /// ```
+    pub fn update(&mut self, idx: i64, x: M::S) {
+        self.check_index(idx);
+
+        // Walk down to the leaf, allocating missing nodes and remembering the path.
+        let mut path = Vec::new();
+        let mut node = match self.root {
+            Some(k) => k,
+            None => {
+                let k = self.alloc();
+                self.root = Some(k);
+                k
+            }
+        };
+        let (mut lo, mut hi) = (self.lo, self.hi);
+        while lo + 1 < hi {
+            path.push(node);
+            let m = mid(lo, hi);
+            let go_left = idx < m;
+            let child = if go_left {
+                self.nodes[node].left
+            } else {
+                self.nodes[node].right
+            };
+            let child = match child {
+                Some(k) => k,
+                None => {
+                    let k = self.alloc();
+                    if go_left {
+                        self.nodes[node].left = Some(k);
+                    } else {
+                        self.nodes[node].right = Some(k);
+                    }
+                    k
+                }
+            };
+            if go_left {
+                hi = m;
+            } else {
+                lo = m;
+            }
+            node = child;
+        }
+        self.nodes[node].value = x;
+
+        // Recompute the values on the path from the bottom.
+        while let Some(k) = path.pop() {
+            let (left, right) = (self.nodes[k].left, self.nodes[k].right);
+            self.nodes[k].value = M::op(&self.value(left), &self.value(right));
+        }
+    }
+
+    /// Gets the value at index `idx`.
+    /// インデックス `idx` の値を取得する.
+    ///
+    /// # Args
+    /// - `idx`: The index to retrieve.
+    ///   値を取得するインデックス.
+    ///
+    /// # Returns
+    /// `M::S`: The value at `idx`, which is the identity if it has never been updated.
+    ///         `idx` の値. 一度も更新されていなければ単位元.
+    ///
+    /// # Panics
+    /// Panics if `idx` is not in `[lo, hi)`.
+    /// `idx` が `[lo, hi)` に含まれない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(hi - lo)).
+    /// - Space complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let mut seg = segment_tree_sparse::SegmentTreeSparse::<monoid::AddMonoid>::new(-5, 5);
+    /// seg.update(-3, 7);
+    /// assert_eq!(seg.get(-3), 7);
+    /// assert_eq!(seg.get(4), 0);
+    /// ```
+    pub fn get(&self, idx: i64) -> M::S {
+        self.check_index(idx);
+
+        let mut node = self.root;
+        let (mut lo, mut hi) = (self.lo, self.hi);
+        while let Some(k) = node {
+            if lo + 1 == hi {
+                break;
+            }
+            let m = mid(lo, hi);
+            if idx < m {
+                node = self.nodes[k].left;
+                hi = m;
+            } else {
+                node = self.nodes[k].right;
+                lo = m;
+            }
+        }
+        self.value(node)
+    }
+
+    /// Performs a range fold (query) on the interval `[l, r)`.
+    /// 区間 `[l, r)` 上の値に対して `fold` (畳み込み) を行う `query` を実行する.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   `query` 区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   `query` 区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `M::S`: The folded result of the interval `[l, r)`. It is the identity element `M::id()` if the range is empty.
+    ///         区間 `[l, r)` の畳み込み結果. 区間が空の場合, 単位元 `M::id()` となる.
+    ///
+    /// # Panics
+    /// Panics if the range is not empty and not contained in `[lo, hi)`.
+    /// 区間が空でなく, `[lo, hi)` に含まれない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(hi - lo)).
+    /// - Space complexity: O(log(hi - lo)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let mut seg = segment_tree_sparse::SegmentTreeSparse::<monoid::MaxMonoid>::new(0, 1 << 40);
+    /// seg.update(10, 3);
+    /// seg.update(1 << 30, 8);
+    /// assert_eq!(seg.fold(0, 1 << 30), 3);
+    /// assert_eq!(seg.fold(0, 1 << 40), 8);
+    /// ```
+    pub fn fold(&self, l: i64, r: i64) -> M::S {
+        if l >= r {
+            return M::id();
+        }
+
+        assert!(
+            self.lo <= l && r <= self.hi,
+            "index out of bounds: the range is [{}, {}) but the query is [{}, {})",
+            self.lo,
+            self.hi,
+            l,
+            r
+        );
+
+        self.fold_rec(self.root, self.lo, self.hi, l, r)
+    }
+
+    fn fold_rec(&self, node: Option<usize>, lo: i64, hi: i64, l: i64, r: i64) -> M::S {
+        let Some(k) = node else {
+            return M::id();
+        };
+        if r <= lo || hi <= l {
+            return M::id();
+        }
+        if l <= lo && hi <= r {
+            return self.nodes[k].value.clone();
+        }
+        let m = mid(lo, hi);
+        M::op(
+            &self.fold_rec(self.nodes[k].left, lo, m, l, r),
+            &self.fold_rec(self.nodes[k].right, m, hi, l, r),
+        )
+    }
+
+    /// Finds the maximum `r` in `[l, hi]` such that `f` applied to the fold result
+    /// from `[l, r)` is `true`.
+    /// 区間 `[l, hi]` 内で, `[l, r)` の `fold` 結果に対して述語 `f` が `true` を返すような
+    /// 最大の `r` を探索する.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range.
+    ///   範囲の開始インデックス.
+    /// - `f`: A monotone predicate that takes a reference to `M::S` and returns a boolean.
+    ///   `M::S` への参照を受け取り, 真偽値を返す単調な述語.
+    ///
+    /// # Returns
+    /// `i64`: The maximum `r` such that `f(fold(l, r))` is `true`.
+    ///        `f(fold(l, r))` が `true` となる最大の `r`.
+    ///
+    /// # Panics
+    /// Panics if `f(&M::id())` is false or `l` is not in `[lo, hi]`.
+    /// `f(&M::id())` が `false` の場合, または `l` が `[lo, hi]` に含まれない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(hi - lo)).
+    /// - Space complexity: O(log(hi - lo)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let mut seg = segment_tree_sparse::SegmentTreeSparse::<monoid::AddMonoid>::new(0, 1_000_000_000);
+    /// seg.update(100, 4);
+    /// seg.update(200, 5);
+    /// // [0, 200] sums to 9, while [0, 200) sums to 4.
+    /// assert_eq!(seg.max_right(0, |&sum| sum < 9), 200);
+    /// assert_eq!(seg.max_right(101, |&sum| sum < 9), 1_000_000_000);
+    /// ```
+    pub fn max_right<F>(&self, l: i64, f: F) -> i64
+    where
+        F: Fn(&M::S) -> bool,
+    {
+        assert!(
+            f(&M::id()),
+            "predicate must be true for the identity element"
+        );
+        assert!(
+            self.lo <= l && l <= self.hi,
+            "index out of bounds: l must be in [lo, hi] (l: {}, lo: {}, hi: {})",
+            l,
+            self.lo,
+            self.hi
+        );
+
+        let mut sum = M::id();
+        self.max_right_rec(self.root, self.lo, self.hi, l, &f, &mut sum)
+            .unwrap_or(self.hi)
+    }
+
+    // Returns the boundary if it lies in `[lo, hi)`, otherwise folds the part right of `l` into `sum`.
+    fn max_right_rec<F>(
+        &self,
+        node: Option<usize>,
+        lo: i64,
+        hi: i64,
+        l: i64,
+        f: &F,
+        sum: &mut M::S,
+    ) -> Option<i64>
+    where
+        F: Fn(&M::S) -> bool,
+    {
+        // An absent node folds to the identity, which keeps `f(sum)` true.
+        let k = node?;
+        if hi <= l {
+            return None;
+        }
+        if l <= lo {
+            let t = M::op(sum, &self.nodes[k].value);
+            if f(&t) {
+                *sum = t;
+                return None;
+            }
+            if lo + 1 == hi {
+                return Some(lo);
+            }
+        }
+        let m = mid(lo, hi);
+        self.max_right_rec(self.nodes[k].left, lo, m, l, f, sum)
+            .or_else(|| self.max_right_rec(self.nodes[k].right, m, hi, l, f, sum))
+    }
+
+    /// Finds the minimum `l` in `[lo, r]` such that `f` applied to the fold result
+    /// from `[l, r)` is `true`.
+    /// 区間 `[lo, r]` 内で, `[l, r)` の `fold` 結果に対して述語 `f` が `true` を返すような
+    /// 最小の `l` を探索する.
+    ///
+    /// # Args
+    /// - `r`: The end index of the range.
+    ///   範囲の終了インデックス.
+    /// - `f`: A monotone predicate that takes a reference to `M::S` and returns a boolean.
+    ///   `M::S` への参照を受け取り, 真偽値を返す単調な述語.
+    ///
+    /// # Returns
+    /// `i64`: The minimum `l` such that `f(fold(l, r))` is `true`.
+    ///        `f(fold(l, r))` が `true` となる最小の `l`.
+    ///
+    /// # Panics
+    /// Panics if `f(&M::id())` is false or `r` is not in `[lo, hi]`.
+    /// `f(&M::id())` が `false` の場合, または `r` が `[lo, hi]` に含まれない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log(hi - lo)).
+    /// - Space complexity: O(log(hi - lo)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_sparse};
+    /// let mut seg = segment_tree_sparse::SegmentTreeSparse::<monoid::AddMonoid>::new(0, 1_000_000_000);
+    /// seg.update(100, 4);
+    /// seg.update(200, 5);
+    /// // [101, 1000) sums to 5, while [100, 1000) sums to 9.
+    /// assert_eq!(seg.min_left(1000, |&sum| sum < 9), 101);
+    /// assert_eq!(seg.min_left(200, |&sum| sum < 9), 0);
+    /// ```
+    pub fn min_left<F>(&self, r: i64, f: F) -> i64
+    where
+        F: Fn(&M::S) -> bool,
+    {
+        assert!(
+            f(&M::id()),
+            "predicate must be true for the identity element"
+        );
+        assert!(
+            self.lo <= r && r <= self.hi,
+            "index out of bounds: r must be in [lo, hi] (r: {}, lo: {}, hi: {})",
+            r,
+            self.lo,
+            self.hi
+        );
+
+        let mut sum = M::id();
+        self.min_left_rec(self.root, self.lo, self.hi, r, &f, &mut sum)
+            .unwrap_or(self.lo)
+    }
+
+    // Returns the boundary if it lies in `(lo, hi]`, otherwise folds the part left of `r` into `sum`.
+    fn min_left_rec<F>(
+        &self,
+        node: Option<usize>,
+        lo: i64,
+        hi: i64,
+        r: i64,
+        f: &F,
+        sum: &mut M::S,
+    ) -> Option<i64>
+    where
+        F: Fn(&M::S) -> bool,
+    {
+        let k = node?;
+        if r <= lo {
+            return None;
+        }
+        if hi <= r {
+            let t = M::op(&self.nodes[k].value, sum);
+            if f(&t) {
+                *sum = t;
+                return None;
+            }
+            if lo + 1 == hi {
+                return Some(hi);
+            }
+        }
+        let m = mid(lo, hi);
+        self.min_left_rec(self.nodes[k].right, m, hi, r, f, sum)
+            .or_else(|| self.min_left_rec(self.nodes[k].left, lo, m, r, f, sum))
+    }
+}
@@ -0,0 +1,122 @@
+//! A module that defines `SparseTable`, which answers range queries on an idempotent semigroup in O(1).
+//! 冪等な半群上の区間クエリに O(1) で答える `SparseTable` を定義するモジュールである.
+
+use crate::algebra::semi_group::Idempotent;
+
+/// A sparse table that answers range folds of a static sequence on an idempotent semigroup in O(1),
+/// by combining two overlapping power-of-two ranges.
+///
+/// 冪等な半群上で, 静的な列の区間の畳み込みに O(1) で答えるスパーステーブルである.
+/// 重なり合う長さ 2 冪の二つの区間を結合する.
+#[derive(Clone)]
+pub struct SparseTable<S>
+where
+    S: Idempotent,
+{
+    // `table[k][i]` is the fold of `[i, i + 2^k)`.
+    table: Vec<Vec<S::S>>,
+}
+
+impl<S> SparseTable<S>
+where
+    S: Idempotent,
+    S::S: Clone,
+{
+    /// Creates a new `SparseTable` from a sequence.
+    /// 列から新しい `SparseTable` を生成する.
+    ///
+    /// # Args
+    /// - `v`: The sequence.
+    ///   列.
+    ///
+    /// # Returns
+    /// `SparseTable<S>`: A new instance of `SparseTable`.
+    ///                   `SparseTable` の新しいインスタンス.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the length of `v`.
+    ///   ここで N は `v` の長さである.
+    /// - Space complexity: O(N log N), where N is the length of `v`.
+    ///   ここで N は `v` の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::semi_group::MinSemiGroup, ds::sparse_table::SparseTable};
+    /// let st = SparseTable::<MinSemiGroup>::new(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(5, st.len());
+    /// ```
+    pub fn new(v: &[S::S]) -> Self {
+        let mut table = vec![v.to_vec()];
+        let mut k = 1;
+        while 1 << k <= v.len() {
+            let prev = table.last().unwrap();
+            let half = 1 << (k - 1);
+            let next = (0..=v.len() - (1 << k))
+                .map(|i| S::op(&prev[i], &prev[i + half]))
+                .collect();
+            table.push(next);
+            k += 1;
+        }
+        SparseTable { table }
+    }
+
+    /// Returns the length of the sequence.
+    /// 列の長さを返す.
+    ///
+    /// # Returns
+    /// `usize`: The length of the sequence.
+    ///          列の長さ.
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    /// Returns `true` if the sequence is empty.
+    /// 列が空の場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: Whether the sequence is empty.
+    ///         列が空かどうか.
+    pub fn is_empty(&self) -> bool {
+        self.table[0].is_empty()
+    }
+
+    /// Folds the non-empty range `[l, r)`.
+    /// 空でない区間 `[l, r)` を畳み込む.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   区間の開始インデックス (含む).
+    /// - `r`: The end index of the range (exclusive).
+    ///   区間の終了インデックス (含まない).
+    ///
+    /// # Returns
+    /// `S::S`: The fold `op(v[l], ..., v[r - 1])`.
+    ///         畳み込み `op(v[l], ..., v[r - 1])`.
+    ///
+    /// # Panics
+    /// Panics if `l >= r` or `r > self.len()`.
+    /// `l >= r` または `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::semi_group::MaxSemiGroup, ds::sparse_table::SparseTable};
+    /// let st = SparseTable::<MaxSemiGroup>::new(&[3, 1, 4, 1, 5]);
+    /// assert_eq!(4, st.fold(1, 4));
+    /// assert_eq!(5, st.fold(0, 5));
+    /// ```
+    pub fn fold(&self, l: usize, r: usize) -> S::S {
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+        assert!(l < r, "l must be less than r (l: {}, r: {})", l, r);
+
+        let k = (r - l).ilog2() as usize;
+        S::op(&self.table[k][l], &self.table[k][r - (1 << k)])
+    }
+}
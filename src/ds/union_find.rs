@@ -11,6 +11,7 @@ pub struct UnionFind {
     rank: Vec<usize>,
     size: Vec<usize>,
     group_next: Vec<usize>,
+    num_components: usize,
 }
 
 impl UnionFind {
@@ -22,7 +23,7 @@ impl UnionFind {
     ///
     /// # Args
     /// * `n`: The number of elements.
-    ///        要素数.
+    ///   要素数.
     ///
     /// # Returns
     /// A new `UnionFind` instance.
@@ -30,9 +31,9 @@ impl UnionFind {
     ///
     /// # Complexity
     /// - Time complexity: O(N), where N is the number of elements.
-    ///                    ここで N は要素数である.
+    ///   ここで N は要素数である.
     /// - Space complexity: O(N), where N is the number of elements.
-    ///                     ここで N は要素数である.
+    ///   ここで N は要素数である.
     ///
     /// # Examples
     /// ```rust
@@ -45,6 +46,7 @@ impl UnionFind {
             rank: vec![0; n],
             size: vec![1; n],
             group_next: (0..n).collect::<Vec<_>>(),
+            num_components: n,
         }
     }
 
@@ -58,12 +60,22 @@ impl UnionFind {
         self.parent.len()
     }
 
+    /// Returns `true` if this `UnionFind` manages no elements.
+    /// `UnionFind` が要素を一つも管理していない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: `true` if `self.len() == 0`, otherwise `false`.
+    ///         `self.len() == 0` の場合は `true`, そうでなければ `false`.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
     /// Checks if an element `x` is the root of its set.
     /// 要素 `x` がその集合の根であるかどうかを判定する.
     ///
     /// # Args
     /// * `x`: The element to check.
-    ///        判定対象の要素.
+    ///   判定対象の要素.
     ///
     /// # Returns
     /// `true` if `x` is a root, otherwise `false`.
@@ -92,7 +104,7 @@ impl UnionFind {
     ///
     /// # Args
     /// * `x`: The element to find the root of.
-    ///        根を探す対象の要素.
+    ///   根を探す対象の要素.
     ///
     /// # Returns
     /// The root of the set.
@@ -104,7 +116,7 @@ impl UnionFind {
     ///
     /// # Complexity
     /// - Time complexity: O(α(N)), where α is the inverse Ackermann function.
-    ///                    ここで α は逆アッカーマン関数である.
+    ///   ここで α は逆アッカーマン関数である.
     pub fn find(&mut self, x: usize) -> usize {
         if x >= self.parent.len() {
             panic!(
@@ -133,9 +145,9 @@ impl UnionFind {
     ///
     /// # Args
     /// * `x`: An element in the first set.
-    ///        最初の集合に含まれる要素.
+    ///   最初の集合に含まれる要素.
     /// * `y`: An element in the second set.
-    ///        二番目の集合に含まれる要素.
+    ///   二番目の集合に含まれる要素.
     ///
     /// # Panics
     /// Panics if `x` or `y` are out of bounds.
@@ -155,6 +167,7 @@ impl UnionFind {
             // Link the `group_next` pointers to enable group traversal.
             // This is a specific implementation detail for `get_group`.
             self.group_next.swap(root_x, root_y);
+            self.num_components -= 1;
 
             // Union by rank: Attach the shorter tree to the root of the taller tree.
             // This helps to keep the trees from becoming too deep.
@@ -181,9 +194,9 @@ impl UnionFind {
     ///
     /// # Args
     /// * `x`: The first element.
-    ///        最初の要素.
+    ///   最初の要素.
     /// * `y`: The second element.
-    ///        二番目の要素.
+    ///   二番目の要素.
     ///
     /// # Returns
     /// `true` if `x` and `y` are in the same set, otherwise `false`.
@@ -218,7 +231,7 @@ impl UnionFind {
     ///
     /// # Args
     /// * `x`: The element.
-    ///        対象の要素.
+    ///   対象の要素.
     ///
     /// # Returns
     /// The size of the set.
@@ -255,14 +268,16 @@ impl UnionFind {
     }
 
     /// Returns all elements in the set containing element `x`.
-    /// Note: This implementation may be inefficient and is not a standard part of Union-Find.
+    /// Note: This allocates and sorts on every call. Use `group_iter` to walk a set without
+    /// allocation, or `groups` to enumerate all sets at once.
     ///
     /// 要素 `x` が属する集合の全要素を取得する.
-    /// 注意: この実装は非効率な場合があり, Union-Find の標準的な機能ではない.
+    /// 注意: 呼び出しのたびにメモリ確保とソートを行う. 集合をメモリ確保なしに走査するには `group_iter` を,
+    /// 全ての集合を一度に列挙するには `groups` を用いる.
     ///
     /// # Args
     /// * `x`: The element.
-    ///        対象の要素.
+    ///   対象の要素.
     ///
     /// # Returns
     /// A sorted vector of all elements in the set.
@@ -270,7 +285,7 @@ impl UnionFind {
     ///
     /// # Complexity
     /// - Time complexity: O(S log S), where S is the size of the group.
-    ///                    ここで S はグループのサイズである.
+    ///   ここで S はグループのサイズである.
     ///
     /// # Panics
     /// Panics if `x` is out of bounds.
@@ -284,18 +299,169 @@ impl UnionFind {
             );
         }
 
-        let mut res = vec![x];
-        let mut v = x;
-
         // Traverse the cyclic list created by `group_next` to collect all members of the group.
-        // This is a custom extension to the standard Union-Find structure.
-        while self.group_next[v] != x {
-            res.push(self.group_next[v]);
-            v = self.group_next[v];
-        }
+        let mut res = self.group_iter(x).collect::<Vec<_>>();
 
         // Sort for a consistent and predictable output order.
         res.sort();
         res
     }
+
+    /// Returns an iterator over the elements in the set containing element `x`, starting with `x`.
+    /// It walks the `group_next` ring without allocation, so the elements are not sorted.
+    ///
+    /// 要素 `x` が属する集合の要素を `x` から順に走査するイテレータを返す.
+    /// `group_next` の環をメモリ確保なしに辿るため, 要素はソートされていない.
+    ///
+    /// # Args
+    /// * `x`: The element.
+    ///   対象の要素.
+    ///
+    /// # Returns
+    /// An iterator over the members of the set.
+    /// 集合の要素を走査するイテレータ.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(S) in total, where S is the size of the group.
+    ///   全体で O(S). ここで S はグループのサイズである.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::union_find::UnionFind;
+    /// let mut uf = UnionFind::new(4);
+    /// uf.union(0, 2);
+    /// uf.union(3, 2);
+    /// let mut members = uf.group_iter(3).collect::<Vec<_>>();
+    /// assert_eq!(3, members[0]);
+    /// members.sort();
+    /// assert_eq!(vec![0, 2, 3], members);
+    /// ```
+    pub fn group_iter(&self, x: usize) -> GroupIter<'_> {
+        if x >= self.len() {
+            panic!(
+                "Index {} is out of bounds for UnionFind with size {}",
+                x,
+                self.len()
+            );
+        }
+
+        GroupIter {
+            group_next: &self.group_next,
+            start: x,
+            next: Some(x),
+        }
+    }
+
+    /// Returns the number of sets, which is maintained on each `union`.
+    /// 集合の個数を返す. これは `union` のたびに更新される.
+    ///
+    /// # Returns
+    /// The number of disjoint sets.
+    /// 互いに素な集合の個数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::union_find::UnionFind;
+    /// let mut uf = UnionFind::new(4);
+    /// uf.union(0, 1);
+    /// uf.union(1, 0);
+    /// assert_eq!(3, uf.num_components());
+    /// ```
+    pub fn num_components(&self) -> usize {
+        self.num_components
+    }
+
+    /// Returns all sets in one pass.
+    /// 全ての集合を一度の走査で返す.
+    ///
+    /// # Returns
+    /// The sets ordered by their smallest elements, each sorted in ascending order.
+    /// 最小の要素の順に並んだ集合のリスト. 各集合は昇順にソートされている.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N α(N)), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::union_find::UnionFind;
+    /// let mut uf = UnionFind::new(5);
+    /// uf.union(3, 1);
+    /// uf.union(4, 0);
+    /// assert_eq!(vec![vec![0, 4], vec![1, 3], vec![2]], uf.groups());
+    /// ```
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let (ids, count) = self.component_ids();
+        let mut res = vec![Vec::new(); count];
+        for (x, &id) in ids.iter().enumerate() {
+            if res[id].is_empty() {
+                let size = self.get_size(x);
+                res[id].reserve_exact(size);
+            }
+            res[id].push(x);
+        }
+        res
+    }
+
+    /// Labels every element with a dense id of its set.
+    /// 各要素に, 属する集合の密な番号を付ける.
+    ///
+    /// # Returns
+    /// `(ids, count)`, where `ids[x]` is in `0..count` and ids are assigned in the order of
+    /// the smallest elements of the sets.
+    /// `(ids, count)` を返す. `ids[x]` は `0..count` の範囲にあり, 番号は集合の最小の要素の順に割り当てられる.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N α(N)), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::union_find::UnionFind;
+    /// let mut uf = UnionFind::new(4);
+    /// uf.union(3, 1);
+    /// assert_eq!((vec![0, 1, 2, 1], 3), uf.component_ids());
+    /// ```
+    pub fn component_ids(&mut self) -> (Vec<usize>, usize) {
+        let n = self.len();
+        let mut root_id = vec![usize::MAX; n];
+        let mut count = 0;
+        let ids = (0..n)
+            .map(|x| {
+                let root = self.find(x);
+                if root_id[root] == usize::MAX {
+                    root_id[root] = count;
+                    count += 1;
+                }
+                root_id[root]
+            })
+            .collect::<Vec<_>>();
+        (ids, count)
+    }
+}
+
+/// An iterator over the members of a set, created by `UnionFind::group_iter`.
+/// `UnionFind::group_iter` が生成する, 集合の要素を走査するイテレータである.
+pub struct GroupIter<'a> {
+    group_next: &'a [usize],
+    start: usize,
+    next: Option<usize>,
+}
+
+impl Iterator for GroupIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let x = self.next?;
+        let next = self.group_next[x];
+        self.next = if next == self.start { None } else { Some(next) };
+        Some(x)
+    }
 }
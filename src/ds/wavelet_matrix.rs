@@ -1,3 +1,5 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
 use super::bit_vector;
 
 /// A data structure that represents a sequence of values and supports fast ranking queries.
@@ -15,7 +17,7 @@ impl WaveletMatrix {
     ///
     /// # Args
     /// - `v`: A slice of `usize` to be stored in the WaveletMatrix.
-    ///        WaveletMatrix に格納する `usize` のスライス.
+    ///   WaveletMatrix に格納する `usize` のスライス.
     ///
     /// # Returns
     /// `WaveletMatrix`: A new instance of WaveletMatrix.
@@ -42,10 +44,10 @@ impl WaveletMatrix {
     /// # Complexity
     /// - Time complexity: O(N log U), where N is the length of `v` and U is the number of
     ///   distinct values in `v`.
-    ///                          ここで N は `v` の長さ, U は `v` のユニークな値の数である.
+    ///   ここで N は `v` の長さ, U は `v` のユニークな値の数である.
     /// - Space complexity: O(N log U), where N is the length of `v` and U is the number of
     ///   distinct values in `v`.
-    ///                           ここで N は `v` の長さ, U は `v` のユニークな値の数である.
+    ///   ここで N は `v` の長さ, U は `v` のユニークな値の数である.
     ///
     /// # Examples
     /// ```rust
@@ -87,10 +89,7 @@ impl WaveletMatrix {
         // `compress.len()` is the number of unique elements (U).
         // `(1..)` finds the smallest `i` such that `2^i >= U + 1`.
         // `1_usize << i` explicitly specifies `usize` for the bit shift to avoid type issues.
-        let height = (1..)
-            .find(|i| (1_usize << i) >= compress.len() + 1)
-            .unwrap()
-            + 1;
+        let height = (1..).find(|i| (1_usize << i) > compress.len()).unwrap() + 1;
 
         // Initialize bit_table; type is inferred from `BitVector::new`.
         let mut bit_table = Vec::new();
@@ -129,11 +128,11 @@ impl WaveletMatrix {
     ///
     /// # Args
     /// - `l`: The start index of the range (inclusive).
-    ///        範囲の開始インデックス (inclusive).
+    ///   範囲の開始インデックス (inclusive).
     /// - `r`: The end index of the range (exclusive).
-    ///        範囲の終了インデックス (exclusive).
+    ///   範囲の終了インデックス (exclusive).
     /// - `upper`: The upper bound value (exclusive).
-    ///            上限値 (exclusive).
+    ///   上限値 (exclusive).
     ///
     /// # Returns
     /// `usize`: The number of elements less than `upper` in the specified range.
@@ -152,19 +151,26 @@ impl WaveletMatrix {
     ///
     /// # Complexity
     /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
-    ///                          ここで U は元のシーケンスにおけるユニークな値の数である.
-    pub fn count_less_than(&self, mut l: usize, mut r: usize, upper: usize) -> usize {
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    pub fn count_less_than(&self, l: usize, r: usize, upper: usize) -> usize {
         if r <= l {
             return 0;
         }
 
-        // Arrange
         // Find the compressed index for `upper`. All values less than `upper`
         // will have compressed indices less than `upper_idx`.
         let upper_idx = self.sorted_v.partition_point(|&x| x < upper);
+        self.count_less_than_idx(l, r, upper_idx)
+    }
+
+    // Returns the count of elements whose compressed index is less than `upper_idx` in `v[l..r]`.
+    fn count_less_than_idx(&self, mut l: usize, mut r: usize, upper_idx: usize) -> usize {
+        if r <= l {
+            return 0;
+        }
+
         let mut res = 0;
 
-        // Act & Assert
         // Iterate through bit layers from MSB to LSB.
         for (i, bit) in (0..self.height).rev().zip(self.bit_table.iter()) {
             // Calculate ranks of `l` and `r` (count of 1s up to l/r) in the current bit_vector.
@@ -183,26 +189,30 @@ impl WaveletMatrix {
                 res += (r - l) - (rank_r - rank_l);
 
                 // Update `l` and `r` to reflect the new range in the 1-block.
-                // `len_zeros_upto_end` is the count of 0s in the entire bit_vector up to its end.
-                let len_zeros_upto_end = bit.len() - bit.rank(bit.len());
-                l = rank_l + len_zeros_upto_end;
-                r = rank_r + len_zeros_upto_end;
+                let zeros = Self::zeros(bit);
+                l = rank_l + zeros;
+                r = rank_r + zeros;
             }
         }
 
         res
     }
 
+    // Returns the number of 0s in the whole bit vector, i.e. the offset of the 1-block.
+    fn zeros(bit: &bit_vector::BitVector) -> usize {
+        bit.rank0(bit.len())
+    }
+
     /// Returns the count of elements `y` such that `y >= lower` in the range `v[l..r]`.
     /// `v[l..r]` の範囲にある要素 `y` のうち, `y >= lower` となるものの個数を返す.
     ///
     /// # Args
     /// - `l`: The start index of the range (inclusive).
-    ///        範囲の開始インデックス (inclusive).
+    ///   範囲の開始インデックス (inclusive).
     /// - `r`: The end index of the range (exclusive).
-    ///        範囲の終了インデックス (exclusive).
+    ///   範囲の終了インデックス (exclusive).
     /// - `lower`: The lower bound value (inclusive).
-    ///            下限値 (inclusive).
+    ///   下限値 (inclusive).
     ///
     /// # Returns
     /// `usize`: The number of elements greater than or equal to `lower` in the specified range.
@@ -221,7 +231,7 @@ impl WaveletMatrix {
     ///
     /// # Complexity
     /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
-    ///                               ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
     pub fn count_more_than(&self, l: usize, r: usize, lower: usize) -> usize {
         if r <= l {
             return 0;
@@ -235,13 +245,13 @@ impl WaveletMatrix {
     ///
     /// # Args
     /// - `l`: The start index of the range (inclusive).
-    ///        範囲の開始インデックス (inclusive).
+    ///   範囲の開始インデックス (inclusive).
     /// - `r`: The end index of the range (exclusive).
-    ///        範囲の終了インデックス (exclusive).
+    ///   範囲の終了インデックス (exclusive).
     /// - `lower`: The lower bound value (inclusive).
-    ///            下限値 (inclusive).
+    ///   下限値 (inclusive).
     /// - `upper`: The upper bound value (exclusive).
-    ///            上限値 (exclusive).
+    ///   上限値 (exclusive).
     ///
     /// # Returns
     /// `usize`: The number of elements in the range [`lower`, `upper`) in the specified range of `v`.
@@ -260,7 +270,7 @@ impl WaveletMatrix {
     ///
     /// # Complexity
     /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
-    ///                          ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
     pub fn count(&self, l: usize, r: usize, lower: usize, upper: usize) -> usize {
         if r <= l {
             return 0;
@@ -274,4 +284,450 @@ impl WaveletMatrix {
 
         self.count_less_than(l, r, upper) - self.count_less_than(l, r, lower)
     }
+
+    /// Returns the length of the original sequence.
+    /// 元のシーケンスの長さを返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements.
+    ///          要素数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn len(&self) -> usize {
+        self.bit_table[0].len()
+    }
+
+    /// Returns `true` if the original sequence is empty.
+    /// 元のシーケンスが空の場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: `true` if the sequence is empty, otherwise `false`.
+    ///         シーケンスが空の場合は `true`, そうでなければ `false`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element `v[i]`.
+    /// 要素 `v[i]` を返す.
+    ///
+    /// # Args
+    /// - `i`: The index of the element.
+    ///   要素のインデックス.
+    ///
+    /// # Returns
+    /// `usize`: The value of `v[i]`.
+    ///          `v[i]` の値.
+    ///
+    /// # Panics
+    /// - Panics if `i >= self.len()`.
+    ///   `i >= self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20]);
+    /// assert_eq!(5, wm.access(1));
+    /// ```
+    pub fn access(&self, mut i: usize) -> usize {
+        assert!(
+            i < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            i
+        );
+
+        let mut idx = 0;
+        for bit in self.bit_table.iter() {
+            idx <<= 1;
+            if bit.access(i) == 1 {
+                // Follow `v[i]` into the 1-block.
+                idx |= 1;
+                i = bit.rank(i) + Self::zeros(bit);
+            } else {
+                i = bit.rank0(i);
+            }
+        }
+
+        self.sorted_v[idx]
+    }
+
+    /// Returns the `k`-th (0-indexed) smallest element in the range `v[l..r]`.
+    /// `v[l..r]` の範囲で `k` 番目 (0-indexed) に小さい要素を返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   範囲の開始インデックス (inclusive).
+    /// - `r`: The end index of the range (exclusive).
+    ///   範囲の終了インデックス (exclusive).
+    /// - `k`: The rank of the element to find (0-indexed).
+    ///   求める要素の順位 (0-indexed).
+    ///
+    /// # Returns
+    /// `usize`: The `k`-th smallest element.
+    ///          `k` 番目に小さい要素.
+    ///
+    /// # Panics
+    /// - Panics if `r > self.len()` or `k >= r - l`.
+    ///   `r > self.len()` または `k >= r - l` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 15, 5]);
+    /// // The median of [5, 20, 15] is 15.
+    /// assert_eq!(15, wm.kth_smallest(1, 4, 1));
+    /// assert_eq!(5, wm.kth_smallest(0, 5, 1));
+    /// ```
+    pub fn kth_smallest(&self, mut l: usize, mut r: usize, mut k: usize) -> usize {
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+        assert!(
+            k < r.saturating_sub(l),
+            "k must be less than r - l (k: {}, r - l: {})",
+            k,
+            r.saturating_sub(l)
+        );
+
+        let mut idx = 0;
+        for bit in self.bit_table.iter() {
+            let rank_l = bit.rank(l);
+            let rank_r = bit.rank(r);
+            let zero_count = (r - l) - (rank_r - rank_l);
+            idx <<= 1;
+            if k < zero_count {
+                l -= rank_l;
+                r -= rank_r;
+            } else {
+                // Skip every element of the 0-block, which are all smaller.
+                k -= zero_count;
+                idx |= 1;
+                let zeros = Self::zeros(bit);
+                l = rank_l + zeros;
+                r = rank_r + zeros;
+            }
+        }
+
+        self.sorted_v[idx]
+    }
+
+    /// Returns the `k`-th (0-indexed) largest element in the range `v[l..r]`.
+    /// `v[l..r]` の範囲で `k` 番目 (0-indexed) に大きい要素を返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   範囲の開始インデックス (inclusive).
+    /// - `r`: The end index of the range (exclusive).
+    ///   範囲の終了インデックス (exclusive).
+    /// - `k`: The rank of the element to find (0-indexed).
+    ///   求める要素の順位 (0-indexed).
+    ///
+    /// # Returns
+    /// `usize`: The `k`-th largest element.
+    ///          `k` 番目に大きい要素.
+    ///
+    /// # Panics
+    /// - Panics if `r > self.len()` or `k >= r - l`.
+    ///   `r > self.len()` または `k >= r - l` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 15, 5]);
+    /// assert_eq!(20, wm.kth_largest(0, 5, 0));
+    /// assert_eq!(5, wm.kth_largest(0, 5, 4));
+    /// ```
+    pub fn kth_largest(&self, l: usize, r: usize, k: usize) -> usize {
+        let len = r.saturating_sub(l);
+        assert!(
+            k < len,
+            "k must be less than r - l (k: {}, r - l: {})",
+            k,
+            len
+        );
+        self.kth_smallest(l, r, len - 1 - k)
+    }
+
+    /// Returns the largest element `y` such that `y < upper` in the range `v[l..r]`.
+    /// `v[l..r]` の範囲にある要素 `y` のうち, `y < upper` を満たす最大のものを返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   範囲の開始インデックス (inclusive).
+    /// - `r`: The end index of the range (exclusive).
+    ///   範囲の終了インデックス (exclusive).
+    /// - `upper`: The upper bound value (exclusive).
+    ///   上限値 (exclusive).
+    ///
+    /// # Returns
+    /// `Option<usize>`: The largest such element, or `None` if no element is less than `upper`.
+    ///                  条件を満たす最大の要素. `upper` 未満の要素がなければ `None`.
+    ///
+    /// # Panics
+    /// - Panics if `r > self.len()`.
+    ///   `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 15, 5]);
+    /// assert_eq!(Some(10), wm.prev_value(0, 5, 15));
+    /// assert_eq!(None, wm.prev_value(0, 5, 5));
+    /// ```
+    pub fn prev_value(&self, l: usize, r: usize, upper: usize) -> Option<usize> {
+        match self.count_less_than(l, r, upper) {
+            0 => None,
+            cnt => Some(self.kth_smallest(l, r, cnt - 1)),
+        }
+    }
+
+    /// Returns the smallest element `y` such that `y >= lower` in the range `v[l..r]`.
+    /// `v[l..r]` の範囲にある要素 `y` のうち, `y >= lower` を満たす最小のものを返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   範囲の開始インデックス (inclusive).
+    /// - `r`: The end index of the range (exclusive).
+    ///   範囲の終了インデックス (exclusive).
+    /// - `lower`: The lower bound value (inclusive).
+    ///   下限値 (inclusive).
+    ///
+    /// # Returns
+    /// `Option<usize>`: The smallest such element, or `None` if no element is at least `lower`.
+    ///                  条件を満たす最小の要素. `lower` 以上の要素がなければ `None`.
+    ///
+    /// # Panics
+    /// - Panics if `r > self.len()`.
+    ///   `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 15, 5]);
+    /// assert_eq!(Some(15), wm.next_value(0, 5, 11));
+    /// assert_eq!(None, wm.next_value(0, 5, 21));
+    /// ```
+    pub fn next_value(&self, l: usize, r: usize, lower: usize) -> Option<usize> {
+        let cnt = self.count_less_than(l, r, lower);
+        if cnt < r.saturating_sub(l) {
+            Some(self.kth_smallest(l, r, cnt))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of occurrences of `value` in `v[0..i]`.
+    /// `v[0..i]` における `value` の出現回数を返す.
+    ///
+    /// # Args
+    /// - `value`: The value to count.
+    ///   数える値.
+    /// - `i`: The end index of the prefix (exclusive).
+    ///   接頭辞の終了インデックス (exclusive).
+    ///
+    /// # Returns
+    /// `usize`: The number of `j < i` with `v[j] == value`.
+    ///          `v[j] == value` を満たす `j < i` の個数.
+    ///
+    /// # Panics
+    /// - Panics if `i > self.len()`.
+    ///   `i > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 5, 5]);
+    /// assert_eq!(2, wm.rank(5, 4));
+    /// assert_eq!(0, wm.rank(7, 5));
+    /// ```
+    pub fn rank(&self, value: usize, i: usize) -> usize {
+        assert!(
+            i <= self.len(),
+            "index out of bounds: i must be less than or equal to the len (i: {}, len: {})",
+            i,
+            self.len()
+        );
+
+        match self.sorted_v.binary_search(&value) {
+            Ok(idx) => {
+                self.count_less_than_idx(0, i, idx + 1) - self.count_less_than_idx(0, i, idx)
+            }
+            Err(_) => 0,
+        }
+    }
+
+    /// Returns the index of the `k`-th (0-indexed) occurrence of `value`.
+    /// `value` が `k` 番目 (0-indexed) に出現するインデックスを返す.
+    ///
+    /// # Args
+    /// - `value`: The value to find.
+    ///   探す値.
+    /// - `k`: The occurrence to find (0-indexed).
+    ///   求める出現 (0-indexed).
+    ///
+    /// # Returns
+    /// `Option<usize>`: The index, or `None` if `value` occurs at most `k` times.
+    ///                  インデックス. `value` の出現が `k` 回以下なら `None`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log U) calls of `BitVector::select0` or `BitVector::select1`,
+    ///   where U is the number of distinct values in the original sequence.
+    ///   `BitVector::select0` または `BitVector::select1` の O(log U) 回の呼び出し.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 5, 5]);
+    /// assert_eq!(Some(3), wm.select(5, 1));
+    /// assert_eq!(None, wm.select(5, 3));
+    /// ```
+    pub fn select(&self, value: usize, k: usize) -> Option<usize> {
+        let idx = self.sorted_v.binary_search(&value).ok()?;
+        if self.rank(value, self.len()) <= k {
+            return None;
+        }
+
+        // Descend to the start of the block of `value` in the last layer.
+        let mut p = 0;
+        for (i, bit) in (0..self.height).rev().zip(self.bit_table.iter()) {
+            if ((idx >> i) & 1) == 0 {
+                p = bit.rank0(p);
+            } else {
+                p = bit.rank(p) + Self::zeros(bit);
+            }
+        }
+
+        // Climb back up from the k-th occurrence by inverting each step with select.
+        p += k;
+        for (i, bit) in (0..self.height).zip(self.bit_table.iter().rev()) {
+            p = if ((idx >> i) & 1) == 0 {
+                bit.select0(p)
+            } else {
+                bit.select1(p - Self::zeros(bit))
+            }
+            .unwrap();
+        }
+
+        Some(p)
+    }
+
+    /// Returns up to `k` most frequent values in the range `v[l..r]` with their counts.
+    /// `v[l..r]` の範囲で出現回数の多い値を最大 `k` 個, その出現回数とともに返す.
+    ///
+    /// # Args
+    /// - `l`: The start index of the range (inclusive).
+    ///   範囲の開始インデックス (inclusive).
+    /// - `r`: The end index of the range (exclusive).
+    ///   範囲の終了インデックス (exclusive).
+    /// - `k`: The maximum number of values to return.
+    ///   返す値の最大個数.
+    ///
+    /// # Returns
+    /// `Vec<(usize, usize)>`: Pairs of `(value, count)` in descending order of count,
+    ///                        ties broken by ascending value.
+    ///                        出現回数の降順 (同数の場合は値の昇順) に並んだ `(値, 出現回数)` の組.
+    ///
+    /// # Panics
+    /// - Panics if `r > self.len()`.
+    ///   `r > self.len()` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(k log U log k), where U is the number of distinct values in the original sequence.
+    ///   ここで U は元のシーケンスにおけるユニークな値の数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::ds::wavelet_matrix::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[10, 5, 20, 5, 10, 5]);
+    /// assert_eq!(vec![(5, 3), (10, 2)], wm.top_k_frequent(0, 6, 2));
+    /// ```
+    pub fn top_k_frequent(&self, l: usize, r: usize, k: usize) -> Vec<(usize, usize)> {
+        assert!(
+            r <= self.len(),
+            "index out of bounds: r must be less than or equal to the len (r: {}, len: {})",
+            r,
+            self.len()
+        );
+
+        let mut res = Vec::new();
+        if l >= r {
+            return res;
+        }
+
+        // Each node is (count, smallest compressed value in the node, depth, l, r).
+        // A child never has a larger count or a smaller value than its parent,
+        // so leaves are popped in descending order of count and ascending order of value.
+        let mut heap = BinaryHeap::new();
+        heap.push((r - l, Reverse(0), 0, l, r));
+        while let Some((cnt, Reverse(idx), depth, l, r)) = heap.pop() {
+            if res.len() >= k {
+                break;
+            }
+            if depth == self.height {
+                res.push((self.sorted_v[idx], cnt));
+                continue;
+            }
+
+            let bit = &self.bit_table[depth];
+            let rank_l = bit.rank(l);
+            let rank_r = bit.rank(r);
+            let zero_count = (r - l) - (rank_r - rank_l);
+            if zero_count > 0 {
+                heap.push((zero_count, Reverse(idx), depth + 1, l - rank_l, r - rank_r));
+            }
+            if zero_count < r - l {
+                let zeros = Self::zeros(bit);
+                let one_idx = idx | 1 << (self.height - 1 - depth);
+                heap.push((
+                    (r - l) - zero_count,
+                    Reverse(one_idx),
+                    depth + 1,
+                    rank_l + zeros,
+                    rank_r + zeros,
+                ));
+            }
+        }
+
+        res
+    }
 }
@@ -0,0 +1,297 @@
+use crate::algebra::group::Group;
+
+/// A Union-Find that also maintains the potential of each element relative to its root,
+/// where the potentials live in an abelian group `G`.
+///
+/// 各要素の根に対するポテンシャルを, アーベル群 `G` の元として管理する Union-Find である.
+///
+/// The potential difference `diff(x, y)` is `pot(y) - pot(x)`, written as `op(pot(y), inv(pot(x)))`.
+/// ポテンシャルの差 `diff(x, y)` は `pot(y) - pot(x)` であり, `op(pot(y), inv(pot(x)))` と書かれる.
+#[derive(Clone)]
+pub struct WeightedUnionFind<G>
+where
+    G: Group,
+{
+    parent: Vec<usize>,
+    size: Vec<usize>,
+
+    // The potential of each element relative to its parent.
+    weight: Vec<G::S>,
+}
+
+impl<G> WeightedUnionFind<G>
+where
+    G: Group,
+    G::S: Clone + PartialEq,
+{
+    /// Creates a new `WeightedUnionFind` instance with `n` elements.
+    /// Initially, each element is in its own set with the identity as its potential.
+    ///
+    /// `n` 個の要素を持つ新しい `WeightedUnionFind` インスタンスを生成する.
+    /// 初期状態では, 各要素がそれぞれ独立した集合に属し, ポテンシャルは単位元である.
+    ///
+    /// # Args
+    /// * `n`: The number of elements.
+    ///   要素数.
+    ///
+    /// # Returns
+    /// A new `WeightedUnionFind` instance.
+    /// 新しい `WeightedUnionFind` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    /// - Space complexity: O(N), where N is the number of elements.
+    ///   ここで N は要素数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::weighted_union_find::WeightedUnionFind};
+    /// let uf = WeightedUnionFind::<AddMonoid>::new(5);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        WeightedUnionFind {
+            parent: (0..n).collect::<Vec<_>>(),
+            size: vec![1; n],
+            weight: vec![G::id(); n],
+        }
+    }
+
+    /// Returns the number of elements managed by this `WeightedUnionFind`.
+    /// `WeightedUnionFind` が管理する要素数を返す.
+    ///
+    /// # Returns
+    /// The total number of elements.
+    /// 全要素数.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if this `WeightedUnionFind` manages no elements.
+    /// `WeightedUnionFind` が要素を一つも管理していない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `true` if `self.len() == 0`, otherwise `false`.
+    /// `self.len() == 0` であれば `true` を, そうでなければ `false` を返す.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    fn check_index(&self, x: usize) {
+        if x >= self.len() {
+            panic!(
+                "Index {} is out of bounds for WeightedUnionFind with size {}",
+                x,
+                self.len()
+            );
+        }
+    }
+
+    /// Finds the root of the set containing element `x`.
+    /// This method also performs path compression.
+    ///
+    /// 要素 `x` を含む集合の根を見つける.
+    /// このメソッドは経路圧縮も同時に行う.
+    ///
+    /// # Args
+    /// * `x`: The element to find the root of.
+    ///   根を探す対象の要素.
+    ///
+    /// # Returns
+    /// The root of the set.
+    /// 属する集合の根を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(α(N)), where α is the inverse Ackermann function.
+    ///   ここで α は逆アッカーマン関数である.
+    pub fn find(&mut self, x: usize) -> usize {
+        self.check_index(x);
+
+        let parent = self.parent[x];
+        if parent == x {
+            x
+        } else {
+            // Path compression: fold the potential of the parent into `x` before relinking it.
+            let root = self.find(parent);
+            self.weight[x] = G::op(&self.weight[x], &self.weight[parent]);
+            self.parent[x] = root;
+            root
+        }
+    }
+
+    /// Returns the potential of element `x` relative to the root of its set.
+    /// 要素 `x` の, 属する集合の根に対するポテンシャルを返す.
+    ///
+    /// # Args
+    /// * `x`: The element.
+    ///   対象の要素.
+    ///
+    /// # Returns
+    /// The potential `pot(x) - pot(root)`.
+    /// ポテンシャル `pot(x) - pot(root)`.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(α(N)), where α is the inverse Ackermann function.
+    ///   ここで α は逆アッカーマン関数である.
+    pub fn potential(&mut self, x: usize) -> G::S {
+        self.find(x);
+        self.weight[x].clone()
+    }
+
+    /// Adds the constraint `pot(y) - pot(x) = w`, merging the sets of `x` and `y`.
+    /// This method uses union by size to keep the tree structure balanced.
+    ///
+    /// 制約 `pot(y) - pot(x) = w` を追加し, `x` と `y` を含む集合をマージする.
+    /// このメソッドはサイズによる統合 (union by size) を用いて木構造のバランスを保つ.
+    ///
+    /// # Args
+    /// * `x`: An element in the first set.
+    ///   最初の集合に含まれる要素.
+    /// * `y`: An element in the second set.
+    ///   二番目の集合に含まれる要素.
+    /// * `w`: The potential difference `pot(y) - pot(x)`.
+    ///   ポテンシャルの差 `pot(y) - pot(x)`.
+    ///
+    /// # Returns
+    /// `true` if the constraint is consistent with the previous ones, otherwise `false`.
+    /// An inconsistent constraint is ignored.
+    /// 制約がそれまでの制約と矛盾しない場合は `true` を, そうでなければ `false` を返す.
+    /// 矛盾する制約は無視される.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(α(N)), where α is the inverse Ackermann function.
+    ///   ここで α は逆アッカーマン関数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::weighted_union_find::WeightedUnionFind};
+    /// let mut uf = WeightedUnionFind::<AddMonoid>::new(3);
+    /// assert!(uf.union(0, 1, 5));
+    /// assert!(uf.union(1, 2, 3));
+    /// assert!(uf.union(0, 2, 8));
+    /// assert!(!uf.union(0, 2, 7));
+    /// ```
+    pub fn union(&mut self, x: usize, y: usize, w: G::S) -> bool {
+        let len = self.len();
+        if x >= len || y >= len {
+            panic!(
+                "Index out of bounds for union: x={}, y={}, len={}",
+                x, y, len
+            );
+        }
+
+        let (mut root_x, mut root_y) = (self.find(x), self.find(y));
+        if root_x == root_y {
+            return self.diff(x, y) == Some(w);
+        }
+
+        // pot(root_y) - pot(root_x) = pot(x) + w - pot(y).
+        let mut w = G::op(&G::op(&self.weight[x], &w), &G::inv(&self.weight[y]));
+
+        // Union by size: Attach the smaller tree to the root of the larger tree.
+        if self.size[root_x] < self.size[root_y] {
+            std::mem::swap(&mut root_x, &mut root_y);
+            w = G::inv(&w);
+        }
+        self.parent[root_y] = root_x;
+        self.size[root_x] += self.size[root_y];
+        self.weight[root_y] = w;
+        true
+    }
+
+    /// Checks if elements `x` and `y` are in the same set.
+    /// 要素 `x` と `y` が同じ集合に属するかどうかを判定する.
+    ///
+    /// # Args
+    /// * `x`: The first element.
+    ///   最初の要素.
+    /// * `y`: The second element.
+    ///   二番目の要素.
+    ///
+    /// # Returns
+    /// `true` if `x` and `y` are in the same set, otherwise `false`.
+    /// `x` と `y` が同じ集合に属する場合は `true` を, そうでなければ `false` を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    pub fn is_same(&mut self, x: usize, y: usize) -> bool {
+        let len = self.len();
+        if x >= len || y >= len {
+            panic!(
+                "Index out of bounds for is_same: x={}, y={}, len={}",
+                x, y, len
+            );
+        }
+
+        self.find(x) == self.find(y)
+    }
+
+    /// Returns the potential difference `pot(y) - pot(x)` if it is determined.
+    /// ポテンシャルの差 `pot(y) - pot(x)` が定まっていれば返す.
+    ///
+    /// # Args
+    /// * `x`: The first element.
+    ///   最初の要素.
+    /// * `y`: The second element.
+    ///   二番目の要素.
+    ///
+    /// # Returns
+    /// `Some(pot(y) - pot(x))` if `x` and `y` are in the same set, otherwise `None`.
+    /// `x` と `y` が同じ集合に属する場合は `Some(pot(y) - pot(x))` を, そうでなければ `None` を返す.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are out of bounds.
+    /// `x` または `y` が範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(α(N)), where α is the inverse Ackermann function.
+    ///   ここで α は逆アッカーマン関数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, ds::weighted_union_find::WeightedUnionFind};
+    /// let mut uf = WeightedUnionFind::<AddMonoid>::new(3);
+    /// uf.union(0, 1, 5);
+    /// assert_eq!(Some(-5), uf.diff(1, 0));
+    /// assert_eq!(None, uf.diff(0, 2));
+    /// ```
+    pub fn diff(&mut self, x: usize, y: usize) -> Option<G::S> {
+        if !self.is_same(x, y) {
+            return None;
+        }
+
+        Some(G::op(&self.weight[y], &G::inv(&self.weight[x])))
+    }
+
+    /// Returns the size of the set containing element `x`.
+    /// 要素 `x` を含む集合のサイズを返す.
+    ///
+    /// # Args
+    /// * `x`: The element.
+    ///   対象の要素.
+    ///
+    /// # Returns
+    /// The size of the set.
+    /// 集合のサイズ.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    /// `x` が範囲外の場合にパニックする.
+    pub fn get_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
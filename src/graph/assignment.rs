@@ -0,0 +1,141 @@
+//! Weighted assignment problems by the Hungarian algorithm.
+//! ハンガリアン法による重み付き割当問題である.
+
+// Panics unless `cost` is a rectangular matrix with at most as many rows as columns.
+fn check_matrix(cost: &[Vec<i64>]) {
+    let m = cost.first().map_or(0, |row| row.len());
+    if cost.iter().any(|row| row.len() != m) {
+        panic!("Every row of the cost matrix must have the same length");
+    }
+    if cost.len() > m {
+        panic!(
+            "The number of rows must not exceed the number of columns (rows: {}, columns: {})",
+            cost.len(),
+            m
+        );
+    }
+}
+
+/// Assigns each row to a distinct column so that the total cost is minimized, by the Hungarian algorithm.
+/// ハンガリアン法により, 費用の合計が最小となるように各行を相異なる列に割り当てる.
+///
+/// # Args
+/// - `cost`: The `N x M` cost matrix, where `cost[i][j]` is the cost of assigning row `i` to column `j`.
+///   `N x M` の費用行列. `cost[i][j]` は行 `i` を列 `j` に割り当てる費用である.
+///
+/// # Returns
+/// `(i64, Vec<usize>)`: The minimum total cost and the column assigned to each row.
+///                      費用の合計の最小値と, 各行に割り当てられた列.
+///
+/// # Constraints
+/// - `N <= M`.
+///
+/// # Panics
+/// Panics if the rows have different lengths, or there are more rows than columns.
+/// 行の長さが異なる場合, または行数が列数より多い場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N^2 M).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::assignment::hungarian_min;
+/// let cost = vec![vec![4, 1, 3], vec![2, 0, 5]];
+/// assert_eq!((3, vec![1, 0]), hungarian_min(&cost));
+/// ```
+pub fn hungarian_min(cost: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    check_matrix(cost);
+
+    let n = cost.len();
+    let m = cost.first().map_or(0, |row| row.len());
+
+    // Rows and columns are 1-indexed below, and column 0 is a sentinel matched with the row being inserted.
+    let mut u = vec![0; n + 1];
+    let mut v = vec![0; m + 1];
+    let mut row_of = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_slack = vec![i64::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let (mut delta, mut j1) = (i64::MAX, 0);
+            for j in (1..=m).filter(|&j| !used[j]) {
+                let slack = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+
+        // Flip the alternating path ending at the free column `j0`.
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for j in (1..=m).filter(|&j| row_of[j] != 0) {
+        assignment[row_of[j] - 1] = j - 1;
+    }
+    let total = (0..n).map(|i| cost[i][assignment[i]]).sum();
+    (total, assignment)
+}
+
+/// Assigns each row to a distinct column so that the total profit is maximized, by the Hungarian algorithm.
+/// ハンガリアン法により, 利益の合計が最大となるように各行を相異なる列に割り当てる.
+///
+/// # Args
+/// - `profit`: The `N x M` profit matrix, where `profit[i][j]` is the profit of assigning row `i` to column `j`.
+///   `N x M` の利益行列. `profit[i][j]` は行 `i` を列 `j` に割り当てる利益である.
+///
+/// # Returns
+/// `(i64, Vec<usize>)`: The maximum total profit and the column assigned to each row.
+///                      利益の合計の最大値と, 各行に割り当てられた列.
+///
+/// # Constraints
+/// - `N <= M`.
+///
+/// # Panics
+/// Panics if the rows have different lengths, or there are more rows than columns.
+/// 行の長さが異なる場合, または行数が列数より多い場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N^2 M).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::assignment::hungarian_max;
+/// let profit = vec![vec![4, 1, 3], vec![2, 0, 5]];
+/// assert_eq!((9, vec![0, 2]), hungarian_max(&profit));
+/// ```
+pub fn hungarian_max(profit: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    let negated = profit
+        .iter()
+        .map(|row| row.iter().map(|&p| -p).collect())
+        .collect::<Vec<_>>();
+    let (total, assignment) = hungarian_min(&negated);
+    (-total, assignment)
+}
@@ -0,0 +1,244 @@
+//! A module that defines the `Graph` type shared by the graph algorithms.
+//! グラフアルゴリズムで共有される `Graph` 型を定義するモジュールである.
+
+/// A graph with `n` vertices stored as a list of weighted edges `(from, to, weight)`,
+/// together with a compressed sparse row (CSR) adjacency built from it.
+/// An undirected edge appears in the adjacency of both endpoints.
+///
+/// `n` 頂点のグラフであり, 重み付き辺 `(from, to, weight)` のリストと,
+/// そこから構築した compressed sparse row (CSR) 形式の隣接リストとして保持される.
+/// 無向辺は両端点の隣接リストに現れる.
+#[derive(Clone, Debug)]
+pub struct Graph<W> {
+    n: usize,
+    directed: bool,
+    edges: Vec<(usize, usize, W)>,
+
+    // The adjacency of `v` is `adj[start[v]..start[v + 1]]` as pairs of (neighbor, edge index).
+    start: Vec<usize>,
+    adj: Vec<(usize, usize)>,
+}
+
+impl<W> Graph<W> {
+    fn new(n: usize, edges: Vec<(usize, usize, W)>, directed: bool) -> Self {
+        let mut start = vec![0; n + 1];
+        for &(u, v, _) in edges.iter() {
+            check_vertex(n, u);
+            check_vertex(n, v);
+            start[u + 1] += 1;
+            if !directed {
+                start[v + 1] += 1;
+            }
+        }
+        for v in 0..n {
+            start[v + 1] += start[v];
+        }
+
+        // Fill each adjacency in the order of the edges.
+        let mut pos = start.clone();
+        let mut adj = vec![(0, 0); start[n]];
+        for (i, &(u, v, _)) in edges.iter().enumerate() {
+            adj[pos[u]] = (v, i);
+            pos[u] += 1;
+            if !directed {
+                adj[pos[v]] = (u, i);
+                pos[v] += 1;
+            }
+        }
+
+        Graph {
+            n,
+            directed,
+            edges,
+            start,
+            adj,
+        }
+    }
+
+    /// Creates a directed graph in which each edge `(from, to, weight)` goes from `from` to `to`.
+    /// 各辺 `(from, to, weight)` が `from` から `to` へ向かう有向グラフを生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of vertices.
+    ///   頂点数.
+    /// - `edges`: The edges `(from, to, weight)`.
+    ///   辺 `(from, to, weight)` のリスト.
+    ///
+    /// # Returns
+    /// `Graph<W>`: A new directed graph.
+    ///             新しい有向グラフ.
+    ///
+    /// # Panics
+    /// Panics if an endpoint of an edge is not less than `n`.
+    /// 辺の端点が `n` 以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+    ///   ここで N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::Graph;
+    /// let g = Graph::directed(3, vec![(0, 1, 5), (1, 2, 3)]);
+    /// assert!(g.is_directed());
+    /// assert_eq!(2, g.num_edges());
+    /// ```
+    pub fn directed(n: usize, edges: Vec<(usize, usize, W)>) -> Self {
+        Self::new(n, edges, true)
+    }
+
+    /// Creates an undirected graph in which each edge `(u, v, weight)` connects `u` and `v`.
+    /// 各辺 `(u, v, weight)` が `u` と `v` を結ぶ無向グラフを生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of vertices.
+    ///   頂点数.
+    /// - `edges`: The edges `(u, v, weight)`.
+    ///   辺 `(u, v, weight)` のリスト.
+    ///
+    /// # Returns
+    /// `Graph<W>`: A new undirected graph.
+    ///             新しい無向グラフ.
+    ///
+    /// # Panics
+    /// Panics if an endpoint of an edge is not less than `n`.
+    /// 辺の端点が `n` 以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+    ///   ここで N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::Graph;
+    /// let g = Graph::undirected(3, vec![(0, 1, 5), (1, 2, 3)]);
+    /// assert!(!g.is_directed());
+    /// assert_eq!(3, g.num_vertices());
+    /// ```
+    pub fn undirected(n: usize, edges: Vec<(usize, usize, W)>) -> Self {
+        Self::new(n, edges, false)
+    }
+
+    /// Returns the number of vertices.
+    /// 頂点数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of vertices.
+    ///          頂点数.
+    pub fn num_vertices(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of edges.
+    /// 辺数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of edges.
+    ///          辺数.
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns `true` if the graph is directed.
+    /// グラフが有向であれば `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: `true` for a directed graph, `false` for an undirected one.
+    ///         有向グラフであれば `true`, 無向グラフであれば `false`.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the edges in the order they were given.
+    /// 辺を与えられた順に返す.
+    ///
+    /// # Returns
+    /// `&[(usize, usize, W)]`: The edges `(from, to, weight)`.
+    ///                         辺 `(from, to, weight)` のリスト.
+    pub fn edges(&self) -> &[(usize, usize, W)] {
+        &self.edges
+    }
+
+    /// Returns the edges leaving `v` as pairs of the neighbor and the edge index.
+    /// `v` から出る辺を, 隣接頂点と辺のインデックスの組として返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `&[(usize, usize)]`: The pairs `(neighbor, edge index)` in the order of the edges.
+    ///                      辺の順に並んだ `(隣接頂点, 辺のインデックス)` の組.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::Graph;
+    /// let g = Graph::undirected(3, vec![(0, 1, 5), (2, 0, 3)]);
+    /// assert_eq!(&[(1, 0), (2, 1)], g.out_edges(0));
+    /// assert_eq!(&[(0, 1)], g.out_edges(2));
+    /// ```
+    pub fn out_edges(&self, v: usize) -> &[(usize, usize)] {
+        check_vertex(self.n, v);
+        &self.adj[self.start[v]..self.start[v + 1]]
+    }
+
+    /// Returns an iterator over the neighbors of `v`.
+    /// `v` の隣接頂点を走査するイテレータを返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// An iterator over the heads of the edges leaving `v`.
+    /// `v` から出る辺の行き先を走査するイテレータ.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::Graph;
+    /// let g = Graph::directed(3, vec![(0, 1, ()), (0, 2, ()), (2, 0, ())]);
+    /// assert_eq!(vec![1, 2], g.neighbors(0).collect::<Vec<_>>());
+    /// ```
+    pub fn neighbors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.out_edges(v).iter().map(|&(to, _)| to)
+    }
+
+    /// Returns the out-degree of `v`, which is the degree for an undirected graph.
+    /// `v` の出次数を返す. 無向グラフでは次数である.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `usize`: The number of edges leaving `v`.
+    ///          `v` から出る辺の数.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn degree(&self, v: usize) -> usize {
+        self.out_edges(v).len()
+    }
+}
+
+// Panics unless `v` is a vertex of a graph with `n` vertices.
+pub(crate) fn check_vertex(n: usize, v: usize) {
+    if v >= n {
+        panic!(
+            "index out of bounds: the len is {} but the index is {}",
+            n, v
+        );
+    }
+}
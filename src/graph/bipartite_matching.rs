@@ -0,0 +1,227 @@
+//! A module that defines `BipartiteMatching`, which computes maximum matchings by the Hopcroft-Karp algorithm.
+//! Hopcroft-Karp 法により最大マッチングを計算する `BipartiteMatching` を定義するモジュールである.
+
+use std::collections::VecDeque;
+
+use super::base::check_vertex;
+
+/// A bipartite graph that computes maximum matchings by the Hopcroft-Karp algorithm.
+/// The left and right vertices are numbered separately from 0.
+///
+/// Hopcroft-Karp 法により最大マッチングを計算する二部グラフである.
+/// 左側と右側の頂点はそれぞれ 0 から番号付けされる.
+#[derive(Clone, Debug)]
+pub struct BipartiteMatching {
+    adj: Vec<Vec<usize>>,
+    match_left: Vec<Option<usize>>,
+    match_right: Vec<Option<usize>>,
+}
+
+impl BipartiteMatching {
+    /// Creates a bipartite graph with `left` and `right` vertices on each side and no edges.
+    /// 左側に `left` 頂点, 右側に `right` 頂点を持ち, 辺を持たない二部グラフを生成する.
+    ///
+    /// # Args
+    /// - `left`: The number of left vertices.
+    ///   左側の頂点数.
+    /// - `right`: The number of right vertices.
+    ///   右側の頂点数.
+    ///
+    /// # Returns
+    /// A new `BipartiteMatching` instance.
+    /// 新しい `BipartiteMatching` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(L + R), where L and R are the numbers of left and right vertices.
+    ///   ここで L, R はそれぞれ左側, 右側の頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::bipartite_matching::BipartiteMatching;
+    /// let bm = BipartiteMatching::new(2, 3);
+    /// ```
+    pub fn new(left: usize, right: usize) -> Self {
+        BipartiteMatching {
+            adj: vec![Vec::new(); left],
+            match_left: vec![None; left],
+            match_right: vec![None; right],
+        }
+    }
+
+    /// Adds an edge between the left vertex `l` and the right vertex `r`.
+    /// 左側の頂点 `l` と右側の頂点 `r` の間に辺を追加する.
+    ///
+    /// # Args
+    /// - `l`: The left vertex.
+    ///   左側の頂点.
+    /// - `r`: The right vertex.
+    ///   右側の頂点.
+    ///
+    /// # Panics
+    /// Panics if `l` or `r` is out of bounds on its side.
+    /// `l` または `r` がその側の範囲外の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1) amortized.
+    pub fn add_edge(&mut self, l: usize, r: usize) {
+        check_vertex(self.match_left.len(), l);
+        check_vertex(self.match_right.len(), r);
+        self.adj[l].push(r);
+    }
+
+    /// Computes a maximum matching, extending the matching found by previous calls.
+    /// 以前の呼び出しで求めたマッチングを拡張して, 最大マッチングを計算する.
+    ///
+    /// # Returns
+    /// `Vec<(usize, usize)>`: The matched pairs `(l, r)` in ascending order of `l`.
+    ///                        `l` の昇順に並んだマッチした組 `(l, r)`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(M √N), where N is the number of vertices and M is the number of edges.
+    ///   ここで N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::bipartite_matching::BipartiteMatching;
+    /// let mut bm = BipartiteMatching::new(3, 2);
+    /// bm.add_edge(0, 0);
+    /// bm.add_edge(1, 0);
+    /// bm.add_edge(1, 1);
+    /// bm.add_edge(2, 1);
+    /// assert_eq!(2, bm.max_matching().len());
+    /// ```
+    pub fn max_matching(&mut self) -> Vec<(usize, usize)> {
+        while let Some(mut dist) = self.layers() {
+            let mut next = vec![0; self.adj.len()];
+            for l in 0..self.adj.len() {
+                if self.match_left[l].is_none() {
+                    self.augment(l, &mut dist, &mut next);
+                }
+            }
+        }
+
+        self.match_left
+            .iter()
+            .enumerate()
+            .filter_map(|(l, &r)| Some((l, r?)))
+            .collect()
+    }
+
+    /// Computes a minimum vertex cover by König's theorem after computing a maximum matching.
+    /// Its size equals the size of a maximum matching.
+    ///
+    /// 最大マッチングを計算した上で, König の定理により最小頂点被覆を計算する.
+    /// その大きさは最大マッチングの大きさに等しい.
+    ///
+    /// # Returns
+    /// `(Vec<usize>, Vec<usize>)`: The left and right vertices in the cover, each in ascending order.
+    ///                             被覆に含まれる左側と右側の頂点. それぞれ昇順に並ぶ.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(M √N), where N is the number of vertices and M is the number of edges.
+    ///   ここで N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::bipartite_matching::BipartiteMatching;
+    /// let mut bm = BipartiteMatching::new(3, 2);
+    /// bm.add_edge(0, 0);
+    /// bm.add_edge(1, 0);
+    /// bm.add_edge(2, 0);
+    /// bm.add_edge(2, 1);
+    /// assert_eq!((vec![2], vec![0]), bm.min_vertex_cover());
+    /// ```
+    pub fn min_vertex_cover(&mut self) -> (Vec<usize>, Vec<usize>) {
+        self.max_matching();
+
+        // Collect the vertices reachable from the unmatched left vertices by alternating paths.
+        let mut seen_left = self
+            .match_left
+            .iter()
+            .map(|m| m.is_none())
+            .collect::<Vec<_>>();
+        let mut seen_right = vec![false; self.match_right.len()];
+        let mut stack = (0..self.adj.len())
+            .filter(|&l| seen_left[l])
+            .collect::<Vec<_>>();
+        while let Some(l) = stack.pop() {
+            for &r in self.adj[l].iter() {
+                if seen_right[r] {
+                    continue;
+                }
+                seen_right[r] = true;
+                if let Some(l2) = self.match_right[r]
+                    && !seen_left[l2]
+                {
+                    seen_left[l2] = true;
+                    stack.push(l2);
+                }
+            }
+        }
+
+        let left = (0..self.adj.len()).filter(|&l| !seen_left[l]).collect();
+        let right = (0..self.match_right.len())
+            .filter(|&r| seen_right[r])
+            .collect();
+        (left, right)
+    }
+
+    // Computes the BFS layers of the left vertices from the unmatched ones,
+    // or returns `None` if no augmenting path exists.
+    fn layers(&self) -> Option<Vec<usize>> {
+        let mut dist = vec![usize::MAX; self.adj.len()];
+        let mut queue = VecDeque::new();
+        for (l, m) in self.match_left.iter().enumerate() {
+            if m.is_none() {
+                dist[l] = 0;
+                queue.push_back(l);
+            }
+        }
+
+        let mut found = false;
+        while let Some(l) = queue.pop_front() {
+            for &r in self.adj[l].iter() {
+                match self.match_right[r] {
+                    None => found = true,
+                    Some(l2) if dist[l2] == usize::MAX => {
+                        dist[l2] = dist[l] + 1;
+                        queue.push_back(l2);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        found.then_some(dist)
+    }
+
+    // Searches an augmenting path from the unmatched left vertex `root` along the layers
+    // with an explicit stack, and flips the matching along it if found.
+    fn augment(&mut self, root: usize, dist: &mut [usize], next: &mut [usize]) {
+        let mut stack = vec![root];
+        while let Some(&l) = stack.last() {
+            let Some(&r) = self.adj[l].get(next[l]) else {
+                // `l` cannot reach an unmatched right vertex anymore.
+                dist[l] = usize::MAX;
+                stack.pop();
+                if let Some(&parent) = stack.last() {
+                    next[parent] += 1;
+                }
+                continue;
+            };
+
+            match self.match_right[r] {
+                None => {
+                    // Each vertex on the stack is matched with the right vertex it is currently pointing to.
+                    for &l in stack.iter() {
+                        let r = self.adj[l][next[l]];
+                        self.match_left[l] = Some(r);
+                        self.match_right[r] = Some(l);
+                    }
+                    return;
+                }
+                Some(l2) if dist[l2] == dist[l] + 1 => stack.push(l2),
+                _ => next[l] += 1,
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Topological sorting and connected components of `Graph`.
+//! `Graph` のトポロジカルソートおよび連結成分である.
+
+use super::base::Graph;
+use crate::ds::union_find::UnionFind;
+
+fn check_directed<W>(g: &Graph<W>) {
+    if !g.is_directed() {
+        panic!("The graph must be directed");
+    }
+}
+
+/// Sorts the vertices of a directed graph topologically by Kahn's algorithm.
+/// Kahn のアルゴリズムにより有向グラフの頂点をトポロジカルソートする.
+///
+/// # Args
+/// - `g`: The directed graph.
+///   有向グラフ.
+///
+/// # Returns
+/// `Option<Vec<usize>>`: The vertices such that every edge goes forward, or `None` if the graph has a cycle.
+///                       全ての辺が前から後ろへ向かうように並べた頂点. グラフが閉路を持つなら `None`.
+///
+/// # Panics
+/// Panics if `g` is undirected.
+/// `g` が無向グラフの場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{components::toposort, Graph};
+/// let g = Graph::directed(3, vec![(2, 0, ()), (0, 1, ())]);
+/// assert_eq!(Some(vec![2, 0, 1]), toposort(&g));
+/// let g = Graph::directed(2, vec![(0, 1, ()), (1, 0, ())]);
+/// assert_eq!(None, toposort(&g));
+/// ```
+pub fn toposort<W>(g: &Graph<W>) -> Option<Vec<usize>> {
+    check_directed(g);
+
+    let n = g.num_vertices();
+    let mut indegree = vec![0; n];
+    for &(_, v, _) in g.edges() {
+        indegree[v] += 1;
+    }
+
+    let mut order = (0..n).filter(|&v| indegree[v] == 0).collect::<Vec<_>>();
+    let mut head = 0;
+    while head < order.len() {
+        let v = order[head];
+        head += 1;
+        for to in g.neighbors(v) {
+            indegree[to] -= 1;
+            if indegree[to] == 0 {
+                order.push(to);
+            }
+        }
+    }
+
+    // Vertices on or behind a cycle never reach indegree 0.
+    if order.len() < n {
+        return None;
+    }
+    Some(order)
+}
+
+/// Labels the connected components, ignoring the directions of the edges.
+/// 辺の向きを無視して連結成分に番号を付ける.
+///
+/// # Args
+/// - `g`: The graph.
+///   グラフ.
+///
+/// # Returns
+/// `(Vec<usize>, usize)`: `(ids, count)`, where `ids[v]` is in `0..count` and ids are assigned
+///                        in the order of the smallest vertices of the components.
+///                        `(ids, count)`. `ids[v]` は `0..count` の範囲にあり, 番号は成分の最小の頂点の順に割り当てられる.
+///
+/// # Complexity
+/// - Time complexity: O(N + M α(N)), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{components::connected_components, Graph};
+/// let g = Graph::undirected(4, vec![(3, 1, ())]);
+/// assert_eq!((vec![0, 1, 2, 1], 3), connected_components(&g));
+/// ```
+pub fn connected_components<W>(g: &Graph<W>) -> (Vec<usize>, usize) {
+    let mut uf = UnionFind::new(g.num_vertices());
+    for &(u, v, _) in g.edges() {
+        uf.union(u, v);
+    }
+    uf.component_ids()
+}
+
+/// Labels the strongly connected components of a directed graph by Tarjan's algorithm.
+/// Tarjan のアルゴリズムにより有向グラフの強連結成分に番号を付ける.
+///
+/// # Args
+/// - `g`: The directed graph.
+///   有向グラフ.
+///
+/// # Returns
+/// `(Vec<usize>, usize)`: `(ids, count)`, where `ids[v]` is in `0..count` and ids are in topological
+///                        order, i.e. every edge `(u, v)` satisfies `ids[u] <= ids[v]`.
+///                        `(ids, count)`. `ids[v]` は `0..count` の範囲にあり, 番号はトポロジカル順,
+///                        すなわち全ての辺 `(u, v)` が `ids[u] <= ids[v]` を満たす.
+///
+/// # Panics
+/// Panics if `g` is undirected.
+/// `g` が無向グラフの場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{components::strongly_connected_components, Graph};
+/// let g = Graph::directed(4, vec![(1, 2, ()), (2, 1, ()), (0, 1, ()), (2, 3, ())]);
+/// assert_eq!((vec![0, 1, 1, 2], 3), strongly_connected_components(&g));
+/// ```
+pub fn strongly_connected_components<W>(g: &Graph<W>) -> (Vec<usize>, usize) {
+    check_directed(g);
+
+    let n = g.num_vertices();
+    let mut ord = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut ids = vec![usize::MAX; n];
+    let mut visited = Vec::with_capacity(n);
+    let (mut time, mut count) = (0, 0);
+
+    for s in 0..n {
+        if ord[s] != usize::MAX {
+            continue;
+        }
+
+        // Each frame holds a vertex and the position of the next edge to follow.
+        let mut stack = vec![(s, 0)];
+        ord[s] = time;
+        low[s] = time;
+        time += 1;
+        visited.push(s);
+        while let Some((v, i)) = stack.last_mut() {
+            let v = *v;
+            if let Some(&(to, _)) = g.out_edges(v).get(*i) {
+                *i += 1;
+                if ord[to] == usize::MAX {
+                    ord[to] = time;
+                    low[to] = time;
+                    time += 1;
+                    visited.push(to);
+                    stack.push((to, 0));
+                } else if ids[to] == usize::MAX {
+                    // `to` is still on the stack of the current component candidates.
+                    low[v] = low[v].min(ord[to]);
+                }
+                continue;
+            }
+
+            stack.pop();
+            if let Some(&(parent, _)) = stack.last() {
+                low[parent] = low[parent].min(low[v]);
+            }
+            if low[v] == ord[v] {
+                while let Some(u) = visited.pop() {
+                    ids[u] = count;
+                    if u == v {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    // Tarjan's algorithm finds the components in reverse topological order.
+    for id in ids.iter_mut() {
+        *id = count - 1 - *id;
+    }
+    (ids, count)
+}
+
+/// Computes the condensation of a directed graph, which contracts each strongly connected component.
+/// 有向グラフの縮約, すなわち各強連結成分を一つの頂点に潰した DAG を計算する.
+///
+/// # Args
+/// - `g`: The directed graph.
+///   有向グラフ.
+///
+/// # Returns
+/// `(Vec<usize>, Graph<()>)`: The component id of each vertex as in `strongly_connected_components`,
+///                            and the DAG on the components without self-loops or parallel edges.
+///                            `strongly_connected_components` と同じ各頂点の成分番号と,
+///                            自己ループや多重辺を持たない成分上の DAG.
+///
+/// # Panics
+/// Panics if `g` is undirected.
+/// `g` が無向グラフの場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N + M log M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{components::condensation, Graph};
+/// let g = Graph::directed(3, vec![(0, 1, ()), (1, 0, ()), (1, 2, ()), (0, 2, ())]);
+/// let (ids, dag) = condensation(&g);
+/// assert_eq!(vec![0, 0, 1], ids);
+/// assert_eq!(&[(0, 1, ())], dag.edges());
+/// ```
+pub fn condensation<W>(g: &Graph<W>) -> (Vec<usize>, Graph<()>) {
+    let (ids, count) = strongly_connected_components(g);
+    let mut edges = g
+        .edges()
+        .iter()
+        .map(|&(u, v, _)| (ids[u], ids[v]))
+        .filter(|&(a, b)| a != b)
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let dag = Graph::directed(count, edges.into_iter().map(|(a, b)| (a, b, ())).collect());
+    (ids, dag)
+}
@@ -0,0 +1,177 @@
+//! Maximum matchings of general graphs by Edmonds' blossom algorithm.
+//! Edmonds の花アルゴリズムによる一般グラフの最大マッチングである.
+
+use std::collections::VecDeque;
+
+use super::base::check_vertex;
+
+const NONE: usize = usize::MAX;
+
+// The state of a search for an augmenting path from a single root.
+struct Blossom<'a> {
+    adj: &'a [Vec<usize>],
+    mate: &'a mut [usize],
+    parent: Vec<usize>,
+    base: Vec<usize>,
+    used: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl Blossom<'_> {
+    // Returns the base of the lowest common ancestor of `a` and `b` in the alternating tree.
+    fn lca(&self, mut a: usize, mut b: usize) -> usize {
+        let mut on_path = vec![false; self.mate.len()];
+        loop {
+            a = self.base[a];
+            on_path[a] = true;
+            if self.mate[a] == NONE {
+                break;
+            }
+            a = self.parent[self.mate[a]];
+        }
+        loop {
+            b = self.base[b];
+            if on_path[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b]];
+        }
+    }
+
+    // Marks the bases on the path from `v` up to the blossom base `b`, pointing the parents toward `child`.
+    fn mark_path(&mut self, mut v: usize, b: usize, mut child: usize, in_blossom: &mut [bool]) {
+        while self.base[v] != b {
+            in_blossom[self.base[v]] = true;
+            in_blossom[self.base[self.mate[v]]] = true;
+            self.parent[v] = child;
+            child = self.mate[v];
+            v = self.parent[self.mate[v]];
+        }
+    }
+
+    // Searches an augmenting path from the unmatched vertex `root`, and returns its other end if found.
+    fn find_path(&mut self, root: usize) -> Option<usize> {
+        let n = self.mate.len();
+        self.parent.fill(NONE);
+        self.used.fill(false);
+        for (i, b) in self.base.iter_mut().enumerate() {
+            *b = i;
+        }
+        self.queue.clear();
+        self.used[root] = true;
+        self.queue.push_back(root);
+
+        while let Some(v) = self.queue.pop_front() {
+            for &to in self.adj[v].iter() {
+                if self.base[v] == self.base[to] || self.mate[v] == to {
+                    continue;
+                }
+                if to == root || (self.mate[to] != NONE && self.parent[self.mate[to]] != NONE) {
+                    // An odd cycle is found, so it is contracted into its base.
+                    let b = self.lca(v, to);
+                    let mut in_blossom = vec![false; n];
+                    self.mark_path(v, b, to, &mut in_blossom);
+                    self.mark_path(to, b, v, &mut in_blossom);
+                    for i in 0..n {
+                        if in_blossom[self.base[i]] {
+                            self.base[i] = b;
+                            if !self.used[i] {
+                                self.used[i] = true;
+                                self.queue.push_back(i);
+                            }
+                        }
+                    }
+                } else if self.parent[to] == NONE {
+                    self.parent[to] = v;
+                    if self.mate[to] == NONE {
+                        return Some(to);
+                    }
+                    let next = self.mate[to];
+                    self.used[next] = true;
+                    self.queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Computes a maximum matching of an undirected general graph by Edmonds' blossom algorithm.
+/// Edmonds の花アルゴリズムにより無向一般グラフの最大マッチングを計算する.
+///
+/// # Args
+/// - `n`: The number of vertices.
+///   頂点数.
+/// - `edges`: The undirected edges `(u, v)`. Self-loops are ignored.
+///   無向辺 `(u, v)` のリスト. 自己ループは無視される.
+///
+/// # Returns
+/// `Vec<(usize, usize)>`: The matched pairs `(u, v)` with `u < v`, in ascending order of `u`.
+///                        `u < v` を満たすマッチした組 `(u, v)`. `u` の昇順に並ぶ.
+///
+/// # Panics
+/// Panics if an endpoint of an edge is not less than `n`.
+/// 辺の端点が `n` 以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N^3), where N is the number of vertices.
+///   ここで N は頂点数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::general_matching::general_matching;
+/// // A triangle with a pendant vertex has a perfect matching.
+/// let edges = vec![(0, 1), (1, 2), (2, 0), (2, 3)];
+/// assert_eq!(vec![(0, 1), (2, 3)], general_matching(4, &edges));
+/// ```
+pub fn general_matching(n: usize, edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut adj = vec![Vec::new(); n];
+    for &(u, v) in edges.iter() {
+        check_vertex(n, u);
+        check_vertex(n, v);
+        if u != v {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+    }
+
+    // A greedy matching reduces the number of searches.
+    let mut mate = vec![NONE; n];
+    for &(u, v) in edges.iter() {
+        if u != v && mate[u] == NONE && mate[v] == NONE {
+            mate[u] = v;
+            mate[v] = u;
+        }
+    }
+
+    let mut search = Blossom {
+        adj: &adj,
+        mate: &mut mate,
+        parent: vec![NONE; n],
+        base: vec![0; n],
+        used: vec![false; n],
+        queue: VecDeque::new(),
+    };
+    for root in 0..n {
+        if search.mate[root] != NONE {
+            continue;
+        }
+        let Some(mut v) = search.find_path(root) else {
+            continue;
+        };
+
+        // Flip the matching along the augmenting path ending at `v`.
+        while v != NONE {
+            let pv = search.parent[v];
+            let next = search.mate[pv];
+            search.mate[v] = pv;
+            search.mate[pv] = v;
+            v = next;
+        }
+    }
+
+    (0..n)
+        .filter(|&u| mate[u] != NONE && u < mate[u])
+        .map(|u| (u, mate[u]))
+        .collect()
+}
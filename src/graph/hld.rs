@@ -0,0 +1,456 @@
+//! A module that defines `HeavyLightDecomposition`, which maps tree paths and subtrees to contiguous ranges.
+//! 木の経路と部分木を連続する区間に対応付ける `HeavyLightDecomposition` を定義するモジュールである.
+
+use super::base::check_vertex;
+use super::tree::Tree;
+use crate::algebra::monoid::Monoid;
+use crate::ds::segment_tree::segment_tree_dense::SegmentTreeDense;
+
+/// A heavy-light decomposition of a rooted tree.
+/// It numbers the vertices in a DFS preorder that visits the heavy child (the child with the largest subtree) first,
+/// so that every heavy path and every subtree occupies a contiguous range of positions,
+/// and every path crosses O(log N) heavy paths.
+///
+/// Path queries on a non-commutative monoid need the values in both directions,
+/// so the helpers take a `forward` tree holding the value of `v` at `pos(v)`
+/// and a `backward` tree holding it at `len() - 1 - pos(v)`, both built by `segment_trees`.
+///
+/// 根付き木の重軽分解である.
+/// 頂点を重い子 (部分木が最大の子) を先に訪問する DFS の行きがけ順に番号付けるため,
+/// 全ての重い経路と部分木は連続する位置の区間を占め, 全ての経路は O(log N) 個の重い経路を横切る.
+///
+/// 非可換なモノイド上の経路クエリは両方向の値を必要とするため, 補助関数は `v` の値を `pos(v)` に保持する
+/// `forward` 木と, `len() - 1 - pos(v)` に保持する `backward` 木を受け取る. どちらも `segment_trees` で構築される.
+#[derive(Clone, Debug)]
+pub struct HeavyLightDecomposition {
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    head: Vec<usize>,
+    pos: Vec<usize>,
+    subtree_size: Vec<usize>,
+}
+
+impl HeavyLightDecomposition {
+    /// Decomposes a rooted tree.
+    /// 根付き木を分解する.
+    ///
+    /// # Args
+    /// - `tree`: The rooted tree.
+    ///   根付き木.
+    ///
+    /// # Returns
+    /// A new `HeavyLightDecomposition` instance.
+    /// 新しい `HeavyLightDecomposition` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::{hld::HeavyLightDecomposition, tree::Tree};
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (0, 3)], 0);
+    /// let hld = HeavyLightDecomposition::new(&tree);
+    /// assert_eq!(vec![0, 1, 2, 3], (0..4).map(|v| hld.pos(v)).collect::<Vec<_>>());
+    /// ```
+    pub fn new(tree: &Tree) -> Self {
+        let n = tree.len();
+        let parent = (0..n).map(|v| tree.parent(v)).collect::<Vec<_>>();
+        let depth = (0..n).map(|v| tree.depth(v)).collect::<Vec<_>>();
+        let subtree_size = (0..n).map(|v| tree.subtree_size(v)).collect::<Vec<_>>();
+
+        let mut head = vec![tree.root(); n];
+        let mut pos = vec![0; n];
+        let mut next = 0;
+        let mut stack = vec![tree.root()];
+        while let Some(v) = stack.pop() {
+            pos[v] = next;
+            next += 1;
+
+            // The heavy child is pushed last, so that it is visited right after `v`.
+            let children = tree.children(v);
+            let heavy = children.iter().copied().max_by_key(|&c| subtree_size[c]);
+            for &c in children.iter().filter(|&&c| Some(c) != heavy) {
+                head[c] = c;
+                stack.push(c);
+            }
+            if let Some(h) = heavy {
+                head[h] = head[v];
+                stack.push(h);
+            }
+        }
+
+        HeavyLightDecomposition {
+            parent,
+            depth,
+            head,
+            pos,
+            subtree_size,
+        }
+    }
+
+    /// Returns the number of vertices.
+    /// 頂点数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of vertices.
+    ///          頂点数.
+    pub fn len(&self) -> usize {
+        self.pos.len()
+    }
+
+    /// Returns `true` if the tree has no vertices, which never happens.
+    /// 木が頂点を持たない場合に `true` を返す. これは決して起こらない.
+    ///
+    /// # Returns
+    /// `bool`: Always `false`.
+    ///         常に `false`.
+    pub fn is_empty(&self) -> bool {
+        self.pos.is_empty()
+    }
+
+    /// Returns the position of `v` in the decomposition order.
+    /// 分解の順序における `v` の位置を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `usize`: The position in `0..len()`.
+    ///          `0..len()` の範囲の位置.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn pos(&self, v: usize) -> usize {
+        check_vertex(self.len(), v);
+        self.pos[v]
+    }
+
+    /// Returns the range of positions occupied by the subtree of `v`.
+    /// `v` の部分木が占める位置の区間を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `(usize, usize)`: The half-open range `[l, r)` of positions.
+    ///                   位置の半開区間 `[l, r)`.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn subtree_range(&self, v: usize) -> (usize, usize) {
+        check_vertex(self.len(), v);
+        (self.pos[v], self.pos[v] + self.subtree_size[v])
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`.
+    /// `u` と `v` の最小共通祖先を返す.
+    ///
+    /// # Args
+    /// - `u`: The first vertex.
+    ///   一つ目の頂点.
+    /// - `v`: The second vertex.
+    ///   二つ目の頂点.
+    ///
+    /// # Returns
+    /// `usize`: The deepest vertex that is an ancestor of both `u` and `v`.
+    ///          `u` と `v` の両方の祖先である最も深い頂点.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        check_vertex(self.len(), u);
+        check_vertex(self.len(), v);
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] >= self.depth[self.head[v]] {
+                u = self.parent[self.head[u]].unwrap();
+            } else {
+                v = self.parent[self.head[v]].unwrap();
+            }
+        }
+        if self.depth[u] <= self.depth[v] { u } else { v }
+    }
+
+    /// Splits the path from `u` to `v` into ranges of positions.
+    /// `u` から `v` への経路を位置の区間に分割する.
+    ///
+    /// # Args
+    /// - `u`: The start of the path.
+    ///   経路の始点.
+    /// - `v`: The end of the path.
+    ///   経路の終点.
+    ///
+    /// # Returns
+    /// `Vec<(usize, usize, bool)>`: O(log N) triples `(l, r, reversed)` in the order of the path from `u` to `v`.
+    ///                              Each covers the positions `[l, r)`, which the path visits in
+    ///                              descending order if `reversed` and ascending order otherwise.
+    ///                              `u` から `v` への経路の順に並んだ O(log N) 個の組 `(l, r, reversed)`.
+    ///                              各組は位置 `[l, r)` を表し, 経路は `reversed` なら降順に, そうでなければ昇順に訪れる.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::{hld::HeavyLightDecomposition, tree::Tree};
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (0, 3)], 0);
+    /// let hld = HeavyLightDecomposition::new(&tree);
+    /// assert_eq!(vec![(0, 3, true), (3, 4, false)], hld.path_ranges(2, 3));
+    /// ```
+    pub fn path_ranges(&self, mut u: usize, mut v: usize) -> Vec<(usize, usize, bool)> {
+        check_vertex(self.len(), u);
+        check_vertex(self.len(), v);
+
+        let (mut up, mut down) = (Vec::new(), Vec::new());
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] >= self.depth[self.head[v]] {
+                up.push((self.pos[self.head[u]], self.pos[u] + 1, true));
+                u = self.parent[self.head[u]].unwrap();
+            } else {
+                down.push((self.pos[self.head[v]], self.pos[v] + 1, false));
+                v = self.parent[self.head[v]].unwrap();
+            }
+        }
+        if self.pos[u] >= self.pos[v] {
+            up.push((self.pos[v], self.pos[u] + 1, true));
+        } else {
+            down.push((self.pos[u], self.pos[v] + 1, false));
+        }
+        up.extend(down.into_iter().rev());
+        up
+    }
+
+    /// Builds the `forward` and `backward` segment trees from the value of each vertex.
+    /// 各頂点の値から `forward` および `backward` の segment tree を構築する.
+    ///
+    /// # Args
+    /// - `values`: The value of each vertex.
+    ///   各頂点の値.
+    ///
+    /// # Returns
+    /// `(SegmentTreeDense<M>, SegmentTreeDense<M>)`: The `forward` tree holding `values[v]` at `pos(v)`,
+    ///                                              and the `backward` tree holding it at `len() - 1 - pos(v)`.
+    ///                                              `values[v]` を `pos(v)` に保持する `forward` 木と,
+    ///                                              `len() - 1 - pos(v)` に保持する `backward` 木.
+    ///
+    /// # Panics
+    /// Panics if `values.len()` differs from the number of vertices.
+    /// `values.len()` が頂点数と異なる場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    pub fn segment_trees<M>(&self, values: &[M::S]) -> (SegmentTreeDense<M>, SegmentTreeDense<M>)
+    where
+        M: Monoid,
+        M::S: Clone,
+    {
+        if values.len() != self.len() {
+            panic!(
+                "The number of values must equal the number of vertices (values: {}, vertices: {})",
+                values.len(),
+                self.len()
+            );
+        }
+
+        let n = self.len();
+        let mut forward = SegmentTreeDense::<M>::new(n);
+        let mut backward = SegmentTreeDense::<M>::new(n);
+        for (v, x) in values.iter().enumerate() {
+            forward.set(self.pos[v], x.clone());
+            backward.set(n - 1 - self.pos[v], x.clone());
+        }
+        forward.build();
+        backward.build();
+        (forward, backward)
+    }
+
+    /// Folds the values of the vertices on the path from `u` to `v` in this order.
+    /// `u` から `v` への経路上の頂点の値をこの順に畳み込む.
+    ///
+    /// # Args
+    /// - `forward`: The tree holding the value of each vertex `w` at `pos(w)`.
+    ///   各頂点 `w` の値を `pos(w)` に保持する木.
+    /// - `backward`: The tree holding the value of each vertex `w` at `len() - 1 - pos(w)`.
+    ///   各頂点 `w` の値を `len() - 1 - pos(w)` に保持する木.
+    /// - `u`: The start of the path.
+    ///   経路の始点.
+    /// - `v`: The end of the path.
+    ///   経路の終点.
+    ///
+    /// # Returns
+    /// `M::S`: The fold `op(x_u, ..., x_v)` along the path.
+    ///         経路に沿った畳み込み `op(x_u, ..., x_v)`.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log^2 N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, graph::{hld::HeavyLightDecomposition, tree::Tree}};
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (0, 3)], 0);
+    /// let hld = HeavyLightDecomposition::new(&tree);
+    /// let (forward, backward) = hld.segment_trees::<AddMonoid>(&[1, 10, 100, 1000]);
+    /// assert_eq!(1111, hld.path_fold(&forward, &backward, 2, 3));
+    /// ```
+    pub fn path_fold<M>(
+        &self,
+        forward: &SegmentTreeDense<M>,
+        backward: &SegmentTreeDense<M>,
+        u: usize,
+        v: usize,
+    ) -> M::S
+    where
+        M: Monoid,
+        M::S: Clone,
+    {
+        let n = self.len();
+        self.path_ranges(u, v)
+            .into_iter()
+            .fold(M::id(), |acc, (l, r, reversed)| {
+                let part = if reversed {
+                    backward.fold(n - r, n - l)
+                } else {
+                    forward.fold(l, r)
+                };
+                M::op(&acc, &part)
+            })
+    }
+
+    /// Replaces the value `x` of every vertex on the path between `u` and `v` with `f(x)`.
+    /// `u` と `v` の間の経路上の全ての頂点の値 `x` を `f(x)` で置き換える.
+    ///
+    /// # Args
+    /// - `forward`: The tree holding the value of each vertex `w` at `pos(w)`.
+    ///   各頂点 `w` の値を `pos(w)` に保持する木.
+    /// - `backward`: The tree holding the value of each vertex `w` at `len() - 1 - pos(w)`.
+    ///   各頂点 `w` の値を `len() - 1 - pos(w)` に保持する木.
+    /// - `u`: One end of the path.
+    ///   経路の一端.
+    /// - `v`: The other end of the path.
+    ///   経路のもう一端.
+    /// - `f`: The function applied to each value.
+    ///   各値に適用する関数.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(L log N), where L is the number of vertices on the path and N is the number of vertices.
+    ///   ここで L は経路上の頂点数, N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid::AddMonoid, graph::{hld::HeavyLightDecomposition, tree::Tree}};
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (0, 3)], 0);
+    /// let hld = HeavyLightDecomposition::new(&tree);
+    /// let (mut forward, mut backward) = hld.segment_trees::<AddMonoid>(&[1, 10, 100, 1000]);
+    /// hld.path_update(&mut forward, &mut backward, 1, 3, |x| x * 2);
+    /// assert_eq!(2122, hld.path_fold(&forward, &backward, 2, 3));
+    /// ```
+    pub fn path_update<M, F>(
+        &self,
+        forward: &mut SegmentTreeDense<M>,
+        backward: &mut SegmentTreeDense<M>,
+        u: usize,
+        v: usize,
+        mut f: F,
+    ) where
+        M: Monoid,
+        M::S: Clone,
+        F: FnMut(&M::S) -> M::S,
+    {
+        let n = self.len();
+        for (l, r, _) in self.path_ranges(u, v) {
+            for p in l..r {
+                let x = f(&forward.get(p));
+                forward.update(p, x.clone());
+                backward.update(n - 1 - p, x);
+            }
+        }
+    }
+
+    /// Sets the value of `v` to `x`.
+    /// `v` の値を `x` にする.
+    ///
+    /// # Args
+    /// - `forward`: The tree holding the value of each vertex `w` at `pos(w)`.
+    ///   各頂点 `w` の値を `pos(w)` に保持する木.
+    /// - `backward`: The tree holding the value of each vertex `w` at `len() - 1 - pos(w)`.
+    ///   各頂点 `w` の値を `len() - 1 - pos(w)` に保持する木.
+    /// - `v`: The vertex.
+    ///   頂点.
+    /// - `x`: The new value.
+    ///   新しい値.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    pub fn update<M>(
+        &self,
+        forward: &mut SegmentTreeDense<M>,
+        backward: &mut SegmentTreeDense<M>,
+        v: usize,
+        x: M::S,
+    ) where
+        M: Monoid,
+        M::S: Clone,
+    {
+        let p = self.pos(v);
+        forward.update(p, x.clone());
+        backward.update(self.len() - 1 - p, x);
+    }
+
+    /// Folds the values of the vertices in the subtree of `v` in the decomposition order.
+    /// `v` の部分木の頂点の値を分解の順序で畳み込む.
+    ///
+    /// # Args
+    /// - `forward`: The tree holding the value of each vertex `w` at `pos(w)`.
+    ///   各頂点 `w` の値を `pos(w)` に保持する木.
+    /// - `v`: The root of the subtree.
+    ///   部分木の根.
+    ///
+    /// # Returns
+    /// `M::S`: The fold of the subtree.
+    ///         部分木の畳み込み.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    pub fn subtree_fold<M>(&self, forward: &SegmentTreeDense<M>, v: usize) -> M::S
+    where
+        M: Monoid,
+        M::S: Clone,
+    {
+        let (l, r) = self.subtree_range(v);
+        forward.fold(l, r)
+    }
+}
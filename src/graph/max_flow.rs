@@ -0,0 +1,366 @@
+//! A module that defines `MaxFlow`, which computes maximum flows by Dinic's algorithm.
+//! Dinic 法により最大流を計算する `MaxFlow` を定義するモジュールである.
+
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+
+use super::base::check_vertex;
+
+/// An edge of a flow network together with the flow on it.
+/// フローネットワークの辺と, その辺を流れる流量である.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Edge<Cap> {
+    /// The tail of the edge.
+    /// 辺の始点.
+    pub from: usize,
+
+    /// The head of the edge.
+    /// 辺の終点.
+    pub to: usize,
+
+    /// The capacity of the edge.
+    /// 辺の容量.
+    pub cap: Cap,
+
+    /// The flow on the edge.
+    /// 辺を流れる流量.
+    pub flow: Cap,
+}
+
+/// A flow network that computes maximum flows by Dinic's algorithm.
+/// `Cap::default()` is the zero capacity.
+///
+/// Dinic 法により最大流を計算するフローネットワークである.
+/// `Cap::default()` が容量 0 を表す.
+#[derive(Clone, Debug)]
+pub struct MaxFlow<Cap> {
+    n: usize,
+
+    // The `i`-th added edge is the residual edge `2 * i`, and its reverse edge is `2 * i + 1`.
+    to: Vec<usize>,
+    cap: Vec<Cap>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl<Cap> MaxFlow<Cap>
+where
+    Cap: Copy + Ord + Default + Add<Output = Cap> + Sub<Output = Cap>,
+{
+    /// Creates a flow network with `n` vertices and no edges.
+    /// `n` 頂点で辺を持たないフローネットワークを生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of vertices.
+    ///   頂点数.
+    ///
+    /// # Returns
+    /// A new `MaxFlow` instance.
+    /// 新しい `MaxFlow` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::max_flow::MaxFlow;
+    /// let mf = MaxFlow::<i64>::new(4);
+    /// assert_eq!(4, mf.num_vertices());
+    /// ```
+    pub fn new(n: usize) -> Self {
+        MaxFlow {
+            n,
+            to: Vec::new(),
+            cap: Vec::new(),
+            adj: vec![Vec::new(); n],
+        }
+    }
+
+    /// Returns the number of vertices.
+    /// 頂点数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of vertices.
+    ///          頂点数.
+    pub fn num_vertices(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of added edges.
+    /// 追加された辺の数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of edges.
+    ///          辺の数.
+    pub fn num_edges(&self) -> usize {
+        self.to.len() / 2
+    }
+
+    /// Adds an edge from `from` to `to` with capacity `cap`.
+    /// `from` から `to` への容量 `cap` の辺を追加する.
+    ///
+    /// # Args
+    /// - `from`: The tail of the edge.
+    ///   辺の始点.
+    /// - `to`: The head of the edge.
+    ///   辺の終点.
+    /// - `cap`: The capacity of the edge.
+    ///   辺の容量.
+    ///
+    /// # Returns
+    /// `usize`: The handle of the edge, which is the number of edges added before it.
+    ///          辺のハンドル. それ以前に追加された辺の数である.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` is not less than the number of vertices, or `cap` is negative.
+    /// `from` または `to` が頂点数以上の場合, または `cap` が負の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1) amortized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::max_flow::MaxFlow;
+    /// let mut mf = MaxFlow::new(2);
+    /// assert_eq!(0, mf.add_edge(0, 1, 3));
+    /// assert_eq!(1, mf.add_edge(1, 0, 2));
+    /// ```
+    pub fn add_edge(&mut self, from: usize, to: usize, cap: Cap) -> usize {
+        check_vertex(self.n, from);
+        check_vertex(self.n, to);
+        if cap < Cap::default() {
+            panic!("The capacity must be non-negative");
+        }
+
+        let handle = self.num_edges();
+        self.adj[from].push(2 * handle);
+        self.adj[to].push(2 * handle + 1);
+        self.to.extend([to, from]);
+        self.cap.extend([cap, Cap::default()]);
+        handle
+    }
+
+    /// Returns the edge with the handle `i` and the current flow on it.
+    /// ハンドル `i` の辺と, その辺を流れる現在の流量を返す.
+    ///
+    /// # Args
+    /// - `i`: The handle returned by `add_edge`.
+    ///   `add_edge` が返したハンドル.
+    ///
+    /// # Returns
+    /// `Edge<Cap>`: The edge.
+    ///              辺.
+    ///
+    /// # Panics
+    /// Panics if `i` is not less than the number of edges.
+    /// `i` が辺の数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::max_flow::{Edge, MaxFlow};
+    /// let mut mf = MaxFlow::new(3);
+    /// let e = mf.add_edge(0, 1, 3);
+    /// mf.add_edge(1, 2, 2);
+    /// mf.flow(0, 2, i64::MAX);
+    /// assert_eq!(Edge { from: 0, to: 1, cap: 3, flow: 2 }, mf.get_edge(e));
+    /// ```
+    pub fn get_edge(&self, i: usize) -> Edge<Cap> {
+        if i >= self.num_edges() {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.num_edges(),
+                i
+            );
+        }
+
+        let flow = self.cap[2 * i + 1];
+        Edge {
+            from: self.to[2 * i + 1],
+            to: self.to[2 * i],
+            cap: self.cap[2 * i] + flow,
+            flow,
+        }
+    }
+
+    /// Returns all edges in the order they were added.
+    /// 全ての辺を追加された順に返す.
+    ///
+    /// # Returns
+    /// `Vec<Edge<Cap>>`: The edges, where the `i`-th one has the handle `i`.
+    ///                   辺のリスト. `i` 番目の辺のハンドルは `i` である.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(M), where M is the number of edges.
+    ///   ここで M は辺数である.
+    pub fn edges(&self) -> Vec<Edge<Cap>> {
+        (0..self.num_edges()).map(|i| self.get_edge(i)).collect()
+    }
+
+    /// Pushes as much flow as possible from `s` to `t` without exceeding `limit`, in addition to the current flow.
+    /// 現在の流れに加えて, `limit` を超えない範囲で `s` から `t` へ可能な限り流す.
+    ///
+    /// # Args
+    /// - `s`: The source.
+    ///   始点.
+    /// - `t`: The sink.
+    ///   終点.
+    /// - `limit`: The upper bound of the amount of flow to push.
+    ///   流す量の上限.
+    ///
+    /// # Returns
+    /// `Cap`: The amount of flow pushed by this call.
+    ///        この呼び出しで流した量.
+    ///
+    /// # Panics
+    /// Panics if `s` or `t` is not less than the number of vertices, or `s == t`.
+    /// `s` または `t` が頂点数以上の場合, または `s == t` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N^2 M), where N is the number of vertices and M is the number of edges,
+    ///   and O(M min(N^(2/3), M^(1/2))) for unit capacities.
+    ///   ここで N は頂点数, M は辺数であり, 容量が全て 1 なら O(M min(N^(2/3), M^(1/2))) である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::max_flow::MaxFlow;
+    /// let mut mf = MaxFlow::new(4);
+    /// mf.add_edge(0, 1, 2);
+    /// mf.add_edge(0, 2, 1);
+    /// mf.add_edge(1, 3, 1);
+    /// mf.add_edge(2, 3, 2);
+    /// assert_eq!(1, mf.flow(0, 3, 1));
+    /// assert_eq!(1, mf.flow(0, 3, i64::MAX));
+    /// ```
+    pub fn flow(&mut self, s: usize, t: usize, limit: Cap) -> Cap {
+        check_vertex(self.n, s);
+        check_vertex(self.n, t);
+        if s == t {
+            panic!("The source and the sink must be different");
+        }
+
+        let mut total = Cap::default();
+        while total < limit {
+            let level = self.levels(s);
+            if level[t] == usize::MAX {
+                break;
+            }
+            total = total + self.blocking_flow(s, t, limit - total, level);
+        }
+        total
+    }
+
+    /// Returns the vertices reachable from `s` in the residual graph.
+    /// After a maximum flow from `s` to `t`, they form the `s` side of a minimum cut.
+    ///
+    /// 残余グラフにおいて `s` から到達可能な頂点を返す.
+    /// `s` から `t` への最大流を流した後では, これらは最小カットの `s` 側をなす.
+    ///
+    /// # Args
+    /// - `s`: The source.
+    ///   始点.
+    ///
+    /// # Returns
+    /// `Vec<bool>`: Whether each vertex is reachable from `s`.
+    ///              各頂点が `s` から到達可能かどうか.
+    ///
+    /// # Panics
+    /// Panics if `s` is not less than the number of vertices.
+    /// `s` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+    ///   ここで N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::max_flow::MaxFlow;
+    /// let mut mf = MaxFlow::new(3);
+    /// mf.add_edge(0, 1, 5);
+    /// mf.add_edge(1, 2, 1);
+    /// mf.flow(0, 2, i64::MAX);
+    /// assert_eq!(vec![true, true, false], mf.min_cut(0));
+    /// ```
+    pub fn min_cut(&self, s: usize) -> Vec<bool> {
+        check_vertex(self.n, s);
+        self.levels(s)
+            .into_iter()
+            .map(|l| l != usize::MAX)
+            .collect()
+    }
+
+    // Returns the distances from `s` in the residual graph, or `usize::MAX` for unreachable vertices.
+    fn levels(&self, s: usize) -> Vec<usize> {
+        let mut level = vec![usize::MAX; self.n];
+        let mut queue = VecDeque::from([s]);
+        level[s] = 0;
+        while let Some(v) = queue.pop_front() {
+            for &e in self.adj[v].iter() {
+                let to = self.to[e];
+                if self.cap[e] > Cap::default() && level[to] == usize::MAX {
+                    level[to] = level[v] + 1;
+                    queue.push_back(to);
+                }
+            }
+        }
+        level
+    }
+
+    // Pushes a blocking flow of at most `limit` along the level graph, walking augmenting paths with an explicit stack.
+    fn blocking_flow(&mut self, s: usize, t: usize, limit: Cap, mut level: Vec<usize>) -> Cap {
+        let mut next = vec![0; self.n];
+        let mut path: Vec<usize> = Vec::new();
+        let mut total = Cap::default();
+        let mut v = s;
+        loop {
+            if v == t {
+                let pushed = path
+                    .iter()
+                    .map(|&e| self.cap[e])
+                    .fold(limit - total, |a, b| a.min(b));
+                for &e in path.iter() {
+                    self.cap[e] = self.cap[e] - pushed;
+                    self.cap[e ^ 1] = self.cap[e ^ 1] + pushed;
+                }
+                total = total + pushed;
+                if total == limit {
+                    return total;
+                }
+
+                // Resume from the tail of the first saturated edge.
+                let k = path
+                    .iter()
+                    .position(|&e| self.cap[e] == Cap::default())
+                    .unwrap();
+                path.truncate(k);
+                v = path.last().map_or(s, |&e| self.to[e]);
+                continue;
+            }
+
+            let mut advanced = false;
+            while let Some(&e) = self.adj[v].get(next[v]) {
+                let to = self.to[e];
+                if self.cap[e] > Cap::default() && level[to] == level[v] + 1 {
+                    path.push(e);
+                    v = to;
+                    advanced = true;
+                    break;
+                }
+                next[v] += 1;
+            }
+            if advanced {
+                continue;
+            }
+
+            // `v` cannot reach `t` anymore, so it is removed from the level graph.
+            level[v] = usize::MAX;
+            let Some(e) = path.pop() else {
+                return total;
+            };
+            v = self.to[e ^ 1];
+            next[v] += 1;
+        }
+    }
+}
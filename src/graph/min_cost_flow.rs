@@ -0,0 +1,376 @@
+//! A module that defines `MinCostFlow`, which computes minimum cost flows by the primal-dual method.
+//! 主双対法により最小費用流を計算する `MinCostFlow` を定義するモジュールである.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::base::check_vertex;
+
+/// An edge of a flow network with a cost per unit of flow, together with the flow on it.
+/// 単位流量あたりの費用を持つフローネットワークの辺と, その辺を流れる流量である.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Edge {
+    /// The tail of the edge.
+    /// 辺の始点.
+    pub from: usize,
+
+    /// The head of the edge.
+    /// 辺の終点.
+    pub to: usize,
+
+    /// The capacity of the edge.
+    /// 辺の容量.
+    pub cap: i64,
+
+    /// The flow on the edge.
+    /// 辺を流れる流量.
+    pub flow: i64,
+
+    /// The cost per unit of flow on the edge.
+    /// 辺の単位流量あたりの費用.
+    pub cost: i64,
+}
+
+/// A flow network that computes minimum cost flows by the primal-dual method,
+/// which repeats Dijkstra's algorithm on the costs reduced by vertex potentials.
+///
+/// 主双対法により最小費用流を計算するフローネットワークである.
+/// 頂点のポテンシャルで調整した費用の上で Dijkstra 法を繰り返す.
+#[derive(Clone, Debug)]
+pub struct MinCostFlow {
+    n: usize,
+
+    // The `i`-th added edge is the residual edge `2 * i`, and its reverse edge is `2 * i + 1`.
+    to: Vec<usize>,
+    cap: Vec<i64>,
+    cost: Vec<i64>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl MinCostFlow {
+    /// Creates a flow network with `n` vertices and no edges.
+    /// `n` 頂点で辺を持たないフローネットワークを生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of vertices.
+    ///   頂点数.
+    ///
+    /// # Returns
+    /// A new `MinCostFlow` instance.
+    /// 新しい `MinCostFlow` インスタンスを返す.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::min_cost_flow::MinCostFlow;
+    /// let mcf = MinCostFlow::new(4);
+    /// assert_eq!(4, mcf.num_vertices());
+    /// ```
+    pub fn new(n: usize) -> Self {
+        MinCostFlow {
+            n,
+            to: Vec::new(),
+            cap: Vec::new(),
+            cost: Vec::new(),
+            adj: vec![Vec::new(); n],
+        }
+    }
+
+    /// Returns the number of vertices.
+    /// 頂点数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of vertices.
+    ///          頂点数.
+    pub fn num_vertices(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of added edges.
+    /// 追加された辺の数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of edges.
+    ///          辺の数.
+    pub fn num_edges(&self) -> usize {
+        self.to.len() / 2
+    }
+
+    /// Adds an edge from `from` to `to` with capacity `cap` and cost `cost` per unit of flow.
+    /// `from` から `to` への容量 `cap`, 単位流量あたりの費用 `cost` の辺を追加する.
+    ///
+    /// # Args
+    /// - `from`: The tail of the edge.
+    ///   辺の始点.
+    /// - `to`: The head of the edge.
+    ///   辺の終点.
+    /// - `cap`: The capacity of the edge.
+    ///   辺の容量.
+    /// - `cost`: The cost per unit of flow.
+    ///   単位流量あたりの費用.
+    ///
+    /// # Returns
+    /// `usize`: The handle of the edge, which is the number of edges added before it.
+    ///          辺のハンドル. それ以前に追加された辺の数である.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` is not less than the number of vertices, or `cap` or `cost` is negative.
+    /// `from` または `to` が頂点数以上の場合, または `cap` か `cost` が負の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1) amortized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::min_cost_flow::MinCostFlow;
+    /// let mut mcf = MinCostFlow::new(2);
+    /// assert_eq!(0, mcf.add_edge(0, 1, 3, 5));
+    /// ```
+    pub fn add_edge(&mut self, from: usize, to: usize, cap: i64, cost: i64) -> usize {
+        check_vertex(self.n, from);
+        check_vertex(self.n, to);
+        if cap < 0 {
+            panic!("The capacity must be non-negative");
+        }
+        if cost < 0 {
+            panic!("The cost must be non-negative");
+        }
+
+        let handle = self.num_edges();
+        self.adj[from].push(2 * handle);
+        self.adj[to].push(2 * handle + 1);
+        self.to.extend([to, from]);
+        self.cap.extend([cap, 0]);
+        self.cost.extend([cost, -cost]);
+        handle
+    }
+
+    /// Returns the edge with the handle `i` and the current flow on it.
+    /// ハンドル `i` の辺と, その辺を流れる現在の流量を返す.
+    ///
+    /// # Args
+    /// - `i`: The handle returned by `add_edge`.
+    ///   `add_edge` が返したハンドル.
+    ///
+    /// # Returns
+    /// `Edge`: The edge.
+    ///         辺.
+    ///
+    /// # Panics
+    /// Panics if `i` is not less than the number of edges.
+    /// `i` が辺の数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::min_cost_flow::{Edge, MinCostFlow};
+    /// let mut mcf = MinCostFlow::new(2);
+    /// let e = mcf.add_edge(0, 1, 3, 5);
+    /// mcf.flow(0, 1, 2);
+    /// assert_eq!(Edge { from: 0, to: 1, cap: 3, flow: 2, cost: 5 }, mcf.get_edge(e));
+    /// ```
+    pub fn get_edge(&self, i: usize) -> Edge {
+        if i >= self.num_edges() {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.num_edges(),
+                i
+            );
+        }
+
+        let flow = self.cap[2 * i + 1];
+        Edge {
+            from: self.to[2 * i + 1],
+            to: self.to[2 * i],
+            cap: self.cap[2 * i] + flow,
+            flow,
+            cost: self.cost[2 * i],
+        }
+    }
+
+    /// Returns all edges in the order they were added.
+    /// 全ての辺を追加された順に返す.
+    ///
+    /// # Returns
+    /// `Vec<Edge>`: The edges, where the `i`-th one has the handle `i`.
+    ///              辺のリスト. `i` 番目の辺のハンドルは `i` である.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(M), where M is the number of edges.
+    ///   ここで M は辺数である.
+    pub fn edges(&self) -> Vec<Edge> {
+        (0..self.num_edges()).map(|i| self.get_edge(i)).collect()
+    }
+
+    /// Pushes as much flow as possible from `s` to `t` without exceeding `limit` at the minimum cost.
+    /// 最小の費用で, `limit` を超えない範囲で `s` から `t` へ可能な限り流す.
+    ///
+    /// # Args
+    /// - `s`: The source.
+    ///   始点.
+    /// - `t`: The sink.
+    ///   終点.
+    /// - `limit`: The upper bound of the amount of flow to push.
+    ///   流す量の上限.
+    ///
+    /// # Returns
+    /// `(i64, i64)`: The amount of flow and its total cost.
+    ///               流量とその費用の合計.
+    ///
+    /// # Constraints
+    /// - The network has no flow yet, i.e. `flow` and `slope` are called at most once in total.
+    ///   ネットワークにまだ流れがない, すなわち `flow` と `slope` は合わせて高々一度しか呼ばれない.
+    ///
+    /// # Panics
+    /// Panics if `s` or `t` is not less than the number of vertices, or `s == t`.
+    /// `s` または `t` が頂点数以上の場合, または `s == t` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(F (N + M) log M), where F is the amount of flow,
+    ///   N is the number of vertices and M is the number of edges.
+    ///   ここで F は流量, N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::min_cost_flow::MinCostFlow;
+    /// let mut mcf = MinCostFlow::new(3);
+    /// mcf.add_edge(0, 1, 2, 1);
+    /// mcf.add_edge(1, 2, 2, 1);
+    /// mcf.add_edge(0, 2, 1, 5);
+    /// assert_eq!((3, 9), mcf.flow(0, 2, i64::MAX));
+    /// ```
+    pub fn flow(&mut self, s: usize, t: usize, limit: i64) -> (i64, i64) {
+        *self.slope(s, t, limit).last().unwrap()
+    }
+
+    /// Computes the minimum cost as a function of the amount of flow from `s` to `t` up to `limit`.
+    /// The function is convex and piecewise linear, and is returned as its breakpoints.
+    /// The network holds the flow at the last breakpoint afterwards.
+    ///
+    /// `s` から `t` への流量 (`limit` 以下) の関数として最小費用を計算する.
+    /// この関数は下に凸な区分線形関数であり, その折れ点として返される.
+    /// 呼び出し後, ネットワークは最後の折れ点の流れを保持する.
+    ///
+    /// # Args
+    /// - `s`: The source.
+    ///   始点.
+    /// - `t`: The sink.
+    ///   終点.
+    /// - `limit`: The upper bound of the amount of flow to push.
+    ///   流す量の上限.
+    ///
+    /// # Returns
+    /// `Vec<(i64, i64)>`: The breakpoints `(flow, cost)` with strictly increasing flows and slopes,
+    ///                    starting with `(0, 0)` and ending with the maximum flow within `limit`.
+    ///                    流量と傾きが狭義単調増加する折れ点 `(流量, 費用)` のリスト.
+    ///                    `(0, 0)` から始まり, `limit` 以内の最大流で終わる.
+    ///
+    /// # Constraints
+    /// - The network has no flow yet, i.e. `flow` and `slope` are called at most once in total.
+    ///   ネットワークにまだ流れがない, すなわち `flow` と `slope` は合わせて高々一度しか呼ばれない.
+    ///
+    /// # Panics
+    /// Panics if `s` or `t` is not less than the number of vertices, or `s == t`.
+    /// `s` または `t` が頂点数以上の場合, または `s == t` の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(F (N + M) log M), where F is the amount of flow,
+    ///   N is the number of vertices and M is the number of edges.
+    ///   ここで F は流量, N は頂点数, M は辺数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::min_cost_flow::MinCostFlow;
+    /// let mut mcf = MinCostFlow::new(3);
+    /// mcf.add_edge(0, 1, 2, 1);
+    /// mcf.add_edge(1, 2, 2, 1);
+    /// mcf.add_edge(0, 2, 1, 5);
+    /// assert_eq!(vec![(0, 0), (2, 4), (3, 9)], mcf.slope(0, 2, i64::MAX));
+    /// ```
+    pub fn slope(&mut self, s: usize, t: usize, limit: i64) -> Vec<(i64, i64)> {
+        check_vertex(self.n, s);
+        check_vertex(self.n, t);
+        if s == t {
+            panic!("The source and the sink must be different");
+        }
+
+        // The reduced cost `cost + potential[from] - potential[to]` of every residual edge stays non-negative.
+        let mut potential = vec![0; self.n];
+        let mut result = vec![(0, 0)];
+        let (mut flow, mut cost) = (0, 0);
+        let mut last_slope = None;
+        while flow < limit {
+            let Some(prev) = self.dual(s, t, &mut potential) else {
+                break;
+            };
+
+            let mut pushed = limit - flow;
+            let mut v = t;
+            while v != s {
+                let e = prev[v];
+                pushed = pushed.min(self.cap[e]);
+                v = self.to[e ^ 1];
+            }
+            let mut v = t;
+            while v != s {
+                let e = prev[v];
+                self.cap[e] -= pushed;
+                self.cap[e ^ 1] += pushed;
+                v = self.to[e ^ 1];
+            }
+
+            // Consecutive augmentations with the same unit cost lie on one linear piece.
+            let unit = potential[t] - potential[s];
+            flow += pushed;
+            cost += pushed * unit;
+            if last_slope == Some(unit) {
+                result.pop();
+            }
+            result.push((flow, cost));
+            last_slope = Some(unit);
+        }
+        result
+    }
+
+    // Runs Dijkstra's algorithm on the reduced costs, adds the distances to `potential`,
+    // and returns the edge entering each vertex on a shortest path tree, or `None` if `t` is unreachable.
+    fn dual(&self, s: usize, t: usize, potential: &mut [i64]) -> Option<Vec<usize>> {
+        let mut dist = vec![i64::MAX; self.n];
+        let mut prev = vec![usize::MAX; self.n];
+        let mut heap = BinaryHeap::from([Reverse((0, s))]);
+        dist[s] = 0;
+        while let Some(Reverse((d, v))) = heap.pop() {
+            if d > dist[v] {
+                continue;
+            }
+            for &e in self.adj[v].iter() {
+                let to = self.to[e];
+                if self.cap[e] == 0 {
+                    continue;
+                }
+                let nd = d + self.cost[e] + potential[v] - potential[to];
+                if nd < dist[to] {
+                    dist[to] = nd;
+                    prev[to] = e;
+                    heap.push(Reverse((nd, to)));
+                }
+            }
+        }
+        if dist[t] == i64::MAX {
+            return None;
+        }
+
+        // Vertices unreachable from `s` never become reachable again, so their potentials are irrelevant.
+        for (p, d) in potential.iter_mut().zip(dist) {
+            if d != i64::MAX {
+                *p += d;
+            }
+        }
+        Some(prev)
+    }
+}
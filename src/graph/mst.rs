@@ -0,0 +1,308 @@
+//! Minimum spanning trees and forests by Kruskal's algorithm on `ds::union_find::UnionFind`.
+//! `ds::union_find::UnionFind` を用いた Kruskal 法による最小全域木および最小全域森である.
+//!
+//! Every function takes the number of vertices `n` and undirected edges `(u, v, weight)`,
+//! such as `Graph::edges`, and refers to the chosen edges by their indices in `edges`.
+//! Edges of equal weight are considered in the order of their indices.
+//! 全ての関数は頂点数 `n` と, `Graph::edges` のような無向辺 `(u, v, weight)` のリストを受け取り,
+//! 選ばれた辺を `edges` におけるインデックスで表す. 重みの等しい辺はインデックスの順に考慮される.
+
+use std::ops::{Add, Sub};
+
+use super::base::check_vertex;
+use crate::ds::union_find::UnionFind;
+
+// Returns the edge indices sorted by weight, keeping the index order among equal weights.
+fn sorted_indices<W>(n: usize, edges: &[(usize, usize, W)]) -> Vec<usize>
+where
+    W: Copy + Ord,
+{
+    for &(u, v, _) in edges.iter() {
+        check_vertex(n, u);
+        check_vertex(n, v);
+    }
+
+    let mut order = (0..edges.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| edges[i].2);
+    order
+}
+
+// Adds edges in `order` that connect different components of `uf` to `chosen`.
+fn kruskal<W>(
+    uf: &mut UnionFind,
+    edges: &[(usize, usize, W)],
+    order: &[usize],
+    total: &mut W,
+    chosen: &mut Vec<usize>,
+) where
+    W: Copy + Add<Output = W>,
+{
+    for &i in order.iter() {
+        let (u, v, w) = edges[i];
+        if !uf.is_same(u, v) {
+            uf.union(u, v);
+            *total = *total + w;
+            chosen.push(i);
+        }
+    }
+}
+
+/// Computes a minimum spanning forest, which is a minimum spanning tree of each component.
+/// 最小全域森, すなわち各連結成分の最小全域木を計算する.
+///
+/// # Args
+/// - `n`: The number of vertices.
+///   頂点数.
+/// - `edges`: The undirected edges `(u, v, weight)`.
+///   無向辺 `(u, v, weight)` のリスト.
+///
+/// # Returns
+/// `(W, Vec<usize>)`: The total weight and the indices of the chosen edges in the order chosen.
+///                    重みの合計と, 選ばれた順に並んだ辺のインデックス.
+///
+/// # Panics
+/// Panics if an endpoint of an edge is not less than `n`.
+/// 辺の端点が `n` 以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(M log M + M α(N)), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::mst::minimum_spanning_forest;
+/// let edges = vec![(0, 1, 4), (2, 3, 1), (0, 1, 2)];
+/// assert_eq!((3, vec![1, 2]), minimum_spanning_forest(4, &edges));
+/// ```
+pub fn minimum_spanning_forest<W>(n: usize, edges: &[(usize, usize, W)]) -> (W, Vec<usize>)
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let order = sorted_indices(n, edges);
+    let mut uf = UnionFind::new(n);
+    let (mut total, mut chosen) = (W::default(), Vec::new());
+    kruskal(&mut uf, edges, &order, &mut total, &mut chosen);
+    (total, chosen)
+}
+
+/// Computes a minimum spanning tree by Kruskal's algorithm.
+/// Kruskal 法により最小全域木を計算する.
+///
+/// # Args
+/// - `n`: The number of vertices.
+///   頂点数.
+/// - `edges`: The undirected edges `(u, v, weight)`.
+///   無向辺 `(u, v, weight)` のリスト.
+///
+/// # Returns
+/// `Option<(W, Vec<usize>)>`: The total weight and the indices of the chosen edges in the order chosen,
+///                            or `None` if the graph is disconnected.
+///                            重みの合計と選ばれた順に並んだ辺のインデックス. グラフが非連結なら `None`.
+///
+/// # Panics
+/// Panics if an endpoint of an edge is not less than `n`.
+/// 辺の端点が `n` 以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(M log M + M α(N)), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{mst::kruskal_mst, Graph};
+/// let g = Graph::undirected(3, vec![(0, 1, 5), (1, 2, 3), (0, 2, 1)]);
+/// assert_eq!(Some((4, vec![2, 1])), kruskal_mst(g.num_vertices(), g.edges()));
+/// assert_eq!(None, kruskal_mst(4, g.edges()));
+/// ```
+pub fn kruskal_mst<W>(n: usize, edges: &[(usize, usize, W)]) -> Option<(W, Vec<usize>)>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let (total, chosen) = minimum_spanning_forest(n, edges);
+    if chosen.len() + 1 < n {
+        return None;
+    }
+    Some((total, chosen))
+}
+
+/// Computes a minimum spanning tree that contains all of the `forced` edges.
+/// `forced` の辺を全て含む最小全域木を計算する.
+///
+/// # Args
+/// - `n`: The number of vertices.
+///   頂点数.
+/// - `edges`: The undirected edges `(u, v, weight)`.
+///   無向辺 `(u, v, weight)` のリスト.
+/// - `forced`: The indices of the edges that must be in the tree.
+///   木に含まれなければならない辺のインデックス.
+///
+/// # Returns
+/// `Option<(W, Vec<usize>)>`: The total weight and the chosen edge indices, starting with `forced`,
+///                            or `None` if the forced edges contain a cycle or the graph is disconnected.
+///                            重みの合計と `forced` から始まる選ばれた辺のインデックス.
+///                            強制された辺が閉路を含むか, グラフが非連結なら `None`.
+///
+/// # Panics
+/// Panics if an endpoint of an edge is not less than `n`, or an index in `forced` is out of bounds.
+/// 辺の端点が `n` 以上の場合, または `forced` のインデックスが範囲外の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(M log M + M α(N)), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::mst::mst_with_forced_edges;
+/// let edges = vec![(0, 1, 5), (1, 2, 3), (0, 2, 1)];
+/// assert_eq!(Some((6, vec![0, 2])), mst_with_forced_edges(3, &edges, &[0]));
+/// ```
+pub fn mst_with_forced_edges<W>(
+    n: usize,
+    edges: &[(usize, usize, W)],
+    forced: &[usize],
+) -> Option<(W, Vec<usize>)>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let order = sorted_indices(n, edges);
+    let mut uf = UnionFind::new(n);
+    let (mut total, mut chosen) = (W::default(), Vec::new());
+    for &i in forced.iter() {
+        if i >= edges.len() {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                edges.len(),
+                i
+            );
+        }
+        let (u, v, w) = edges[i];
+        if uf.is_same(u, v) {
+            return None;
+        }
+        uf.union(u, v);
+        total = total + w;
+        chosen.push(i);
+    }
+
+    kruskal(&mut uf, edges, &order, &mut total, &mut chosen);
+    if chosen.len() + 1 < n {
+        return None;
+    }
+    Some((total, chosen))
+}
+
+/// Computes the weight of a second-best minimum spanning tree, which is the minimum weight of a
+/// spanning tree whose edge set differs from the one chosen by `kruskal_mst`.
+/// 次善の最小全域木の重み, すなわち `kruskal_mst` が選ぶ木と辺集合が異なる全域木の最小の重みを計算する.
+///
+/// # Args
+/// - `n`: The number of vertices.
+///   頂点数.
+/// - `edges`: The undirected edges `(u, v, weight)`.
+///   無向辺 `(u, v, weight)` のリスト.
+///
+/// # Returns
+/// `Option<W>`: The weight, which may equal that of the minimum spanning tree, or `None` if the
+///              graph is disconnected or has no other spanning tree.
+///              重み. 最小全域木の重みと等しいこともある. グラフが非連結か, 他の全域木がなければ `None`.
+///
+/// # Panics
+/// Panics if an endpoint of an edge is not less than `n`.
+/// 辺の端点が `n` 以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(M log M + (N + M) log N), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::mst::second_best_mst;
+/// let edges = vec![(0, 1, 1), (1, 2, 2), (0, 2, 4)];
+/// // Replacing (1, 2) with (0, 2) gives 1 + 4.
+/// assert_eq!(Some(5), second_best_mst(3, &edges));
+/// assert_eq!(None, second_best_mst(2, &[(0, 1, 1)]));
+/// ```
+pub fn second_best_mst<W>(n: usize, edges: &[(usize, usize, W)]) -> Option<W>
+where
+    W: Copy + Ord + Default + Add<Output = W> + Sub<Output = W>,
+{
+    let (total, chosen) = kruskal_mst(n, edges)?;
+
+    let mut in_tree = vec![false; edges.len()];
+    for &i in chosen.iter() {
+        in_tree[i] = true;
+    }
+    // Without a non-tree edge other than a self-loop, no other spanning tree exists.
+    // This also covers the empty graph, for which there is no root to build the tables from.
+    if (0..edges.len()).all(|i| in_tree[i] || edges[i].0 == edges[i].1) {
+        return None;
+    }
+
+    let mut adj = vec![Vec::new(); n];
+    for &i in chosen.iter() {
+        let (u, v, w) = edges[i];
+        adj[u].push((v, w));
+        adj[v].push((u, w));
+    }
+
+    // Root the tree at 0 and build binary lifting tables of ancestors and maximum edge weights.
+    let log = (usize::BITS - n.leading_zeros()).max(1) as usize;
+    let mut depth = vec![0; n];
+    let mut up = vec![vec![0; n]; log];
+    let mut max_w: Vec<Vec<Option<W>>> = vec![vec![None; n]; log];
+    let mut visited = vec![false; n];
+    let mut stack = vec![0];
+    visited[0] = true;
+    while let Some(v) = stack.pop() {
+        for &(u, w) in adj[v].iter() {
+            if !visited[u] {
+                visited[u] = true;
+                depth[u] = depth[v] + 1;
+                up[0][u] = v;
+                max_w[0][u] = Some(w);
+                stack.push(u);
+            }
+        }
+    }
+    for k in 1..log {
+        for v in 0..n {
+            let mid = up[k - 1][v];
+            up[k][v] = up[k - 1][mid];
+            max_w[k][v] = max_w[k - 1][v].max(max_w[k - 1][mid]);
+        }
+    }
+
+    // Returns the maximum weight on the tree path between `u` and `v`.
+    let path_max = |mut u: usize, mut v: usize| -> Option<W> {
+        let mut res = None;
+        if depth[u] < depth[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        for k in (0..log).rev() {
+            if depth[u] - depth[v] >= 1 << k {
+                res = res.max(max_w[k][u]);
+                u = up[k][u];
+            }
+        }
+        if u == v {
+            return res;
+        }
+        for k in (0..log).rev() {
+            if up[k][u] != up[k][v] {
+                res = res.max(max_w[k][u]).max(max_w[k][v]);
+                u = up[k][u];
+                v = up[k][v];
+            }
+        }
+        res.max(max_w[0][u]).max(max_w[0][v])
+    };
+
+    // Swapping a non-tree edge for the heaviest edge on the cycle it closes gives the candidates.
+    (0..edges.len())
+        .filter(|&i| !in_tree[i] && edges[i].0 != edges[i].1)
+        .map(|i| {
+            let (u, v, w) = edges[i];
+            total - path_max(u, v).unwrap() + w
+        })
+        .min()
+}
@@ -0,0 +1,560 @@
+//! Single-source and all-pairs shortest paths on `Graph`, generic over `algebra::path_monoid::PathMonoid`.
+//! `Graph` 上の単一始点および全点対最短路であり, `algebra::path_monoid::PathMonoid` について汎用である.
+//!
+//! The edge weights of the graph are path values of the monoid, such as `i64` for `MinPlusMonoid`.
+//! A path is better than another when `PathMonoid::compare` says so, and equally good paths are
+//! merged by `PathMonoid::merge` in Dijkstra's algorithm and the Floyd-Warshall algorithm.
+//! A negative cycle is a cycle whose value is better than the empty path.
+//!
+//! グラフの辺の重みは, `MinPlusMonoid` に対する `i64` のようなモノイドの経路の値である.
+//! 経路の優劣は `PathMonoid::compare` で決まり, 同等に良い経路は Dijkstra 法および
+//! Floyd-Warshall 法において `PathMonoid::merge` で併合される.
+//! 負閉路とは, その値が空の経路より良い閉路である.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::base::{Graph, check_vertex};
+use crate::algebra::path_monoid::PathMonoid;
+
+/// The result of a single-source shortest path search.
+/// 単一始点最短路の探索結果である.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPaths<S> {
+    /// The value of a best path to each vertex, or `None` for unreachable vertices.
+    /// 各頂点への最良の経路の値. 到達不能な頂点は `None`.
+    pub dist: Vec<Option<S>>,
+
+    /// The previous vertex on a best path to each vertex, or `None` for the source and unreachable vertices.
+    /// 各頂点への最良の経路における直前の頂点. 始点と到達不能な頂点は `None`.
+    pub prev: Vec<Option<usize>>,
+}
+
+impl<S> ShortestPaths<S> {
+    /// Reconstructs a best path to `t`.
+    /// `t` への最良の経路を復元する.
+    ///
+    /// # Args
+    /// - `t`: The destination vertex.
+    ///   終点.
+    ///
+    /// # Returns
+    /// `Option<Vec<usize>>`: The vertices on the path from the source to `t`, or `None` if `t` is unreachable.
+    ///                       始点から `t` までの経路上の頂点. `t` が到達不能なら `None`.
+    ///
+    /// # Panics
+    /// Panics if `t` is not less than the number of vertices.
+    /// `t` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(L), where L is the length of the path.
+    ///   ここで L は経路の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::algebra::path_monoid::MinPlusMonoid;
+    /// use anmitsu::graph::{shortest_path::dijkstra, Graph};
+    /// let g = Graph::directed(4, vec![(0, 1, 1), (1, 2, 1), (0, 2, 5)]);
+    /// let paths = dijkstra::<MinPlusMonoid>(&g, 0);
+    /// assert_eq!(Some(vec![0, 1, 2]), paths.path(2));
+    /// assert_eq!(None, paths.path(3));
+    /// ```
+    pub fn path(&self, t: usize) -> Option<Vec<usize>> {
+        check_vertex(self.dist.len(), t);
+        self.dist[t].as_ref()?;
+
+        let mut path = vec![t];
+        let mut v = t;
+        while let Some(p) = self.prev[v] {
+            path.push(p);
+            v = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The result of an all-pairs shortest path search.
+/// 全点対最短路の探索結果である.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllPairsShortestPaths<S> {
+    /// `dist[s][t]` is the value of a best path from `s` to `t`, or `None` if `t` is unreachable from `s`.
+    /// `dist[s][t]` は `s` から `t` への最良の経路の値. `t` が `s` から到達不能なら `None`.
+    pub dist: Vec<Vec<Option<S>>>,
+
+    /// `prev[s][t]` is the previous vertex of `t` on a best path from `s` to `t`, or `None` if `s == t` or `t` is unreachable.
+    /// `prev[s][t]` は `s` から `t` への最良の経路における `t` の直前の頂点. `s == t` または到達不能なら `None`.
+    pub prev: Vec<Vec<Option<usize>>>,
+}
+
+impl<S> AllPairsShortestPaths<S> {
+    /// Reconstructs a best path from `s` to `t`.
+    /// `s` から `t` への最良の経路を復元する.
+    ///
+    /// # Args
+    /// - `s`: The source vertex.
+    ///   始点.
+    /// - `t`: The destination vertex.
+    ///   終点.
+    ///
+    /// # Returns
+    /// `Option<Vec<usize>>`: The vertices on the path from `s` to `t`, or `None` if `t` is unreachable from `s`.
+    ///                       `s` から `t` までの経路上の頂点. `t` が `s` から到達不能なら `None`.
+    ///
+    /// # Panics
+    /// Panics if `s` or `t` is not less than the number of vertices.
+    /// `s` または `t` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(L), where L is the length of the path.
+    ///   ここで L は経路の長さである.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::algebra::path_monoid::MinPlusMonoid;
+    /// use anmitsu::graph::{shortest_path::floyd_warshall, Graph};
+    /// let g = Graph::undirected(3, vec![(0, 1, 1), (1, 2, 1), (0, 2, 5)]);
+    /// let paths = floyd_warshall::<MinPlusMonoid>(&g).unwrap();
+    /// assert_eq!(Some(vec![2, 1, 0]), paths.path(2, 0));
+    /// ```
+    pub fn path(&self, s: usize, t: usize) -> Option<Vec<usize>> {
+        check_vertex(self.dist.len(), s);
+        check_vertex(self.dist.len(), t);
+        self.dist[s][t].as_ref()?;
+
+        let mut path = vec![t];
+        let mut v = t;
+        while let Some(p) = self.prev[s][v] {
+            path.push(p);
+            v = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// A heap entry ordered so that the best value is popped first.
+struct HeapEntry<M: PathMonoid>(M::S, usize);
+
+impl<M: PathMonoid> PartialEq for HeapEntry<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<M: PathMonoid> Eq for HeapEntry<M> {}
+
+impl<M: PathMonoid> PartialOrd for HeapEntry<M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: PathMonoid> Ord for HeapEntry<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        M::compare(&other.0, &self.0)
+    }
+}
+
+// Offers `value` as a path to `to` through `from`, and returns whether it is strictly better.
+// An equally good value is merged into `dist[to]` if `merge` is true.
+fn relax<M>(
+    dist: &mut [Option<M::S>],
+    prev: &mut [Option<usize>],
+    from: usize,
+    to: usize,
+    value: M::S,
+    merge: bool,
+) -> bool
+where
+    M: PathMonoid,
+    M::S: Clone,
+{
+    let order = match &dist[to] {
+        None => Ordering::Less,
+        Some(old) => M::compare(&value, old),
+    };
+    match order {
+        Ordering::Less => {
+            dist[to] = Some(value);
+            prev[to] = Some(from);
+            true
+        }
+        Ordering::Equal if merge => {
+            dist[to] = dist[to].as_ref().map(|old| M::merge(old, &value));
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Computes best paths from `s` by Dijkstra's algorithm with a binary heap.
+/// 二分ヒープを用いた Dijkstra 法により `s` からの最良の経路を計算する.
+///
+/// # Args
+/// - `g`: The graph whose weights are path values of `M`.
+///   重みが `M` の経路の値であるグラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `ShortestPaths<M::S>`: The values of best paths and the previous vertices on them.
+///                        最良の経路の値と, その経路における直前の頂点.
+///
+/// # Constraints
+/// - No edge makes a path better, i.e. `compare(op(a, w), a)` is never `Less`.
+///   どの辺も経路を良くしない, すなわち `compare(op(a, w), a)` は `Less` にならない.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices.
+/// `s` が頂点数以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O((N + M) log M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::algebra::path_monoid::{MaxMinMonoid, MinPlusMonoid};
+/// use anmitsu::graph::{shortest_path::dijkstra, Graph};
+/// let g = Graph::directed(3, vec![(0, 1, 4), (1, 2, 3), (0, 2, 9)]);
+/// assert_eq!(vec![Some(0), Some(4), Some(7)], dijkstra::<MinPlusMonoid>(&g, 0).dist);
+/// assert_eq!(Some(9), dijkstra::<MaxMinMonoid>(&g, 0).dist[2]);
+/// ```
+pub fn dijkstra<M>(g: &Graph<M::S>, s: usize) -> ShortestPaths<M::S>
+where
+    M: PathMonoid,
+    M::S: Clone,
+{
+    check_vertex(g.num_vertices(), s);
+
+    let n = g.num_vertices();
+    let mut dist = vec![None; n];
+    let mut prev = vec![None; n];
+    let mut done = vec![false; n];
+    let mut heap = BinaryHeap::from([HeapEntry::<M>(M::id(), s)]);
+    dist[s] = Some(M::id());
+    while let Some(HeapEntry(_, v)) = heap.pop() {
+        if done[v] {
+            continue;
+        }
+        done[v] = true;
+
+        // The entry may hold a stale value, so the current value is read from `dist`.
+        let d = dist[v].clone().unwrap();
+        for &(to, e) in g.out_edges(v) {
+            if done[to] {
+                continue;
+            }
+            let value = M::op(&d, &g.edges()[e].2);
+            if relax::<M>(&mut dist, &mut prev, v, to, value.clone(), true) {
+                heap.push(HeapEntry(value, to));
+            }
+        }
+    }
+    ShortestPaths { dist, prev }
+}
+
+/// Computes best paths from `s` by Dijkstra's algorithm without a heap, which suits dense graphs.
+/// ヒープを用いない Dijkstra 法により `s` からの最良の経路を計算する. 密なグラフに適する.
+///
+/// # Args
+/// - `g`: The graph whose weights are path values of `M`.
+///   重みが `M` の経路の値であるグラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `ShortestPaths<M::S>`: The values of best paths and the previous vertices on them.
+///                        最良の経路の値と, その経路における直前の頂点.
+///
+/// # Constraints
+/// - No edge makes a path better, i.e. `compare(op(a, w), a)` is never `Less`.
+///   どの辺も経路を良くしない, すなわち `compare(op(a, w), a)` は `Less` にならない.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices.
+/// `s` が頂点数以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N^2 + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::algebra::path_monoid::MinPlusMonoid;
+/// use anmitsu::graph::{shortest_path::dijkstra_dense, Graph};
+/// let g = Graph::undirected(3, vec![(0, 1, 4), (1, 2, 3), (0, 2, 9)]);
+/// assert_eq!(vec![Some(7), Some(3), Some(0)], dijkstra_dense::<MinPlusMonoid>(&g, 2).dist);
+/// ```
+pub fn dijkstra_dense<M>(g: &Graph<M::S>, s: usize) -> ShortestPaths<M::S>
+where
+    M: PathMonoid,
+    M::S: Clone,
+{
+    check_vertex(g.num_vertices(), s);
+
+    let n = g.num_vertices();
+    let mut dist = vec![None; n];
+    let mut prev = vec![None; n];
+    let mut done = vec![false; n];
+    dist[s] = Some(M::id());
+    loop {
+        let mut best: Option<usize> = None;
+        for v in (0..n).filter(|&v| !done[v] && dist[v].is_some()) {
+            let better = best.is_none_or(|b| {
+                M::compare(dist[v].as_ref().unwrap(), dist[b].as_ref().unwrap()) == Ordering::Less
+            });
+            if better {
+                best = Some(v);
+            }
+        }
+        let Some(v) = best else {
+            break;
+        };
+        done[v] = true;
+
+        let d = dist[v].clone().unwrap();
+        for &(to, e) in g.out_edges(v) {
+            if !done[to] {
+                let value = M::op(&d, &g.edges()[e].2);
+                relax::<M>(&mut dist, &mut prev, v, to, value, true);
+            }
+        }
+    }
+    ShortestPaths { dist, prev }
+}
+
+/// Computes best paths from `s` by the Bellman-Ford algorithm, which allows negative edges.
+/// Vertices reachable from a negative cycle reachable from `s` have no best path and are reported separately.
+///
+/// 負の辺を許す Bellman-Ford 法により `s` からの最良の経路を計算する.
+/// `s` から到達可能な負閉路から到達可能な頂点は最良の経路を持たず, 別に報告される.
+///
+/// # Args
+/// - `g`: The graph whose weights are path values of `M`.
+///   重みが `M` の経路の値であるグラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `(ShortestPaths<M::S>, Vec<usize>)`: The best paths, in which the affected vertices have `None`,
+///                                      and the affected vertices in ascending order.
+///                                      影響を受ける頂点を `None` とした最良の経路と, 昇順に並んだ影響を受ける頂点.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices.
+/// `s` が頂点数以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(NM), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::algebra::path_monoid::MinPlusMonoid;
+/// use anmitsu::graph::{shortest_path::bellman_ford, Graph};
+/// let g = Graph::directed(4, vec![(0, 1, 2), (1, 2, -3), (2, 1, 1), (0, 3, -1)]);
+/// let (paths, affected) = bellman_ford::<MinPlusMonoid>(&g, 0);
+/// assert_eq!(vec![Some(0), None, None, Some(-1)], paths.dist);
+/// assert_eq!(vec![1, 2], affected);
+/// ```
+pub fn bellman_ford<M>(g: &Graph<M::S>, s: usize) -> (ShortestPaths<M::S>, Vec<usize>)
+where
+    M: PathMonoid,
+    M::S: Clone,
+{
+    check_vertex(g.num_vertices(), s);
+
+    let n = g.num_vertices();
+    let mut dist = vec![None; n];
+    let mut prev = vec![None; n];
+    dist[s] = Some(M::id());
+
+    // Relaxes every edge once, and collects the heads of the edges that improved a path.
+    let round = |dist: &mut [Option<M::S>], prev: &mut [Option<usize>]| {
+        let mut improved = Vec::new();
+        for v in 0..n {
+            let Some(d) = dist[v].clone() else {
+                continue;
+            };
+            for &(to, e) in g.out_edges(v) {
+                let value = M::op(&d, &g.edges()[e].2);
+                if relax::<M>(dist, prev, v, to, value, false) {
+                    improved.push(to);
+                }
+            }
+        }
+        improved
+    };
+
+    for _ in 1..n {
+        if round(&mut dist, &mut prev).is_empty() {
+            return (ShortestPaths { dist, prev }, Vec::new());
+        }
+    }
+
+    // An edge that still improves a path after N - 1 rounds is reachable from a negative cycle.
+    let mut stack = round(&mut dist, &mut prev);
+    let mut affected = vec![false; n];
+    for &v in stack.iter() {
+        affected[v] = true;
+    }
+    while let Some(v) = stack.pop() {
+        for to in g.neighbors(v) {
+            if !affected[to] {
+                affected[to] = true;
+                stack.push(to);
+            }
+        }
+    }
+
+    for v in (0..n).filter(|&v| affected[v]) {
+        dist[v] = None;
+        prev[v] = None;
+    }
+    let affected = (0..n).filter(|&v| affected[v]).collect();
+    (ShortestPaths { dist, prev }, affected)
+}
+
+/// Computes best paths from `s` by the shortest path faster algorithm (SPFA), which allows negative edges.
+/// 負の辺を許す SPFA (shortest path faster algorithm) により `s` からの最良の経路を計算する.
+///
+/// # Args
+/// - `g`: The graph whose weights are path values of `M`.
+///   重みが `M` の経路の値であるグラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `Option<ShortestPaths<M::S>>`: The best paths, or `None` if a negative cycle is reachable from `s`.
+///                                最良の経路. `s` から負閉路に到達可能なら `None`.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices.
+/// `s` が頂点数以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(NM) in the worst case, where N is the number of vertices and M is the number of edges,
+///   though it is often much faster in practice.
+///   最悪の場合. ここで N は頂点数, M は辺数であるが, 実際には多くの場合はるかに高速である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::algebra::path_monoid::MinPlusMonoid;
+/// use anmitsu::graph::{shortest_path::spfa, Graph};
+/// let g = Graph::directed(3, vec![(0, 1, 5), (1, 2, -2), (0, 2, 4)]);
+/// assert_eq!(vec![Some(0), Some(5), Some(3)], spfa::<MinPlusMonoid>(&g, 0).unwrap().dist);
+/// let g = Graph::directed(2, vec![(0, 1, 1), (1, 0, -2)]);
+/// assert_eq!(None, spfa::<MinPlusMonoid>(&g, 0));
+/// ```
+pub fn spfa<M>(g: &Graph<M::S>, s: usize) -> Option<ShortestPaths<M::S>>
+where
+    M: PathMonoid,
+    M::S: Clone,
+{
+    check_vertex(g.num_vertices(), s);
+
+    let n = g.num_vertices();
+    let mut dist = vec![None; n];
+    let mut prev = vec![None; n];
+    let mut queued = vec![false; n];
+    // The number of edges on the path that gave the current value of each vertex.
+    let mut edges_used = vec![0; n];
+    let mut queue = VecDeque::from([s]);
+    dist[s] = Some(M::id());
+    queued[s] = true;
+    while let Some(v) = queue.pop_front() {
+        queued[v] = false;
+        let d = dist[v].clone().unwrap();
+        for &(to, e) in g.out_edges(v) {
+            let value = M::op(&d, &g.edges()[e].2);
+            if relax::<M>(&mut dist, &mut prev, v, to, value, false) {
+                // A path with N edges repeats a vertex, so it contains a negative cycle.
+                edges_used[to] = edges_used[v] + 1;
+                if edges_used[to] >= n {
+                    return None;
+                }
+                if !queued[to] {
+                    queued[to] = true;
+                    queue.push_back(to);
+                }
+            }
+        }
+    }
+    Some(ShortestPaths { dist, prev })
+}
+
+/// Computes best paths between all pairs of vertices by the Floyd-Warshall algorithm, which allows negative edges.
+/// 負の辺を許す Floyd-Warshall 法により全ての頂点対の間の最良の経路を計算する.
+///
+/// # Args
+/// - `g`: The graph whose weights are path values of `M`.
+///   重みが `M` の経路の値であるグラフ.
+///
+/// # Returns
+/// `Option<AllPairsShortestPaths<M::S>>`: The best paths, or `None` if the graph has a negative cycle.
+///                                        最良の経路. グラフが負閉路を持つなら `None`.
+///
+/// # Complexity
+/// - Time complexity: O(N^3 + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+/// - Space complexity: O(N^2).
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::algebra::path_monoid::MinPlusMonoid;
+/// use anmitsu::graph::{shortest_path::floyd_warshall, Graph};
+/// let g = Graph::directed(3, vec![(0, 1, 2), (1, 2, -1), (2, 0, 4)]);
+/// let paths = floyd_warshall::<MinPlusMonoid>(&g).unwrap();
+/// assert_eq!(vec![Some(4), Some(6), Some(0)], paths.dist[2]);
+/// let g = Graph::undirected(2, vec![(0, 1, -1)]);
+/// assert_eq!(None, floyd_warshall::<MinPlusMonoid>(&g));
+/// ```
+pub fn floyd_warshall<M>(g: &Graph<M::S>) -> Option<AllPairsShortestPaths<M::S>>
+where
+    M: PathMonoid,
+    M::S: Clone,
+{
+    let n = g.num_vertices();
+    let mut dist = vec![vec![None; n]; n];
+    let mut prev = vec![vec![None; n]; n];
+    for (v, row) in dist.iter_mut().enumerate() {
+        row[v] = Some(M::id());
+    }
+    for v in 0..n {
+        for &(to, e) in g.out_edges(v) {
+            let value = g.edges()[e].2.clone();
+            relax::<M>(&mut dist[v], &mut prev[v], v, to, value, true);
+        }
+    }
+
+    // Paths through the pivot `k` itself are skipped, so that each path is merged only once.
+    for k in 0..n {
+        for i in (0..n).filter(|&i| i != k) {
+            let Some(dik) = dist[i][k].clone() else {
+                continue;
+            };
+            for j in (0..n).filter(|&j| j != k) {
+                let Some(dkj) = &dist[k][j] else {
+                    continue;
+                };
+                let value = M::op(&dik, dkj);
+                // The previous vertex of `j` is the same as on the path from `k`.
+                let p = prev[k][j].unwrap();
+                relax::<M>(&mut dist[i], &mut prev[i], p, j, value, true);
+            }
+        }
+    }
+
+    // A vertex on a negative cycle has a closed walk better than the empty path.
+    let has_negative_cycle = (0..n).any(|v| {
+        dist[v][v]
+            .as_ref()
+            .is_some_and(|d| M::compare(d, &M::id()) == Ordering::Less)
+    });
+    if has_negative_cycle {
+        return None;
+    }
+    Some(AllPairsShortestPaths { dist, prev })
+}
@@ -0,0 +1,199 @@
+//! Iterative graph traversals on `Graph`, which never overflow the call stack on large graphs.
+//! `Graph` 上の反復的なグラフ探索であり, 大きなグラフでも呼び出しスタックを溢れさせない.
+
+use std::collections::VecDeque;
+
+use super::base::{Graph, check_vertex};
+
+/// The result of a depth-first search from a single source.
+/// 単一の始点からの深さ優先探索の結果である.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DfsTree {
+    /// The reachable vertices in the order they were entered.
+    /// 到達可能な頂点を, 訪問を開始した順に並べたもの.
+    pub preorder: Vec<usize>,
+
+    /// The reachable vertices in the order they were finished.
+    /// 到達可能な頂点を, 訪問を終えた順に並べたもの.
+    pub postorder: Vec<usize>,
+
+    /// The parent of each vertex in the DFS tree, or `None` for the source and unreachable vertices.
+    /// DFS 木における各頂点の親. 始点と到達不能な頂点は `None`.
+    pub parent: Vec<Option<usize>>,
+}
+
+/// Computes the number of edges on a shortest path from `s` to every vertex by breadth-first search.
+/// 幅優先探索により, `s` から各頂点への最短路の辺数を計算する.
+///
+/// # Args
+/// - `g`: The graph.
+///   グラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `Vec<Option<usize>>`: The distances, or `None` for unreachable vertices.
+///                       距離. 到達不能な頂点は `None`.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices.
+/// `s` が頂点数以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{traversal::bfs, Graph};
+/// let g = Graph::directed(4, vec![(0, 1, ()), (1, 2, ()), (0, 2, ())]);
+/// assert_eq!(vec![Some(0), Some(1), Some(1), None], bfs(&g, 0));
+/// ```
+pub fn bfs<W>(g: &Graph<W>, s: usize) -> Vec<Option<usize>> {
+    check_vertex(g.num_vertices(), s);
+
+    let mut dist = vec![None; g.num_vertices()];
+    let mut queue = VecDeque::from([s]);
+    dist[s] = Some(0);
+    while let Some(v) = queue.pop_front() {
+        let d = dist[v].unwrap();
+        for to in g.neighbors(v) {
+            if dist[to].is_none() {
+                dist[to] = Some(d + 1);
+                queue.push_back(to);
+            }
+        }
+    }
+    dist
+}
+
+/// Performs a depth-first search from `s` with an explicit stack.
+/// The visiting order is the same as the recursive search that follows the edges in order.
+///
+/// 明示的なスタックを用いて `s` から深さ優先探索を行う.
+/// 訪問順は, 辺を順に辿る再帰的な探索と同じである.
+///
+/// # Args
+/// - `g`: The graph.
+///   グラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `DfsTree`: The preorder, the postorder and the parents of the DFS tree.
+///            DFS 木の行きがけ順, 帰りがけ順および親.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices.
+/// `s` が頂点数以上の場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{traversal::dfs, Graph};
+/// let g = Graph::undirected(4, vec![(0, 1, ()), (0, 2, ()), (1, 3, ())]);
+/// let tree = dfs(&g, 0);
+/// assert_eq!(vec![0, 1, 3, 2], tree.preorder);
+/// assert_eq!(vec![3, 1, 2, 0], tree.postorder);
+/// assert_eq!(Some(1), tree.parent[3]);
+/// ```
+pub fn dfs<W>(g: &Graph<W>, s: usize) -> DfsTree {
+    check_vertex(g.num_vertices(), s);
+
+    let n = g.num_vertices();
+    let mut visited = vec![false; n];
+    let mut tree = DfsTree {
+        preorder: vec![s],
+        postorder: Vec::new(),
+        parent: vec![None; n],
+    };
+
+    // Each frame holds a vertex and the position of the next edge to follow.
+    let mut stack = vec![(s, 0)];
+    visited[s] = true;
+    while let Some((v, i)) = stack.last_mut() {
+        let v = *v;
+        match g.out_edges(v).get(*i) {
+            Some(&(to, _)) => {
+                *i += 1;
+                if !visited[to] {
+                    visited[to] = true;
+                    tree.preorder.push(to);
+                    tree.parent[to] = Some(v);
+                    stack.push((to, 0));
+                }
+            }
+            None => {
+                tree.postorder.push(v);
+                stack.pop();
+            }
+        }
+    }
+    tree
+}
+
+/// Computes the shortest distances from `s` on a graph whose weights are all 0 or 1 by 0-1 BFS.
+/// 重みが全て 0 または 1 のグラフ上で, 0-1 BFS により `s` からの最短距離を計算する.
+///
+/// # Args
+/// - `g`: The graph.
+///   グラフ.
+/// - `s`: The source vertex.
+///   始点.
+///
+/// # Returns
+/// `Vec<Option<usize>>`: The distances, or `None` for unreachable vertices.
+///                       距離. 到達不能な頂点は `None`.
+///
+/// # Panics
+/// Panics if `s` is not less than the number of vertices, or a weight is neither 0 nor 1.
+/// `s` が頂点数以上の場合, または重みが 0 でも 1 でもない場合にパニックする.
+///
+/// # Complexity
+/// - Time complexity: O(N + M), where N is the number of vertices and M is the number of edges.
+///   ここで N は頂点数, M は辺数である.
+///
+/// # Examples
+/// ```rust
+/// use anmitsu::graph::{traversal::zero_one_bfs, Graph};
+/// let g = Graph::directed(3, vec![(0, 1, 1), (0, 2, 0), (2, 1, 0)]);
+/// assert_eq!(vec![Some(0), Some(0), Some(0)], zero_one_bfs(&g, 0));
+/// ```
+pub fn zero_one_bfs<W>(g: &Graph<W>, s: usize) -> Vec<Option<usize>>
+where
+    W: Copy + PartialEq + From<u8>,
+{
+    check_vertex(g.num_vertices(), s);
+
+    let mut dist: Vec<Option<usize>> = vec![None; g.num_vertices()];
+    let mut deque = VecDeque::from([(0, s)]);
+    dist[s] = Some(0);
+    while let Some((d, v)) = deque.pop_front() {
+        // Skip stale entries that were superseded by a shorter distance.
+        if dist[v] != Some(d) {
+            continue;
+        }
+        for &(to, e) in g.out_edges(v) {
+            let w = g.edges()[e].2;
+            let cost = if w == W::from(0) {
+                0
+            } else if w == W::from(1) {
+                1
+            } else {
+                panic!("0-1 BFS requires every weight to be 0 or 1");
+            };
+            if dist[to].is_none_or(|old| d + cost < old) {
+                dist[to] = Some(d + cost);
+                if cost == 0 {
+                    deque.push_front((d, to));
+                } else {
+                    deque.push_back((d + 1, to));
+                }
+            }
+        }
+    }
+    dist
+}
@@ -0,0 +1,669 @@
+//! A module that defines `Tree`, a rooted tree supporting ancestor, path and subtree queries.
+//! 祖先, 経路および部分木に関するクエリをサポートする根付き木 `Tree` を定義するモジュールである.
+
+use std::collections::VecDeque;
+
+use super::base::check_vertex;
+
+/// A rooted tree with precomputed depths, subtree sizes, a DFS preorder,
+/// binary lifting tables and an Euler tour with a sparse table for LCA queries.
+///
+/// The vertices of the subtree of `v` occupy the contiguous range `subtree_range(v)` of the preorder,
+/// so subtree queries become range queries on a sequence indexed by `preorder_index`,
+/// such as `SegmentTreeDense`.
+///
+/// 深さ, 部分木の大きさ, DFS の行きがけ順, ダブリングの表, および LCA クエリのための
+/// オイラーツアーとスパーステーブルを前計算した根付き木である.
+///
+/// `v` の部分木の頂点は行きがけ順の連続する区間 `subtree_range(v)` を占めるため,
+/// 部分木に関するクエリは `preorder_index` で添字付けた列 (例えば `SegmentTreeDense`) 上の区間クエリになる.
+#[derive(Clone, Debug)]
+pub struct Tree {
+    root: usize,
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+
+    // The children of `v` are `children[child_start[v]..child_start[v + 1]]`.
+    child_start: Vec<usize>,
+    children: Vec<usize>,
+
+    preorder: Vec<usize>,
+    preorder_index: Vec<usize>,
+    subtree_size: Vec<usize>,
+
+    // `ancestor[k][v]` is the `2^k`-th ancestor of `v`, or the root if it does not exist.
+    ancestor: Vec<Vec<usize>>,
+
+    // The Euler tour visits a vertex when entering it and after returning from each child.
+    euler_first: Vec<usize>,
+    // `shallowest[k][i]` is the shallowest vertex in `euler[i..i + 2^k]`.
+    shallowest: Vec<Vec<usize>>,
+}
+
+impl Tree {
+    /// Creates a rooted tree from the parent of each vertex.
+    /// 各頂点の親から根付き木を生成する.
+    ///
+    /// # Args
+    /// - `parents`: The parent of each vertex, or `None` for the root.
+    ///   各頂点の親. 根は `None`.
+    ///
+    /// # Returns
+    /// A new `Tree` instance.
+    /// 新しい `Tree` インスタンスを返す.
+    ///
+    /// # Panics
+    /// Panics if a parent is out of bounds, or the parents do not form a tree with exactly one root.
+    /// 親が範囲外の場合, または親がちょうど一つの根を持つ木をなさない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_parents(&[Some(1), None, Some(1), Some(0)]);
+    /// assert_eq!(1, tree.root());
+    /// assert_eq!(2, tree.depth(3));
+    /// ```
+    pub fn from_parents(parents: &[Option<usize>]) -> Self {
+        let n = parents.len();
+        for &p in parents.iter().flatten() {
+            check_vertex(n, p);
+        }
+        let mut roots = (0..n).filter(|&v| parents[v].is_none());
+        let (Some(root), None) = (roots.next(), roots.next()) else {
+            panic!("The parents must form a tree with exactly one root");
+        };
+
+        Self::build(root, parents.to_vec())
+    }
+
+    /// Creates a tree rooted at `root` from its undirected edges.
+    /// 無向辺のリストから `root` を根とする木を生成する.
+    ///
+    /// # Args
+    /// - `n`: The number of vertices.
+    ///   頂点数.
+    /// - `edges`: The `n - 1` undirected edges `(u, v)`.
+    ///   `n - 1` 本の無向辺 `(u, v)` のリスト.
+    /// - `root`: The root.
+    ///   根.
+    ///
+    /// # Returns
+    /// A new `Tree` instance.
+    /// 新しい `Tree` インスタンスを返す.
+    ///
+    /// # Panics
+    /// Panics if an endpoint or `root` is not less than `n`, or the edges do not form a tree.
+    /// 端点または `root` が `n` 以上の場合, または辺が木をなさない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (1, 3)], 2);
+    /// assert_eq!(Some(1), tree.parent(0));
+    /// assert_eq!(None, tree.parent(2));
+    /// ```
+    pub fn from_edges(n: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        check_vertex(n, root);
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v) in edges.iter() {
+            check_vertex(n, u);
+            check_vertex(n, v);
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+
+        let mut parents = vec![None; n];
+        let mut seen = vec![false; n];
+        let mut queue = VecDeque::from([root]);
+        seen[root] = true;
+        while let Some(v) = queue.pop_front() {
+            for &to in adj[v].iter() {
+                if !seen[to] {
+                    seen[to] = true;
+                    parents[to] = Some(v);
+                    queue.push_back(to);
+                }
+            }
+        }
+        if edges.len() + 1 != n || seen.contains(&false) {
+            panic!("The edges must form a tree");
+        }
+
+        Self::build(root, parents)
+    }
+
+    fn build(root: usize, parent: Vec<Option<usize>>) -> Self {
+        let n = parent.len();
+        let mut child_start = vec![0; n + 1];
+        for &p in parent.iter().flatten() {
+            child_start[p + 1] += 1;
+        }
+        for v in 0..n {
+            child_start[v + 1] += child_start[v];
+        }
+        let mut pos = child_start.clone();
+        let mut children = vec![0; n - 1];
+        for (v, &p) in parent.iter().enumerate() {
+            if let Some(p) = p {
+                children[pos[p]] = v;
+                pos[p] += 1;
+            }
+        }
+
+        // A DFS with an explicit stack, recording the preorder and the Euler tour.
+        let mut depth = vec![0; n];
+        let mut preorder = Vec::with_capacity(n);
+        let mut euler = Vec::with_capacity(2 * n - 1);
+        let mut euler_first = vec![0; n];
+        let mut stack = vec![(root, child_start[root])];
+        preorder.push(root);
+        euler.push(root);
+        while let Some((v, i)) = stack.last_mut() {
+            let v = *v;
+            if *i < child_start[v + 1] {
+                let c = children[*i];
+                *i += 1;
+                depth[c] = depth[v] + 1;
+                euler_first[c] = euler.len();
+                preorder.push(c);
+                euler.push(c);
+                stack.push((c, child_start[c]));
+            } else {
+                stack.pop();
+                if let Some(&(p, _)) = stack.last() {
+                    euler.push(p);
+                }
+            }
+        }
+        if preorder.len() < n {
+            panic!("The parents must form a tree with exactly one root");
+        }
+
+        let mut preorder_index = vec![0; n];
+        for (i, &v) in preorder.iter().enumerate() {
+            preorder_index[v] = i;
+        }
+        let mut subtree_size = vec![1; n];
+        for &v in preorder.iter().rev() {
+            if let Some(p) = parent[v] {
+                subtree_size[p] += subtree_size[v];
+            }
+        }
+
+        let mut ancestor = vec![parent.iter().map(|p| p.unwrap_or(root)).collect::<Vec<_>>()];
+        while 1 << ancestor.len() < n {
+            let prev = ancestor.last().unwrap();
+            let next = prev.iter().map(|&a| prev[a]).collect();
+            ancestor.push(next);
+        }
+
+        let mut shallowest = vec![euler.clone()];
+        let mut k = 1;
+        while 1 << k <= euler.len() {
+            let prev = shallowest.last().unwrap();
+            let next = (0..=euler.len() - (1 << k))
+                .map(|i| {
+                    let (a, b) = (prev[i], prev[i + (1 << (k - 1))]);
+                    if depth[a] <= depth[b] { a } else { b }
+                })
+                .collect();
+            shallowest.push(next);
+            k += 1;
+        }
+
+        Tree {
+            root,
+            parent,
+            depth,
+            child_start,
+            children,
+            preorder,
+            preorder_index,
+            subtree_size,
+            ancestor,
+            euler_first,
+            shallowest,
+        }
+    }
+
+    /// Returns the number of vertices.
+    /// 頂点数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of vertices.
+    ///          頂点数.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if the tree has no vertices, which never happens.
+    /// 木が頂点を持たない場合に `true` を返す. これは決して起こらない.
+    ///
+    /// # Returns
+    /// `bool`: Always `false`.
+    ///         常に `false`.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the root.
+    /// 根を返す.
+    ///
+    /// # Returns
+    /// `usize`: The root.
+    ///          根.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Returns the parent of `v`.
+    /// `v` の親を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `Option<usize>`: The parent, or `None` if `v` is the root.
+    ///                  親. `v` が根なら `None`.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        check_vertex(self.len(), v);
+        self.parent[v]
+    }
+
+    /// Returns the children of `v`.
+    /// `v` の子を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `&[usize]`: The children in ascending order.
+    ///             昇順に並んだ子.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn children(&self, v: usize) -> &[usize] {
+        check_vertex(self.len(), v);
+        &self.children[self.child_start[v]..self.child_start[v + 1]]
+    }
+
+    /// Returns the depth of `v`, which is the number of edges between `v` and the root.
+    /// `v` の深さ, すなわち `v` と根の間の辺の数を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `usize`: The depth.
+    ///          深さ.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn depth(&self, v: usize) -> usize {
+        check_vertex(self.len(), v);
+        self.depth[v]
+    }
+
+    /// Returns the number of vertices in the subtree of `v`.
+    /// `v` の部分木の頂点数を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `usize`: The subtree size.
+    ///          部分木の大きさ.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn subtree_size(&self, v: usize) -> usize {
+        check_vertex(self.len(), v);
+        self.subtree_size[v]
+    }
+
+    /// Returns the vertices in DFS preorder, visiting the children in ascending order.
+    /// 子を昇順に訪問する DFS の行きがけ順に頂点を返す.
+    ///
+    /// # Returns
+    /// `&[usize]`: The preorder.
+    ///             行きがけ順.
+    pub fn preorder(&self) -> &[usize] {
+        &self.preorder
+    }
+
+    /// Returns the position of `v` in the preorder.
+    /// 行きがけ順における `v` の位置を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `usize`: The index `i` such that `preorder()[i] == v`.
+    ///          `preorder()[i] == v` を満たすインデックス `i`.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    pub fn preorder_index(&self, v: usize) -> usize {
+        check_vertex(self.len(), v);
+        self.preorder_index[v]
+    }
+
+    /// Returns the range of the preorder occupied by the subtree of `v`.
+    /// `v` の部分木が占める行きがけ順の区間を返す.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    ///
+    /// # Returns
+    /// `(usize, usize)`: The half-open range `[l, r)` of preorder indices.
+    ///                   行きがけ順のインデックスの半開区間 `[l, r)`.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{algebra::monoid, ds::segment_tree::segment_tree_dense, graph::tree::Tree};
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (0, 3)], 0);
+    /// let weights = [1, 10, 100, 1000];
+    /// let mut seg = segment_tree_dense::SegmentTreeDense::<monoid::AddMonoid>::new(4);
+    /// for v in 0..4 {
+    ///     seg.set(tree.preorder_index(v), weights[v]);
+    /// }
+    /// seg.build();
+    /// let (l, r) = tree.subtree_range(1);
+    /// assert_eq!(110, seg.fold(l, r));
+    /// ```
+    pub fn subtree_range(&self, v: usize) -> (usize, usize) {
+        check_vertex(self.len(), v);
+        let l = self.preorder_index[v];
+        (l, l + self.subtree_size[v])
+    }
+
+    /// Returns the `k`-th ancestor of `v`, where the 0-th ancestor is `v` itself.
+    /// `v` の `k` 番目の祖先を返す. 0 番目の祖先は `v` 自身である.
+    ///
+    /// # Args
+    /// - `v`: The vertex.
+    ///   頂点.
+    /// - `k`: The number of edges to go up.
+    ///   遡る辺の数.
+    ///
+    /// # Returns
+    /// `Option<usize>`: The ancestor, or `None` if `k` exceeds the depth of `v`.
+    ///                  祖先. `k` が `v` の深さを超えるなら `None`.
+    ///
+    /// # Panics
+    /// Panics if `v` is not less than the number of vertices.
+    /// `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_parents(&[None, Some(0), Some(1), Some(2)]);
+    /// assert_eq!(Some(1), tree.kth_ancestor(3, 2));
+    /// assert_eq!(None, tree.kth_ancestor(3, 4));
+    /// ```
+    pub fn kth_ancestor(&self, v: usize, k: usize) -> Option<usize> {
+        check_vertex(self.len(), v);
+        if k > self.depth[v] {
+            return None;
+        }
+
+        let mut v = v;
+        for (bit, table) in self.ancestor.iter().enumerate() {
+            if k >> bit & 1 == 1 {
+                v = table[v];
+            }
+        }
+        Some(v)
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v` by binary lifting.
+    /// ダブリングにより `u` と `v` の最小共通祖先を返す.
+    ///
+    /// # Args
+    /// - `u`: The first vertex.
+    ///   一つ目の頂点.
+    /// - `v`: The second vertex.
+    ///   二つ目の頂点.
+    ///
+    /// # Returns
+    /// `usize`: The deepest vertex that is an ancestor of both `u` and `v`.
+    ///          `u` と `v` の両方の祖先である最も深い頂点.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(5, &[(0, 1), (1, 2), (1, 3), (0, 4)], 0);
+    /// assert_eq!(1, tree.lca(2, 3));
+    /// assert_eq!(0, tree.lca(3, 4));
+    /// ```
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        check_vertex(self.len(), u);
+        check_vertex(self.len(), v);
+
+        let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        u = self.kth_ancestor(u, self.depth[u] - self.depth[v]).unwrap();
+        if u == v {
+            return u;
+        }
+        for table in self.ancestor.iter().rev() {
+            if table[u] != table[v] {
+                u = table[u];
+                v = table[v];
+            }
+        }
+        self.ancestor[0][u]
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v` by the Euler tour and a sparse table.
+    /// オイラーツアーとスパーステーブルにより `u` と `v` の最小共通祖先を返す.
+    ///
+    /// # Args
+    /// - `u`: The first vertex.
+    ///   一つ目の頂点.
+    /// - `v`: The second vertex.
+    ///   二つ目の頂点.
+    ///
+    /// # Returns
+    /// `usize`: The deepest vertex that is an ancestor of both `u` and `v`.
+    ///          `u` と `v` の両方の祖先である最も深い頂点.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(5, &[(0, 1), (1, 2), (1, 3), (0, 4)], 0);
+    /// assert_eq!(1, tree.lca_euler(2, 3));
+    /// assert_eq!(0, tree.lca_euler(3, 4));
+    /// ```
+    pub fn lca_euler(&self, u: usize, v: usize) -> usize {
+        check_vertex(self.len(), u);
+        check_vertex(self.len(), v);
+
+        let (l, r) = {
+            let (a, b) = (self.euler_first[u], self.euler_first[v]);
+            (a.min(b), a.max(b) + 1)
+        };
+        let k = (r - l).ilog2() as usize;
+        let (a, b) = (self.shallowest[k][l], self.shallowest[k][r - (1 << k)]);
+        if self.depth[a] <= self.depth[b] { a } else { b }
+    }
+
+    /// Returns the number of edges on the path between `u` and `v`.
+    /// `u` と `v` の間の経路上の辺の数を返す.
+    ///
+    /// # Args
+    /// - `u`: The first vertex.
+    ///   一つ目の頂点.
+    /// - `v`: The second vertex.
+    ///   二つ目の頂点.
+    ///
+    /// # Returns
+    /// `usize`: The distance.
+    ///          距離.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(5, &[(0, 1), (1, 2), (1, 3), (0, 4)], 0);
+    /// assert_eq!(3, tree.distance(2, 4));
+    /// ```
+    pub fn distance(&self, u: usize, v: usize) -> usize {
+        let w = self.lca_euler(u, v);
+        self.depth[u] + self.depth[v] - 2 * self.depth[w]
+    }
+
+    /// Returns the vertex `k` edges away from `u` on the path from `u` to `v`.
+    /// `u` から `v` への経路上で `u` から `k` 辺進んだ頂点を返す.
+    ///
+    /// # Args
+    /// - `u`: The start of the path.
+    ///   経路の始点.
+    /// - `v`: The end of the path.
+    ///   経路の終点.
+    /// - `k`: The number of edges to move.
+    ///   進む辺の数.
+    ///
+    /// # Returns
+    /// `Option<usize>`: The vertex, or `None` if `k` exceeds the distance between `u` and `v`.
+    ///                  頂点. `k` が `u` と `v` の距離を超えるなら `None`.
+    ///
+    /// # Panics
+    /// Panics if `u` or `v` is not less than the number of vertices.
+    /// `u` または `v` が頂点数以上の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(5, &[(0, 1), (1, 2), (1, 3), (0, 4)], 0);
+    /// assert_eq!(Some(0), tree.jump(2, 4, 2));
+    /// assert_eq!(None, tree.jump(2, 4, 4));
+    /// ```
+    pub fn jump(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let w = self.lca_euler(u, v);
+        let up = self.depth[u] - self.depth[w];
+        let down = self.depth[v] - self.depth[w];
+        if k <= up {
+            self.kth_ancestor(u, k)
+        } else if k <= up + down {
+            self.kth_ancestor(v, up + down - k)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a longest path of the tree.
+    /// 木の最長経路を返す.
+    ///
+    /// # Returns
+    /// `(usize, Vec<usize>)`: The number of edges on the path, and the vertices on it in order.
+    ///                        経路上の辺の数と, 経路上の頂点を順に並べたもの.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(5, &[(0, 1), (1, 2), (1, 3), (0, 4)], 0);
+    /// let (len, path) = tree.diameter();
+    /// assert_eq!(3, len);
+    /// assert_eq!(4, path.len());
+    /// ```
+    pub fn diameter(&self) -> (usize, Vec<usize>) {
+        // The deepest vertex is an end of some longest path.
+        let a = (0..self.len()).max_by_key(|&v| self.depth[v]).unwrap();
+        let b = (0..self.len())
+            .max_by_key(|&v| self.distance(a, v))
+            .unwrap();
+        let len = self.distance(a, b);
+        let path = (0..=len).map(|k| self.jump(a, b, k).unwrap()).collect();
+        (len, path)
+    }
+
+    /// Returns the center of the tree, which minimizes the distance to the farthest vertex.
+    /// 木の中心, すなわち最も遠い頂点までの距離を最小にする頂点を返す.
+    ///
+    /// # Returns
+    /// `Vec<usize>`: The one or two centers in ascending order.
+    ///               昇順に並んだ一つまたは二つの中心.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is the number of vertices.
+    ///   ここで N は頂点数である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::graph::tree::Tree;
+    /// let tree = Tree::from_edges(4, &[(0, 1), (1, 2), (2, 3)], 0);
+    /// assert_eq!(vec![1, 2], tree.center());
+    /// ```
+    pub fn center(&self) -> Vec<usize> {
+        let (len, path) = self.diameter();
+        let mut center = vec![path[len / 2]];
+        if len % 2 == 1 {
+            center.push(path[len / 2 + 1]);
+            center.sort_unstable();
+        }
+        center
+    }
+}
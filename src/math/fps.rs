@@ -0,0 +1,591 @@
+//! A formal power series over `ModInt998244353` built on the number theoretic transform.
+//! number theoretic transform を用いた, `ModInt998244353` 上の形式的冪級数である.
+
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::ds::modint::{convolution998244353, modint998244353::ModInt998244353};
+
+type Mint = ModInt998244353;
+
+/// A formal power series `f(x) = c_0 + c_1 x + c_2 x^2 + ...` with coefficients in `ModInt998244353`.
+/// The series is stored as the finite coefficient vector `[c_0, c_1, ..., c_{len - 1}]`.
+/// `ModInt998244353` を係数に持つ形式的冪級数 `f(x) = c_0 + c_1 x + c_2 x^2 + ...` である.
+/// 級数は有限の係数列 `[c_0, c_1, ..., c_{len - 1}]` として保持される.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Fps {
+    coef: Vec<Mint>,
+}
+
+impl Fps {
+    /// Creates a new `Fps` from its coefficients in ascending order of degree.
+    /// 次数の昇順に並んだ係数から新しい `Fps` を生成する.
+    ///
+    /// # Args
+    /// - `coef`: The coefficients `[c_0, c_1, ...]`.
+    ///   係数列 `[c_0, c_1, ...]`.
+    ///
+    /// # Returns
+    /// `Fps`: A new formal power series.
+    ///        新しい形式的冪級数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// let f = Fps::new(vec![ModInt998244353::new(1), ModInt998244353::new(2)]);
+    /// assert_eq!(2, f.len());
+    /// ```
+    pub fn new(coef: Vec<ModInt998244353>) -> Self {
+        Fps { coef }
+    }
+
+    /// Returns the coefficients in ascending order of degree.
+    /// 次数の昇順に並んだ係数を返す.
+    ///
+    /// # Returns
+    /// `&[ModInt998244353]`: The coefficients.
+    ///                       係数列.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn coef(&self) -> &[ModInt998244353] {
+        &self.coef
+    }
+
+    /// Returns the number of stored coefficients.
+    /// 保持している係数の個数を返す.
+    ///
+    /// # Returns
+    /// `usize`: The number of coefficients.
+    ///          係数の個数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn len(&self) -> usize {
+        self.coef.len()
+    }
+
+    /// Returns `true` if no coefficient is stored.
+    /// 係数を一つも保持していない場合に `true` を返す.
+    ///
+    /// # Returns
+    /// `bool`: `true` if `self.len() == 0`, otherwise `false`.
+    ///         `self.len() == 0` の場合は `true`, そうでなければ `false`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(1).
+    pub fn is_empty(&self) -> bool {
+        self.coef.is_empty()
+    }
+
+    /// Returns `f mod x^n`, padding with zeros if `n > self.len()`.
+    /// `f mod x^n` を返す. `n > self.len()` の場合はゼロで埋める.
+    ///
+    /// # Args
+    /// - `n`: The number of coefficients to keep.
+    ///   保持する係数の個数.
+    ///
+    /// # Returns
+    /// `Fps`: A series with exactly `n` coefficients.
+    ///        ちょうど `n` 個の係数を持つ級数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// let f = Fps::new(vec![ModInt998244353::new(1), ModInt998244353::new(2)]);
+    /// assert_eq!(vec![ModInt998244353::new(1)], f.prefix(1).coef());
+    /// assert_eq!(3, f.prefix(3).len());
+    /// ```
+    pub fn prefix(&self, n: usize) -> Self {
+        let mut coef = self.coef[..n.min(self.len())].to_vec();
+        coef.resize(n, Mint::new(0));
+        Fps { coef }
+    }
+
+    // Returns the coefficient of x^i, which is zero beyond the stored coefficients.
+    fn at(&self, i: usize) -> Mint {
+        self.coef.get(i).copied().unwrap_or(Mint::new(0))
+    }
+
+    // Drops trailing zero coefficients.
+    fn trimmed(mut self) -> Self {
+        while self.coef.last() == Some(&Mint::new(0)) {
+            self.coef.pop();
+        }
+        self
+    }
+
+    /// Returns the formal derivative `f'`.
+    /// 形式的微分 `f'` を返す.
+    ///
+    /// # Returns
+    /// `Fps`: The derivative, which has `max(self.len(), 1) - 1` coefficients.
+    ///        `max(self.len(), 1) - 1` 個の係数を持つ導関数.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is `self.len()`.
+    ///   ここで N は `self.len()` である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// let f = Fps::new([5, 3, 2].into_iter().map(ModInt998244353::new).collect());
+    /// let expected: Vec<_> = [3, 4].into_iter().map(ModInt998244353::new).collect();
+    /// assert_eq!(expected, f.derivative().coef());
+    /// ```
+    pub fn derivative(&self) -> Self {
+        Fps {
+            coef: (1..self.len()).map(|i| self.coef[i] * i as u32).collect(),
+        }
+    }
+
+    /// Returns the formal integral `∫ f` whose constant term is zero.
+    /// 定数項がゼロである形式的積分 `∫ f` を返す.
+    ///
+    /// # Returns
+    /// `Fps`: The integral, which has `self.len() + 1` coefficients.
+    ///        `self.len() + 1` 個の係数を持つ積分.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N), where N is `self.len()`.
+    ///   ここで N は `self.len()` である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// let f = Fps::new([3, 4].into_iter().map(ModInt998244353::new).collect());
+    /// let expected: Vec<_> = [0, 3, 2].into_iter().map(ModInt998244353::new).collect();
+    /// assert_eq!(expected, f.integral().coef());
+    /// ```
+    pub fn integral(&self) -> Self {
+        let invs = inverses(self.len() + 1);
+        let mut coef = Vec::with_capacity(self.len() + 1);
+        coef.push(Mint::new(0));
+        coef.extend(self.coef.iter().zip(&invs[1..]).map(|(&c, &inv)| c * inv));
+        Fps { coef }
+    }
+
+    /// Returns the multiplicative inverse `1 / f mod x^n`.
+    /// 乗法逆元 `1 / f mod x^n` を返す.
+    ///
+    /// # Args
+    /// - `n`: The number of coefficients to compute.
+    ///   計算する係数の個数.
+    ///
+    /// # Returns
+    /// `Fps`: The first `n` coefficients of `1 / f`.
+    ///        `1 / f` の先頭 `n` 項.
+    ///
+    /// # Panics
+    /// Panics if the constant term of `f` is zero.
+    /// `f` の定数項がゼロの場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n log n) by Newton's method.
+    ///   Newton 法により O(n log n) である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// // 1 / (1 - x) = 1 + x + x^2 + ...
+    /// let f = Fps::new(vec![ModInt998244353::new(1), -ModInt998244353::new(1)]);
+    /// assert_eq!(vec![ModInt998244353::new(1); 4], f.inv(4).coef());
+    /// ```
+    pub fn inv(&self, n: usize) -> Self {
+        let c0 = self
+            .at(0)
+            .inv()
+            .unwrap_or_else(|| panic!("The constant term must be non-zero to compute inv"));
+
+        let mut g = Fps { coef: vec![c0] };
+        let mut m = 1;
+        // g <- g (2 - f g) doubles the number of correct coefficients.
+        while m < n {
+            m *= 2;
+            let mut h = -(self.prefix(m) * g.clone()).prefix(m);
+            h.coef[0] += 2_u32;
+            g = (g * h).prefix(m);
+        }
+        g.prefix(n)
+    }
+
+    /// Returns the logarithm `log f mod x^n`.
+    /// 対数 `log f mod x^n` を返す.
+    ///
+    /// # Args
+    /// - `n`: The number of coefficients to compute.
+    ///   計算する係数の個数.
+    ///
+    /// # Returns
+    /// `Fps`: The first `n` coefficients of `log f`.
+    ///        `log f` の先頭 `n` 項.
+    ///
+    /// # Panics
+    /// Panics if the constant term of `f` is not one.
+    /// `f` の定数項が 1 でない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n log n).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// // log(1 / (1 - x)) = x + x^2 / 2 + x^3 / 3 + ...
+    /// let f = Fps::new(vec![ModInt998244353::new(1); 4]);
+    /// let g = f.log(4);
+    /// assert_eq!(ModInt998244353::new(1) / 3_u32, g.coef()[3]);
+    /// ```
+    pub fn log(&self, n: usize) -> Self {
+        assert!(
+            self.at(0) == Mint::new(1),
+            "The constant term must be one to compute log"
+        );
+        if n == 0 {
+            return Fps::new(Vec::new());
+        }
+        // log f = ∫ f' / f.
+        (self.prefix(n).derivative() * self.inv(n))
+            .prefix(n - 1)
+            .integral()
+    }
+
+    /// Returns the exponential `exp f mod x^n`.
+    /// 指数関数 `exp f mod x^n` を返す.
+    ///
+    /// # Args
+    /// - `n`: The number of coefficients to compute.
+    ///   計算する係数の個数.
+    ///
+    /// # Returns
+    /// `Fps`: The first `n` coefficients of `exp f`.
+    ///        `exp f` の先頭 `n` 項.
+    ///
+    /// # Panics
+    /// Panics if the constant term of `f` is not zero.
+    /// `f` の定数項がゼロでない場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n log n) by Newton's method.
+    ///   Newton 法により O(n log n) である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// // exp(x) = 1 + x + x^2 / 2 + x^3 / 6 + ...
+    /// let f = Fps::new(vec![ModInt998244353::new(0), ModInt998244353::new(1)]);
+    /// let g = f.exp(4);
+    /// assert_eq!(ModInt998244353::new(1) / 6_u32, g.coef()[3]);
+    /// ```
+    pub fn exp(&self, n: usize) -> Self {
+        assert!(
+            self.at(0) == Mint::new(0),
+            "The constant term must be zero to compute exp"
+        );
+
+        let mut g = Fps {
+            coef: vec![Mint::new(1)],
+        };
+        let mut m = 1;
+        // g <- g (1 - log g + f) doubles the number of correct coefficients.
+        while m < n {
+            m *= 2;
+            let mut h = self.prefix(m) - g.log(m);
+            h.coef[0] += 1_u32;
+            g = (g * h).prefix(m);
+        }
+        g.prefix(n)
+    }
+
+    /// Returns the power `f^k mod x^n`.
+    /// 冪 `f^k mod x^n` を返す.
+    ///
+    /// # Args
+    /// - `k`: The exponent.
+    ///   冪指数.
+    /// - `n`: The number of coefficients to compute.
+    ///   計算する係数の個数.
+    ///
+    /// # Returns
+    /// `Fps`: The first `n` coefficients of `f^k`. `f^0` is `1`.
+    ///        `f^k` の先頭 `n` 項. `f^0` は `1` である.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n log n).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// // (x + x^2)^2 = x^2 + 2x^3 + x^4
+    /// let f = Fps::new([0, 1, 1].into_iter().map(ModInt998244353::new).collect());
+    /// let expected: Vec<_> = [0, 0, 1, 2, 1].into_iter().map(ModInt998244353::new).collect();
+    /// assert_eq!(expected, f.pow(2, 5).coef());
+    /// ```
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return Fps::new(vec![Mint::new(1)]).prefix(n);
+        }
+
+        // Factor f = c x^i (1 + x g) so that the logarithm is defined.
+        let i = match self.coef.iter().position(|&c| c != Mint::new(0)) {
+            Some(i) => i,
+            None => return Fps::new(Vec::new()).prefix(n),
+        };
+        let shift = match (i as u64).checked_mul(k) {
+            Some(shift) if shift < n as u64 => shift as usize,
+            _ => return Fps::new(Vec::new()).prefix(n),
+        };
+
+        let c = self.coef[i];
+        let c_inv = c.inv().unwrap();
+        let m = n - shift;
+        let normalized = Fps::new(self.coef[i..].iter().map(|&x| x * c_inv).collect());
+        let powered = (normalized.log(m) * Mint::new(k)).exp(m);
+        // c^k = c^(k mod (MOD - 1)) since c is non-zero.
+        let c_pow = c.pow((k % (convolution998244353::MOD as u64 - 1)) as usize);
+
+        let mut coef = vec![Mint::new(0); shift];
+        coef.extend(powered.coef.iter().map(|&x| x * c_pow));
+        Fps { coef }
+    }
+
+    /// Returns a square root `g` with `g^2 = f mod x^n`, if one exists.
+    /// `g^2 = f mod x^n` を満たす平方根 `g` が存在すれば返す.
+    ///
+    /// # Args
+    /// - `n`: The number of coefficients to compute.
+    ///   計算する係数の個数.
+    ///
+    /// # Returns
+    /// `Option<Fps>`: `Some(g)` with `n` coefficients, or `None` if `f` has no square root.
+    ///                `n` 個の係数を持つ `Some(g)`, または `f` が平方根を持たない場合は `None`.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(n log n) by Newton's method.
+    ///   Newton 法により O(n log n) である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// // (1 + x)^2 = 1 + 2x + x^2
+    /// let f = Fps::new([1, 2, 1].into_iter().map(ModInt998244353::new).collect());
+    /// let g = f.sqrt(3).unwrap();
+    /// assert_eq!(f, (g.clone() * g).prefix(3));
+    ///
+    /// // x has no square root.
+    /// let f = Fps::new([0, 1].into_iter().map(ModInt998244353::new).collect());
+    /// assert!(f.sqrt(2).is_none());
+    /// ```
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        // Factor f = c x^i (1 + x g); a square root needs an even i and a square c.
+        let i = match self.coef.iter().position(|&c| c != Mint::new(0)) {
+            Some(i) if i < n => i,
+            _ => return Some(Fps::new(Vec::new()).prefix(n)),
+        };
+        if i % 2 == 1 {
+            return None;
+        }
+
+        let shift = i / 2;
+        let m = n - shift;
+        let f = Fps::new(self.coef[i..].to_vec());
+        let inv2 = Mint::new(2).inv().unwrap();
+
+        let mut g = Fps {
+            coef: vec![sqrt_mod(f.coef[0])?],
+        };
+        let mut k = 1;
+        // g <- (g + f / g) / 2 doubles the number of correct coefficients.
+        while k < m {
+            k *= 2;
+            g = (g.clone() + f.prefix(k) * g.inv(k)).prefix(k) * inv2;
+        }
+
+        let mut coef = vec![Mint::new(0); shift];
+        coef.extend_from_slice(&g.coef[..m]);
+        Some(Fps { coef })
+    }
+
+    /// Divides `self` by the polynomial `rhs` and returns the quotient and the remainder.
+    /// 多項式 `rhs` で `self` を割り, 商と余りを返す.
+    ///
+    /// # Args
+    /// - `rhs`: The divisor polynomial.
+    ///   除数の多項式.
+    ///
+    /// # Returns
+    /// `(Fps, Fps)`: `(q, r)` with `self = q * rhs + r` and `deg r < deg rhs`.
+    ///               Both are returned without trailing zeros.
+    ///               `self = q * rhs + r` かつ `deg r < deg rhs` を満たす `(q, r)`.
+    ///               いずれも末尾のゼロを除いて返される.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is the zero polynomial.
+    /// `rhs` がゼロ多項式の場合にパニックする.
+    ///
+    /// # Complexity
+    /// - Time complexity: O(N log N), where N is `self.len() + rhs.len()`.
+    ///   ここで N は `self.len() + rhs.len()` である.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+    /// // x^2 + 3x + 5 = (x + 2)(x + 1) + 3
+    /// let f = Fps::new([5, 3, 1].into_iter().map(ModInt998244353::new).collect());
+    /// let g = Fps::new([1, 1].into_iter().map(ModInt998244353::new).collect());
+    /// let (q, r) = f.div_rem(&g);
+    /// assert_eq!(vec![ModInt998244353::new(2), ModInt998244353::new(1)], q.coef());
+    /// assert_eq!(vec![ModInt998244353::new(3)], r.coef());
+    /// ```
+    pub fn div_rem(&self, rhs: &Fps) -> (Fps, Fps) {
+        let a = self.clone().trimmed();
+        let b = rhs.clone().trimmed();
+        assert!(!b.is_empty(), "Division by the zero polynomial");
+
+        if a.len() < b.len() {
+            return (Fps::new(Vec::new()), a);
+        }
+
+        // Reversing turns the leading coefficients into a power series division.
+        let k = a.len() - b.len() + 1;
+        let rev_a = Fps::new(a.coef.iter().rev().copied().collect());
+        let rev_b = Fps::new(b.coef.iter().rev().copied().collect());
+        let mut q = (rev_a.prefix(k) * rev_b.inv(k)).prefix(k);
+        q.coef.reverse();
+
+        let r = (a - q.clone() * b.clone()).prefix(b.len() - 1);
+        (q.trimmed(), r.trimmed())
+    }
+}
+
+// Returns the table of the modular inverses of 0, 1, ..., n - 1 (the entry for 0 is unused).
+fn inverses(n: usize) -> Vec<Mint> {
+    let p = convolution998244353::MOD;
+    let mut invs = vec![Mint::new(1); n.max(2)];
+    // inv(i) = -(p / i) * inv(p mod i) since p = (p / i) * i + (p mod i).
+    for i in 2..n {
+        invs[i] = -(invs[p as usize % i] * (p / i as u32));
+    }
+    invs.truncate(n);
+    invs
+}
+
+// Returns a square root of `a` modulo 998244353 by the Tonelli-Shanks algorithm.
+fn sqrt_mod(a: Mint) -> Option<Mint> {
+    let p = convolution998244353::MOD as usize;
+    if a == Mint::new(0) {
+        return Some(a);
+    }
+    // Euler's criterion.
+    if a.pow((p - 1) / 2) != Mint::new(1) {
+        return None;
+    }
+
+    // p - 1 = q * 2^s with q odd; 3 is a quadratic non-residue modulo 998244353.
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let mut m = s;
+    let mut c = Mint::new(3).pow(q);
+    let mut t = a.pow(q);
+    let mut r = a.pow(q.div_ceil(2));
+    while t != Mint::new(1) {
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != Mint::new(1) {
+            t2 *= t2;
+            i += 1;
+        }
+        let b = c.pow(1 << (m - i - 1));
+        m = i;
+        c = b * b;
+        t *= c;
+        r *= b;
+    }
+    Some(r)
+}
+
+/// Implements coefficient-wise addition.
+/// 係数ごとの加算を実装する.
+impl Add for Fps {
+    type Output = Fps;
+
+    fn add(self, rhs: Fps) -> Fps {
+        let n = self.len().max(rhs.len());
+        Fps {
+            coef: (0..n).map(|i| self.at(i) + rhs.at(i)).collect(),
+        }
+    }
+}
+
+/// Implements coefficient-wise subtraction.
+/// 係数ごとの減算を実装する.
+impl Sub for Fps {
+    type Output = Fps;
+
+    fn sub(self, rhs: Fps) -> Fps {
+        let n = self.len().max(rhs.len());
+        Fps {
+            coef: (0..n).map(|i| self.at(i) - rhs.at(i)).collect(),
+        }
+    }
+}
+
+/// Implements coefficient-wise negation.
+/// 係数ごとの符号反転を実装する.
+impl Neg for Fps {
+    type Output = Fps;
+
+    fn neg(self) -> Fps {
+        Fps {
+            coef: self.coef.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+/// Implements the product of two series by the number theoretic transform.
+/// number theoretic transform による 2 つの級数の積を実装する.
+impl Mul for Fps {
+    type Output = Fps;
+
+    fn mul(self, rhs: Fps) -> Fps {
+        Fps {
+            coef: convolution998244353::convolution_modint(&self.coef, &rhs.coef),
+        }
+    }
+}
+
+/// Implements multiplication of every coefficient by a scalar.
+/// 全ての係数へのスカラー倍を実装する.
+impl Mul<ModInt998244353> for Fps {
+    type Output = Fps;
+
+    fn mul(self, rhs: ModInt998244353) -> Fps {
+        Fps {
+            coef: self.coef.into_iter().map(|c| c * rhs).collect(),
+        }
+    }
+}
+
+/// Implements the quotient of polynomial division. See `Fps::div_rem`.
+/// 多項式除算の商を実装する. `Fps::div_rem` を参照.
+impl Div for Fps {
+    type Output = Fps;
+
+    fn div(self, rhs: Fps) -> Fps {
+        self.div_rem(&rhs).0
+    }
+}
+
+/// Implements the remainder of polynomial division. See `Fps::div_rem`.
+/// 多項式除算の余りを実装する. `Fps::div_rem` を参照.
+impl Rem for Fps {
+    type Output = Fps;
+
+    fn rem(self, rhs: Fps) -> Fps {
+        self.div_rem(&rhs).1
+    }
+}
@@ -16,6 +16,7 @@
 /// - If `a = 0` and `b > 0`, returns `b`.
 /// - If `a > 0` and `b = 0`, returns `a`.
 /// - If `a > 0` and `b > 0`, returns the largest positive integer that divides both `a` and `b`.
+///
 /// `a` と `b` の最大公約数。
 /// 具体的には、非負整数 `a` と `b` に対して:
 /// - `a = 0` かつ `b = 0` の場合、`0` を返す。
@@ -64,6 +65,7 @@ pub fn gcd(mut a: u128, mut b: u128) -> u128 {
 /// Specifically:
 /// - Returns `None` if the LCM of `a` and `b` overflows the `u128` range.
 /// - Otherwise, returns `Some(lcm_value)`, where `lcm_value` is the calculated LCM.
+///
 /// `a` と `b` の最小公倍数 (`a` と `b` の非負な公倍数のうち、最小のもの) を `Option<u128>` で返す。
 /// 具体的には:
 /// - `a` と b の最小公倍数が `u128` の範囲を超過する場合、`None` を返す。
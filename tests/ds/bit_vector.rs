@@ -1,4 +1,5 @@
 use anmitsu::ds::bit_vector;
+use rand::{self, Rng};
 
 // Test: new_empty
 // Description: Tests creating an empty BitVector.
@@ -95,3 +96,90 @@ fn test_rank_at_block_boundaries() {
     assert_eq!(5, bv.rank(191)); // v[191] の前の rank(191)
     assert_eq!(6, bv.rank(192)); // v[191] を含む rank(192), 全長の合計
 }
+
+// Test: access_rank0
+// Description: Tests access() and rank0() against the input bits.
+#[test]
+fn test_access_rank0() {
+    let v = [1, 0, 1, 1, 0, 1, 0, 0];
+    let bv = bit_vector::BitVector::new(&v);
+    for (i, &b) in v.iter().enumerate() {
+        assert_eq!(b, bv.access(i));
+        assert_eq!(v[..i].iter().filter(|&&x| x == 0).count(), bv.rank0(i));
+    }
+    assert_eq!(4, bv.rank0(8));
+}
+
+// Test: panic_access_out_of_bounds
+// Description: Tests that BitVector::access panics if i >= len.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_panic_access_out_of_bounds() {
+    let bv = bit_vector::BitVector::new(&[1, 0, 1]);
+    bv.access(3);
+}
+
+// Test: select_at_block_boundaries
+// Description: Tests select1() and select0() around 64-bit block boundaries and past the last bit.
+#[test]
+fn test_select_at_block_boundaries() {
+    let mut v = vec![0; 192];
+    for i in [0, 63, 64, 127, 128, 191] {
+        v[i] = 1;
+    }
+
+    for bv in [
+        bit_vector::BitVector::new(&v),
+        bit_vector::BitVector::new_compact(&v),
+    ] {
+        assert_eq!(Some(0), bv.select1(0));
+        assert_eq!(Some(63), bv.select1(1));
+        assert_eq!(Some(64), bv.select1(2));
+        assert_eq!(Some(191), bv.select1(5));
+        assert_eq!(None, bv.select1(6));
+        assert_eq!(Some(1), bv.select0(0));
+        assert_eq!(Some(65), bv.select0(62));
+        assert_eq!(Some(190), bv.select0(185));
+        assert_eq!(None, bv.select0(186));
+    }
+
+    let empty = bit_vector::BitVector::new(&[]);
+    assert_eq!(None, empty.select1(0));
+    assert_eq!(None, empty.select0(0));
+}
+
+// Test: randomized_comparison
+// Description: Compares rank, rank0, select1 and select0 of both directories with naive results
+// on sparse, dense and balanced bitmaps spanning many superblocks and select hints.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    for (n, density) in [
+        (1, 0.5),
+        (700, 0.5),
+        (5000, 0.01),
+        (5000, 0.99),
+        (20000, 0.5),
+    ] {
+        let v: Vec<u8> = (0..n).map(|_| rng.random_bool(density) as u8).collect();
+        let flat = bit_vector::BitVector::new(&v);
+        let compact = bit_vector::BitVector::new_compact(&v);
+        let ones: Vec<usize> = (0..n).filter(|&i| v[i] == 1).collect();
+        let zeros: Vec<usize> = (0..n).filter(|&i| v[i] == 0).collect();
+
+        for r in 0..=n {
+            let rank = ones.partition_point(|&i| i < r);
+            assert_eq!(rank, flat.rank(r));
+            assert_eq!(rank, compact.rank(r));
+            assert_eq!(r - rank, compact.rank0(r));
+        }
+        for k in 0..=ones.len() {
+            assert_eq!(ones.get(k).copied(), flat.select1(k));
+            assert_eq!(ones.get(k).copied(), compact.select1(k));
+        }
+        for k in 0..=zeros.len() {
+            assert_eq!(zeros.get(k).copied(), flat.select0(k));
+            assert_eq!(zeros.get(k).copied(), compact.select0(k));
+        }
+    }
+}
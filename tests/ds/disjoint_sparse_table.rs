@@ -0,0 +1,78 @@
+use anmitsu::algebra::semi_group::{self, SemiGroup};
+use anmitsu::ds::disjoint_sparse_table::DisjointSparseTable;
+use rand::{self, Rng};
+
+// String concatenation, which is associative but neither commutative nor idempotent.
+struct ConcatSemiGroup;
+
+impl SemiGroup for ConcatSemiGroup {
+    type S = String;
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        format!("{}{}", a, b)
+    }
+}
+
+// Tests every range fold on addition against a naive sum.
+#[test]
+fn test_fold_add_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let v = (0..n)
+            .map(|_| rng.random_range(-100..=100))
+            .collect::<Vec<i64>>();
+        let dst = DisjointSparseTable::<semi_group::AddSemiGroup>::new(&v);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                // Act & Assert
+                assert_eq!(v[l..r].iter().sum::<i64>(), dst.fold(l, r));
+            }
+        }
+    }
+}
+
+// Tests that every range fold keeps the order of a non-commutative operation.
+#[test]
+fn test_fold_concat() {
+    for n in 1..=40 {
+        // Arrange
+        let v = (0..n)
+            .map(|i| char::from(b'a' + (i % 26) as u8).to_string())
+            .collect::<Vec<_>>();
+        let dst = DisjointSparseTable::<ConcatSemiGroup>::new(&v);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                // Act & Assert
+                assert_eq!(v[l..r].concat(), dst.fold(l, r));
+            }
+        }
+    }
+}
+
+// Tests the length of empty and non-empty tables.
+#[test]
+fn test_len() {
+    let empty = DisjointSparseTable::<semi_group::AddSemiGroup>::new(&[]);
+    let dst = DisjointSparseTable::<semi_group::AddSemiGroup>::new(&[1, 2, 3]);
+    assert!(empty.is_empty());
+    assert_eq!(3, dst.len());
+}
+
+// Tests that an empty range panics.
+#[test]
+#[should_panic(expected = "l must be less than r (l: 3, r: 1)")]
+fn test_fold_empty_range() {
+    DisjointSparseTable::<semi_group::AddSemiGroup>::new(&[1, 2, 3]).fold(3, 1);
+}
+
+// Tests that a range beyond the sequence panics.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 4, len: 3)"
+)]
+fn test_fold_out_of_bounds() {
+    DisjointSparseTable::<semi_group::AddSemiGroup>::new(&[1, 2, 3]).fold(0, 4);
+}
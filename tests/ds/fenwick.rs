@@ -0,0 +1,199 @@
+use anmitsu::algebra::monoid::{AddMonoid, XorMonoid};
+use anmitsu::ds::fenwick::{FenwickTree, FenwickTree2D, RangeAddFenwickTree};
+use rand::{self, Rng};
+
+// Tests random point updates and range sums against a naive array.
+#[test]
+fn test_add_sum_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let mut naive = (0..n)
+            .map(|_| rng.random_range(-100..=100))
+            .collect::<Vec<i64>>();
+        let mut ft = FenwickTree::<AddMonoid>::from_slice(&naive);
+
+        for _ in 0..50 {
+            // Act
+            let i = rng.random_range(0..n);
+            let x = rng.random_range(-100..=100);
+            ft.add(i, x);
+            naive[i] += x;
+
+            // Assert
+            for l in 0..=n {
+                for r in l..=n {
+                    assert_eq!(naive[l..r].iter().sum::<i64>(), ft.sum(l, r));
+                }
+            }
+        }
+    }
+}
+
+// Tests that `from_slice` agrees with repeated `add` on the xor group.
+#[test]
+fn test_from_slice_xor() {
+    let mut rng = rand::rng();
+    for n in 0..40 {
+        // Arrange
+        let v = (0..n).map(|_| rng.random::<u64>()).collect::<Vec<_>>();
+        let mut expected = FenwickTree::<XorMonoid>::new(n);
+        for (i, &x) in v.iter().enumerate() {
+            expected.add(i, x);
+        }
+
+        // Act
+        let ft = FenwickTree::<XorMonoid>::from_slice(&v);
+
+        // Assert
+        assert_eq!(n, ft.len());
+        for r in 0..=n {
+            assert_eq!(expected.prefix(r), ft.prefix(r));
+            assert_eq!(v[..r].iter().fold(0, |a, &b| a ^ b), ft.prefix(r));
+        }
+    }
+}
+
+// Tests `lower_bound` against a linear scan over non-negative elements.
+#[test]
+fn test_lower_bound_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(0..=30);
+        let v = (0..n)
+            .map(|_| rng.random_range(0..=5))
+            .collect::<Vec<i64>>();
+        let ft = FenwickTree::<AddMonoid>::from_slice(&v);
+        let total = v.iter().sum::<i64>();
+
+        for target in -1..=total + 1 {
+            // Act
+            let result = ft.lower_bound(&target);
+
+            // Assert
+            let expected = (0..=n).find(|&r| v[..r].iter().sum::<i64>() >= target);
+            assert_eq!(expected, result);
+        }
+    }
+}
+
+// Tests random range additions and range sums against a naive array.
+#[test]
+fn test_range_add_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let mut naive = vec![0i64; n];
+        let mut ft = RangeAddFenwickTree::<AddMonoid>::new(n);
+
+        for _ in 0..50 {
+            // Act
+            let l = rng.random_range(0..=n);
+            let r = rng.random_range(l..=n);
+            let x = rng.random_range(-100..=100);
+            ft.add(l, r, x);
+            naive[l..r].iter_mut().for_each(|y| *y += x);
+
+            // Assert
+            assert_eq!(n, ft.len());
+            for l in 0..=n {
+                for r in l..=n {
+                    assert_eq!(naive[l..r].iter().sum::<i64>(), ft.sum(l, r));
+                }
+            }
+        }
+    }
+}
+
+// Tests range additions on the xor group, where an even number of copies cancels out.
+#[test]
+fn test_range_add_xor() {
+    // Arrange
+    let mut ft = RangeAddFenwickTree::<XorMonoid>::new(6);
+
+    // Act
+    ft.add(0, 4, 0b01);
+    ft.add(1, 6, 0b10);
+
+    // Assert
+    // The elements are [1, 3, 3, 3, 2, 2].
+    assert_eq!(0b01, ft.sum(0, 1));
+    assert_eq!(0b00, ft.sum(1, 3));
+    assert_eq!(0b01, ft.sum(0, 3));
+    assert_eq!(0b11, ft.sum(3, 6));
+    assert_eq!(0b10, ft.sum(0, 6));
+}
+
+// Tests random point updates and rectangle sums against a naive grid.
+#[test]
+fn test_2d_random() {
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        // Arrange
+        let h = rng.random_range(1..=8);
+        let w = rng.random_range(1..=8);
+        let mut naive = vec![vec![0i64; w]; h];
+        let mut ft = FenwickTree2D::<AddMonoid>::new(h, w);
+
+        for _ in 0..30 {
+            // Act
+            let i = rng.random_range(0..h);
+            let j = rng.random_range(0..w);
+            let x = rng.random_range(-100..=100);
+            ft.add(i, j, x);
+            naive[i][j] += x;
+
+            // Assert
+            let i1 = rng.random_range(0..=h);
+            let i2 = rng.random_range(0..=h);
+            let j1 = rng.random_range(0..=w);
+            let j2 = rng.random_range(0..=w);
+            let expected = naive
+                .iter()
+                .take(i2)
+                .skip(i1)
+                .map(|row| row.iter().take(j2).skip(j1).sum::<i64>())
+                .sum::<i64>();
+            assert_eq!(expected, ft.sum(i1, j1, i2, j2));
+        }
+    }
+}
+
+// Tests that `add` panics on an index out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_add_out_of_bounds() {
+    let mut ft = FenwickTree::<AddMonoid>::new(3);
+    ft.add(3, 1);
+}
+
+// Tests that `prefix` panics when `r` exceeds the length.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 4, len: 3)"
+)]
+fn test_prefix_out_of_bounds() {
+    let ft = FenwickTree::<AddMonoid>::new(3);
+    ft.prefix(4);
+}
+
+// Tests that the range-add variant panics when `r` exceeds the length.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 4, len: 3)"
+)]
+fn test_range_add_out_of_bounds() {
+    let mut ft = RangeAddFenwickTree::<AddMonoid>::new(3);
+    ft.add(1, 4, 1);
+}
+
+// Tests that the 2D tree panics on a column out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_2d_add_out_of_bounds() {
+    let mut ft = FenwickTree2D::<AddMonoid>::new(3, 2);
+    ft.add(0, 2, 1);
+}
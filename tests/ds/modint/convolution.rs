@@ -0,0 +1,138 @@
+use anmitsu::ds::modint::convolution;
+use rand::{self, Rng};
+
+// Naive convolution computed exactly in `i128`.
+fn naive_i128(a: &[i128], b: &[i128]) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![0_i128; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            res[i + j] += x * y;
+        }
+    }
+    res
+}
+
+#[test]
+fn convolution_mod_with_empty_input_returns_empty() {
+    // Arrange
+    let a = Vec::<u32>::new();
+    let b = vec![1, 2, 3];
+
+    // Act & Assert
+    assert!(convolution::convolution_mod(&a, &b, 1_000_000_007).is_empty());
+    assert!(convolution::convolution_u64(&[], &[1]).is_empty());
+    assert!(convolution::convolution_i128(&[1], &[]).is_empty());
+}
+
+#[test]
+fn convolution_mod_matches_naive_result_for_various_moduli() {
+    // Arrange
+    let mut rng = rand::rng();
+    for &m in &[1, 2, 1_000_000_007, 998244353, (1 << 31) - 1, 1 << 31] {
+        for &(n, k) in &[(1, 1), (5, 40), (100, 100), (257, 3)] {
+            let a = (0..n).map(|_| rng.random::<u32>()).collect::<Vec<_>>();
+            let b = (0..k).map(|_| rng.random::<u32>()).collect::<Vec<_>>();
+            let expected = naive_i128(
+                &a.iter().map(|&x| (x % m) as i128).collect::<Vec<_>>(),
+                &b.iter().map(|&x| (x % m) as i128).collect::<Vec<_>>(),
+            )
+            .into_iter()
+            .map(|x| (x % m as i128) as u32)
+            .collect::<Vec<_>>();
+
+            // Act
+            let result = convolution::convolution_mod(&a, &b, m);
+
+            // Assert
+            assert_eq!(
+                expected, result,
+                "Failed for m = {}, n = {}, k = {}",
+                m, n, k
+            );
+        }
+    }
+}
+
+#[test]
+fn convolution_u64_matches_naive_result() {
+    // Arrange
+    let mut rng = rand::rng();
+    let a = (0..300)
+        .map(|_| rng.random_range(0..1_u64 << 27))
+        .collect::<Vec<_>>();
+    let b = (0..200)
+        .map(|_| rng.random_range(0..1_u64 << 28))
+        .collect::<Vec<_>>();
+    let expected = naive_i128(
+        &a.iter().map(|&x| x as i128).collect::<Vec<_>>(),
+        &b.iter().map(|&x| x as i128).collect::<Vec<_>>(),
+    )
+    .into_iter()
+    .map(|x| x as u64)
+    .collect::<Vec<_>>();
+
+    // Act
+    let result = convolution::convolution_u64(&a, &b);
+
+    // Assert
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn convolution_i128_matches_naive_result_with_negative_values() {
+    // Arrange
+    let mut rng = rand::rng();
+    let bound = 1_i64 << 38;
+    let a = (0..150)
+        .map(|_| rng.random_range(-bound..bound))
+        .collect::<Vec<_>>();
+    let b = (0..70)
+        .map(|_| rng.random_range(-bound..bound))
+        .collect::<Vec<_>>();
+    let expected = naive_i128(
+        &a.iter().map(|&x| x as i128).collect::<Vec<_>>(),
+        &b.iter().map(|&x| x as i128).collect::<Vec<_>>(),
+    );
+
+    // Act
+    let result = convolution::convolution_i128(&a, &b);
+
+    // Assert
+    assert_eq!(expected, result);
+}
+
+#[test]
+#[should_panic(expected = "overflows u64")]
+fn convolution_u64_panics_on_overflow() {
+    // Arrange
+    let a = vec![1_u64 << 40; 40];
+    let b = vec![1_u64 << 30; 40];
+
+    // Act, Assert (panic)
+    let _ = convolution::convolution_u64(&a, &b);
+}
+
+#[test]
+#[should_panic(expected = "overflows u64")]
+fn convolution_u64_panics_just_above_u64_max() {
+    // Arrange
+    let a = vec![u64::MAX, 1];
+    let b = vec![2];
+
+    // Act, Assert (panic)
+    let _ = convolution::convolution_u64(&a, &b);
+}
+
+#[test]
+#[should_panic(expected = "Convolution length")]
+fn convolution_mod_panics_when_length_exceeds_limit() {
+    // Arrange
+    let a = vec![1u32; convolution::MAX_NTT_LEN];
+    let b = vec![1u32; 64];
+
+    // Act, Assert (panic)
+    let _ = convolution::convolution_mod(&a, &b, 7);
+}
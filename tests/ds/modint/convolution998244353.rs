@@ -1,4 +1,5 @@
-use anmitsu::ds::modint::convolution998244353;
+use anmitsu::ds::modint::{convolution998244353, modint998244353::ModInt998244353};
+use rand::{self, Rng};
 
 #[test]
 fn convolution_with_empty_input_returns_empty() {
@@ -82,3 +83,93 @@ fn convolution_panics_when_length_exceeds_limit() {
     // Act, Assert (panic)
     let _ = convolution998244353::convolution(&a, &b);
 }
+
+#[test]
+fn convolution_modint_with_empty_input_returns_empty() {
+    // Arrange
+    let a = Vec::<ModInt998244353>::new();
+    let b = vec![ModInt998244353::new(1); 40];
+
+    // Act & Assert
+    assert!(convolution998244353::convolution_modint(&a, &b).is_empty());
+    assert!(convolution998244353::convolution_modint(&b, &a).is_empty());
+    assert!(convolution998244353::convolution_naive_modint(&a, &b).is_empty());
+}
+
+#[test]
+fn convolution_modint_matches_u32_convolution() {
+    // Arrange
+    let mut rng = rand::rng();
+    for (n, m) in [(1, 1), (3, 50), (33, 33), (100, 257), (1000, 1000)] {
+        let a: Vec<u32> = (0..n)
+            .map(|_| rng.random_range(0..convolution998244353::MOD))
+            .collect();
+        let b: Vec<u32> = (0..m)
+            .map(|_| rng.random_range(0..convolution998244353::MOD))
+            .collect();
+        let ma: Vec<_> = a.iter().map(|&x| ModInt998244353::new_raw(x)).collect();
+        let mb: Vec<_> = b.iter().map(|&x| ModInt998244353::new_raw(x)).collect();
+
+        // Act
+        let result = convolution998244353::convolution_modint(&ma, &mb);
+
+        // Assert
+        let expected = convolution998244353::convolution(&a, &b);
+        let actual: Vec<u32> = result.iter().map(|x| x.val()).collect();
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn convolution_naive_modint_matches_convolution_modint() {
+    // Arrange
+    let mut rng = rand::rng();
+    let a: Vec<_> = (0..70)
+        .map(|_| ModInt998244353::new(rng.random_range(0..u64::MAX)))
+        .collect();
+    let b: Vec<_> = (0..90)
+        .map(|_| ModInt998244353::new(rng.random_range(0..u64::MAX)))
+        .collect();
+
+    // Act
+    let naive = convolution998244353::convolution_naive_modint(&a, &b);
+    let fast = convolution998244353::convolution_modint(&a, &b);
+
+    // Assert
+    assert_eq!(naive, fast);
+}
+
+#[test]
+fn ntt_modint_matches_u32_ntt_and_round_trips() {
+    // Arrange
+    let mut rng = rand::rng();
+    let raw: Vec<u32> = (0..256)
+        .map(|_| rng.random_range(0..convolution998244353::MOD))
+        .collect();
+    let original: Vec<_> = raw.iter().map(|&x| ModInt998244353::new_raw(x)).collect();
+    let mut values = original.clone();
+    let mut expected = raw.clone();
+
+    // Act
+    convolution998244353::ntt_modint(&mut values);
+    convolution998244353::ntt(&mut expected);
+
+    // Assert
+    let actual: Vec<u32> = values.iter().map(|x| x.val()).collect();
+    assert_eq!(expected, actual);
+
+    // Act
+    convolution998244353::intt_modint(&mut values);
+    let inv_len = ModInt998244353::new(256).inv().unwrap();
+    values.iter_mut().for_each(|x| *x *= inv_len);
+
+    // Assert
+    assert_eq!(original, values);
+}
+
+#[test]
+#[should_panic(expected = "NTT length 3 is not a power of two")]
+fn ntt_modint_panics_when_length_is_not_power_of_two() {
+    let mut values = vec![ModInt998244353::new(1); 3];
+    convolution998244353::ntt_modint(&mut values);
+}
@@ -0,0 +1,195 @@
+// Integration tests run in parallel within one process, so each test uses its own id
+// to avoid racing on the global modulus.
+use anmitsu::ds::modint::{
+    dynamic_modint::{Barrett, DynamicModInt},
+    static_modint::StaticModInt,
+};
+use rand::{self, Rng};
+
+// Tests that every id starts with the default modulus 998244353.
+#[test]
+fn default_modulus_is_998244353() {
+    assert_eq!(998244353, DynamicModInt::<0>::modulus());
+    assert_eq!(1755647, DynamicModInt::<0>::new(1_000_000_000).val());
+}
+
+// Tests that different ids keep independent moduli.
+#[test]
+fn ids_have_independent_moduli() {
+    // Arrange
+    DynamicModInt::<1>::set_modulus(7);
+    DynamicModInt::<2>::set_modulus(11);
+
+    // Act
+    let a = DynamicModInt::<1>::new(20);
+    let b = DynamicModInt::<2>::new(20);
+
+    // Assert
+    assert_eq!(6, a.val());
+    assert_eq!(9, b.val());
+    assert_eq!(7, DynamicModInt::<1>::modulus());
+    assert_eq!(11, DynamicModInt::<2>::modulus());
+}
+
+// Tests the full operator set, including mixed `u32` operands and `Display`.
+#[test]
+fn operators_match_expected_values() {
+    // Arrange
+    type Mint = DynamicModInt<3>;
+    Mint::set_modulus(1_000_000_007);
+    let a = Mint::new(1_000_000_000);
+    let b = Mint::new(10);
+
+    // Act & Assert
+    assert_eq!(3, (a + b).val());
+    assert_eq!(999_999_990, (a - b).val());
+    assert_eq!(999_999_937, (a * b).val());
+    assert_eq!(a, (a / b) * b);
+    assert_eq!(1_000_000_000, (-Mint::new(7)).val());
+    assert_eq!(0, (-Mint::new(0)).val());
+    assert_eq!(1024, Mint::new(2).pow(10).val());
+    assert_eq!(3, (a + 10u32).val());
+    assert_eq!(999_999_990, (a - 10u32).val());
+    assert_eq!(999_999_937, (a * 10u32).val());
+    assert_eq!(a, (a / 10u32) * 10u32);
+
+    let mut c = a;
+    c += b;
+    c -= 1u32;
+    c *= b;
+    c /= 2u32;
+    c += 1u32;
+    c -= b;
+    c *= 3u32;
+    c /= b;
+    assert_eq!((((a + b - 1u32) * b / 2u32 + 1u32 - b) * 3u32) / b, c);
+
+    let m: Mint = (-1i32).into();
+    assert_eq!(1_000_000_006, m.val());
+    let m: Mint = u32::MAX.into();
+    assert_eq!(u32::MAX % 1_000_000_007, m.val());
+    assert_eq!("1000000000", format!("{}", a));
+}
+
+// Tests that `inv` works for a composite modulus.
+#[test]
+fn inv_with_composite_modulus_returns_none_for_non_coprime() {
+    // Arrange
+    type Mint = DynamicModInt<4>;
+    Mint::set_modulus(12);
+
+    for x in 0..12u64 {
+        // Act
+        let inv = Mint::new(x).inv();
+
+        // Assert
+        assert_eq!(
+            [1, 5, 7, 11].contains(&x),
+            inv.is_some(),
+            "Failed for {}",
+            x
+        );
+        if let Some(inv) = inv {
+            assert_eq!(1, (Mint::new(x) * inv).val());
+        }
+    }
+}
+
+// Compares Barrett multiplication with `StaticModInt` on random inputs.
+#[test]
+fn mul_matches_static_modint() {
+    // Arrange
+    type Mint = DynamicModInt<5>;
+    let mut rng = rand::rng();
+
+    fn check<const M: u32>(rng: &mut impl Rng) {
+        Mint::set_modulus(M);
+        for _ in 0..10000 {
+            let a = rng.random_range(0..M as u64 * 3);
+            let b = rng.random_range(0..M as u64 * 3);
+            // Act & Assert
+            assert_eq!(
+                (StaticModInt::<M>::new(a) * StaticModInt::<M>::new(b)).val(),
+                (Mint::new(a) * Mint::new(b)).val(),
+                "Failed for {} * {} mod {}",
+                a,
+                b,
+                M
+            );
+        }
+    }
+
+    check::<1>(&mut rng);
+    check::<2>(&mut rng);
+    check::<998244353>(&mut rng);
+    check::<1_000_000_007>(&mut rng);
+    check::<2_147_483_647>(&mut rng);
+}
+
+// Tests `Barrett` as an explicit context.
+#[test]
+fn barrett_context_multiplies_correctly() {
+    // Arrange
+    let bt = Barrett::new(2_147_483_647);
+
+    // Act & Assert
+    assert_eq!(2_147_483_647, bt.modulus());
+    assert_eq!(1, bt.mul(2_147_483_646, 2_147_483_646));
+    assert_eq!(0, bt.mul(0, 2_147_483_646));
+}
+
+// Tests that `set_modulus` rejects a modulus outside of `[1, 2^31)`.
+#[test]
+#[should_panic(expected = "The modulus must be in [1, 2^31)")]
+fn set_modulus_with_zero_panics() {
+    DynamicModInt::<6>::set_modulus(0);
+}
+
+// Tests that dividing by zero panics.
+#[test]
+#[should_panic(expected = "Division by zero is not allowed for ModInt13")]
+fn div_by_zero_panics() {
+    DynamicModInt::<7>::set_modulus(13);
+    let _ = DynamicModInt::<7>::new(3) / DynamicModInt::<7>::new(13);
+}
+
+// Tests that multiplication racing with `set_modulus` always uses a consistent modulus.
+#[test]
+fn set_modulus_racing_with_mul_uses_consistent_modulus() {
+    // Arrange
+    type Mint = DynamicModInt<8>;
+    const M1: u64 = 998244353;
+    const M2: u64 = 1_000_000_007;
+    Mint::set_modulus(M1 as u32);
+    let stop = std::sync::atomic::AtomicBool::new(false);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let mut flip = false;
+            while !stop.load(std::sync::atomic::Ordering::Relaxed) {
+                Mint::set_modulus(if flip { M1 } else { M2 } as u32);
+                flip = !flip;
+            }
+        });
+
+        let mut rng = rand::rng();
+        for _ in 0..200000 {
+            // Both operands are below either modulus, so only the product depends on it.
+            let a = rng.random_range(0..M1);
+            let b = rng.random_range(0..M1);
+
+            // Act
+            let c = (Mint::new(a) * Mint::new(b)).val() as u64;
+
+            // Assert
+            assert!(
+                c == a * b % M1 || c == a * b % M2,
+                "Failed for {} * {}: got {}",
+                a,
+                b,
+                c
+            );
+        }
+        stop.store(true, std::sync::atomic::Ordering::Relaxed);
+    });
+}
@@ -425,6 +425,7 @@ fn equality_and_inequality_work_correctly() {
 
 // Tests `Copy` and `Clone` implementations.
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn copy_and_clone_work_correctly() {
     // Arrange
     let m1 = ModInt998244353::new(12345);
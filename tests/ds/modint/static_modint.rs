@@ -0,0 +1,115 @@
+use anmitsu::ds::modint::static_modint::StaticModInt;
+
+type Mint1e9 = StaticModInt<1_000_000_007>;
+type Mint12 = StaticModInt<12>;
+type MintLarge = StaticModInt<4_294_967_291>; // The largest prime below 2^32.
+
+// Tests that `modulus` returns the const generic parameter.
+#[test]
+fn modulus_returns_const_parameter() {
+    assert_eq!(1_000_000_007, Mint1e9::modulus());
+    assert_eq!(12, Mint12::modulus());
+}
+
+// Tests basic arithmetic modulo 1_000_000_007.
+#[test]
+fn arithmetic_with_prime_modulus_works() {
+    // Arrange
+    let a = Mint1e9::new(1_000_000_000);
+    let b = Mint1e9::new(10);
+
+    // Act & Assert
+    assert_eq!(3, (a + b).val());
+    assert_eq!(999_999_990, (a - b).val());
+    assert_eq!(999_999_937, (a * b).val()); // 10^10 mod (10^9 + 7)
+    assert_eq!(a, (a / b) * b);
+    assert_eq!(Mint1e9::new(1_000_000_007 - 7), -Mint1e9::new(7));
+    assert_eq!(Mint1e9::new(1024), Mint1e9::new(2).pow(10));
+}
+
+// Tests mixed `u32` operands and compound assignments.
+#[test]
+fn mixed_u32_operands_work() {
+    // Arrange
+    let mut a = Mint1e9::new(5);
+
+    // Act & Assert
+    a += 1_000_000_005u32;
+    assert_eq!(3, a.val());
+    a -= 4u32;
+    assert_eq!(1_000_000_006, a.val());
+    a *= 2u32;
+    assert_eq!(1_000_000_005, a.val());
+    a /= 2u32;
+    assert_eq!(1_000_000_006, a.val());
+    assert_eq!(2, (a + 3u32).val());
+}
+
+// Tests `From<i32>` with negative values.
+#[test]
+fn from_i32_handles_negative_values() {
+    let m: Mint1e9 = (-1i32).into();
+    assert_eq!(1_000_000_006, m.val());
+    let m: Mint12 = (-25i32).into();
+    assert_eq!(11, m.val());
+    let m: MintLarge = i32::MIN.into();
+    assert_eq!((4_294_967_291_i64 + i32::MIN as i64) as u32, m.val());
+}
+
+// Tests that `inv` returns `None` exactly for non-invertible elements of a composite modulus.
+#[test]
+fn inv_with_composite_modulus_returns_none_for_non_coprime() {
+    for x in 0..12u64 {
+        // Arrange
+        let m = Mint12::new(x);
+
+        // Act
+        let inv = m.inv();
+
+        // Assert
+        let coprime = [1, 5, 7, 11].contains(&x);
+        assert_eq!(coprime, inv.is_some(), "Failed for {}", x);
+        if let Some(inv) = inv {
+            assert_eq!(1, (m * inv).val(), "Failed for {}", x);
+        }
+    }
+}
+
+// Tests that arithmetic does not overflow for a modulus close to `u32::MAX`.
+#[test]
+fn arithmetic_with_large_modulus_does_not_overflow() {
+    // Arrange
+    let a = MintLarge::new(4_294_967_290);
+    let b = MintLarge::new(4_294_967_289);
+
+    // Act & Assert
+    assert_eq!(4_294_967_288, (a + b).val());
+    assert_eq!(1, (a - b).val());
+    assert_eq!(2, (a * b).val()); // (-1) * (-2)
+    assert_eq!(1, (a * a.inv().unwrap()).val());
+    assert_eq!(3, (a + u32::MAX).val()); // (-1) + 4
+    assert_eq!(4_294_967_285, (b - u32::MAX).val()); // (-2) - 4
+}
+
+// Tests that modulus 1 maps everything to zero.
+#[test]
+fn modulus_one_maps_everything_to_zero() {
+    let a = StaticModInt::<1>::new(123);
+    assert_eq!(0, a.val());
+    assert_eq!(0, a.pow(5).val());
+    assert_eq!(0, a.inv().unwrap().val());
+}
+
+// Tests that dividing by a non-invertible element panics.
+#[test]
+#[should_panic(expected = "Division by 4 is not allowed for ModInt12 since it is not invertible")]
+fn div_by_non_invertible_panics() {
+    let _ = Mint12::new(3) / Mint12::new(4);
+}
+
+// Tests that dividing by zero panics.
+#[test]
+#[should_panic(expected = "Division by zero is not allowed for ModInt1000000007")]
+fn div_by_zero_panics() {
+    let _ = Mint1e9::new(3) / 0u32;
+}
@@ -0,0 +1,86 @@
+use anmitsu::ds::{
+    offline_dynamic_connectivity::OfflineDynamicConnectivity, union_find::UnionFind,
+};
+use rand::{self, Rng};
+
+// Test: no_queries
+// Description: Tests that solving without queries returns no answers.
+#[test]
+fn test_no_queries() {
+    let mut dc = OfflineDynamicConnectivity::new(2);
+    dc.add_edge(0, 1);
+    assert!(dc.solve().is_empty());
+}
+
+// Test: parallel_edges_and_self_loops
+// Description: Tests that a pair stays connected while any parallel copy of the edge remains.
+#[test]
+fn test_parallel_edges_and_self_loops() {
+    let mut dc = OfflineDynamicConnectivity::new(3);
+    dc.add_edge(0, 1);
+    dc.add_edge(1, 0);
+    dc.add_edge(2, 2);
+    assert_eq!(0, dc.query(0, 1));
+    dc.remove_edge(0, 1);
+    dc.query(0, 1);
+    dc.remove_edge(0, 1);
+    dc.query(1, 0);
+    dc.query(2, 2);
+    assert_eq!(vec![true, true, false, true], dc.solve());
+}
+
+// Test: panic_remove_missing_edge
+// Description: Tests that removing an edge that does not exist panics.
+#[test]
+#[should_panic(expected = "Edge (1, 2) does not exist")]
+fn test_panic_remove_missing_edge() {
+    let mut dc = OfflineDynamicConnectivity::new(3);
+    dc.add_edge(0, 1);
+    dc.remove_edge(1, 2);
+}
+
+// Test: panic_out_of_bounds
+// Description: Tests that an out-of-bounds vertex panics.
+#[test]
+#[should_panic(expected = "Index out of bounds for edge: u=0, v=3, len=3")]
+fn test_panic_out_of_bounds() {
+    let mut dc = OfflineDynamicConnectivity::new(3);
+    dc.query(0, 3);
+}
+
+// Test: randomized_comparison
+// Description: Compares the answers with rebuilding a UnionFind from the current edges at each query.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    for n in [1, 5, 15] {
+        let mut dc = OfflineDynamicConnectivity::new(n);
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut expected = Vec::new();
+
+        for _ in 0..300 {
+            match rng.random_range(0..3) {
+                0 => {
+                    let (u, v) = (rng.random_range(0..n), rng.random_range(0..n));
+                    dc.add_edge(u, v);
+                    edges.push((u, v));
+                }
+                1 if !edges.is_empty() => {
+                    let (u, v) = edges.swap_remove(rng.random_range(0..edges.len()));
+                    dc.remove_edge(v, u);
+                }
+                _ => {
+                    let (u, v) = (rng.random_range(0..n), rng.random_range(0..n));
+                    dc.query(u, v);
+                    let mut uf = UnionFind::new(n);
+                    for &(a, b) in edges.iter() {
+                        uf.union(a, b);
+                    }
+                    expected.push(uf.is_same(u, v));
+                }
+            }
+        }
+
+        assert_eq!(expected, dc.solve());
+    }
+}
@@ -0,0 +1,89 @@
+use anmitsu::ds::{persistent_union_find::PersistentUnionFind, union_find::UnionFind};
+use rand::{self, Rng};
+
+// Test: new_len_and_singletons
+// Description: Verifies initial length, time, roots and sizes.
+#[test]
+fn test_new_len_and_singletons() {
+    let uf = PersistentUnionFind::new(3);
+    assert_eq!(3, uf.len());
+    assert!(!uf.is_empty());
+    assert!(PersistentUnionFind::new(0).is_empty());
+    assert_eq!(0, uf.now());
+    for i in 0..3 {
+        assert_eq!(i, uf.find(i));
+        assert_eq!(1, uf.get_size(i));
+        assert_eq!(Some(0), uf.first_connected_time(i, i));
+    }
+    assert_eq!(None, uf.first_connected_time(0, 1));
+}
+
+// Test: queries_on_past_states
+// Description: Tests queries at each time, including no-op unions and times after now.
+#[test]
+fn test_queries_on_past_states() {
+    let mut uf = PersistentUnionFind::new(5);
+    assert!(uf.union(0, 1)); // t = 1
+    assert!(uf.union(2, 3)); // t = 2
+    assert!(!uf.union(1, 0)); // t = 3
+    assert!(uf.union(3, 0)); // t = 4
+    assert_eq!(4, uf.now());
+
+    assert!(!uf.is_same_at(0, 1, 0));
+    assert!(uf.is_same_at(0, 1, 1));
+    assert!(!uf.is_same_at(1, 2, 3));
+    assert!(uf.is_same_at(1, 2, 4));
+    assert!(uf.is_same_at(1, 2, 100));
+    assert!(uf.is_same(0, 3));
+    assert!(!uf.is_same(0, 4));
+
+    assert_eq!(2, uf.get_size_at(3, 3));
+    assert_eq!(4, uf.get_size_at(3, 4));
+    assert_eq!(4, uf.get_size(1));
+    assert_eq!(1, uf.get_size_at(4, 4));
+
+    assert_eq!(Some(1), uf.first_connected_time(0, 1));
+    assert_eq!(Some(2), uf.first_connected_time(3, 2));
+    assert_eq!(Some(4), uf.first_connected_time(1, 2));
+    assert_eq!(None, uf.first_connected_time(1, 4));
+}
+
+// Test: panic_find_out_of_bounds
+// Description: Tests that find_at panics on an out-of-bounds index.
+#[test]
+#[should_panic(expected = "Index 3 is out of bounds for PersistentUnionFind with size 3")]
+fn test_panic_find_out_of_bounds() {
+    let uf = PersistentUnionFind::new(3);
+    uf.find_at(3, 0);
+}
+
+// Test: randomized_comparison
+// Description: Compares every past state with a UnionFind snapshot taken at that time.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    let n = 15;
+    let mut uf = PersistentUnionFind::new(n);
+    let mut snapshot = UnionFind::new(n);
+    let mut snapshots = vec![snapshot.clone()];
+    for _ in 0..40 {
+        let (x, y) = (rng.random_range(0..n), rng.random_range(0..n));
+        uf.union(x, y);
+        snapshot.union(x, y);
+        snapshots.push(snapshot.clone());
+    }
+
+    for x in 0..n {
+        for y in 0..n {
+            let mut first = None;
+            for (t, expected) in snapshots.iter_mut().enumerate() {
+                assert_eq!(expected.is_same(x, y), uf.is_same_at(x, y, t));
+                assert_eq!(expected.get_size(x), uf.get_size_at(x, t));
+                if first.is_none() && expected.is_same(x, y) {
+                    first = Some(t);
+                }
+            }
+            assert_eq!(first, uf.first_connected_time(x, y));
+        }
+    }
+}
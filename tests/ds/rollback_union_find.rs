@@ -0,0 +1,105 @@
+use anmitsu::ds::{rollback_union_find::RollbackUnionFind, union_find::UnionFind};
+use rand::{self, Rng};
+
+// Test: new_len_and_singletons
+// Description: Verifies initial length, roots, sizes and an empty history.
+#[test]
+fn test_new_len_and_singletons() {
+    let uf = RollbackUnionFind::new(3);
+    assert_eq!(3, uf.len());
+    assert!(!uf.is_empty());
+    assert!(RollbackUnionFind::new(0).is_empty());
+    assert_eq!(0, uf.history_len());
+    for i in 0..3 {
+        assert_eq!(i, uf.find(i));
+        assert_eq!(1, uf.get_size(i));
+    }
+}
+
+// Test: union_undo_and_rollback
+// Description: Tests that undo and rollback restore connectivity and sizes, including no-op unions.
+#[test]
+fn test_union_undo_and_rollback() {
+    let mut uf = RollbackUnionFind::new(5);
+    assert!(uf.union(0, 1));
+    let snapshot = uf.snapshot();
+    assert!(uf.union(2, 3));
+    assert!(uf.union(1, 3));
+    assert!(!uf.union(0, 2));
+    assert_eq!(4, uf.history_len());
+    assert_eq!(4, uf.get_size(2));
+
+    // Undo the no-op union, then the merge of {0, 1} and {2, 3}.
+    assert!(uf.undo());
+    assert!(uf.is_same(0, 3));
+    assert!(uf.undo());
+    assert!(!uf.is_same(0, 3));
+    assert!(uf.is_same(2, 3));
+    assert_eq!(2, uf.get_size(0));
+
+    uf.rollback(snapshot);
+    assert_eq!(1, uf.history_len());
+    assert!(uf.is_same(0, 1));
+    assert!(!uf.is_same(2, 3));
+    assert_eq!(1, uf.get_size(3));
+
+    uf.rollback(0);
+    assert!(!uf.is_same(0, 1));
+    assert!(!uf.undo());
+}
+
+// Test: panic_find_out_of_bounds
+// Description: Tests that find panics on an out-of-bounds index.
+#[test]
+#[should_panic(expected = "Index 3 is out of bounds for RollbackUnionFind with size 3")]
+fn test_panic_find_out_of_bounds() {
+    let uf = RollbackUnionFind::new(3);
+    uf.find(3);
+}
+
+// Test: panic_rollback_to_future
+// Description: Tests that rollback panics when the target is beyond the history.
+#[test]
+#[should_panic(expected = "Cannot roll back to 2 since the history length is 1")]
+fn test_panic_rollback_to_future() {
+    let mut uf = RollbackUnionFind::new(3);
+    uf.union(0, 1);
+    uf.rollback(2);
+}
+
+// Test: randomized_comparison
+// Description: Compares with a UnionFind rebuilt from the surviving unions after random rollbacks.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    let n = 20;
+    let mut uf = RollbackUnionFind::new(n);
+    let mut unions = Vec::new();
+
+    for _ in 0..500 {
+        match rng.random_range(0..4) {
+            0 | 1 => {
+                let (x, y) = (rng.random_range(0..n), rng.random_range(0..n));
+                uf.union(x, y);
+                unions.push((x, y));
+            }
+            2 => {
+                let to = rng.random_range(0..=unions.len());
+                uf.rollback(to);
+                unions.truncate(to);
+            }
+            _ => {
+                let mut expected = UnionFind::new(n);
+                for &(x, y) in unions.iter() {
+                    expected.union(x, y);
+                }
+                for x in 0..n {
+                    assert_eq!(expected.get_size(x), uf.get_size(x));
+                    for y in 0..n {
+                        assert_eq!(expected.is_same(x, y), uf.is_same(x, y));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,460 @@
+// tests/ds/segment_tree/lazy_segment_tree.rs
+use anmitsu::{
+    algebra::{
+        action::{Action, AddAction, AssignAction},
+        monoid::{self, AddWithLenMonoid, MaxMonoid, MinMonoid, Monoid},
+        semi_group,
+    },
+    ds::segment_tree::lazy_segment_tree::LazySegmentTree,
+};
+use rand::{self, Rng};
+
+const P: i64 = 998244353;
+
+// A monoid of (sum, len) modulo P.
+struct SumLenModMonoid;
+
+impl semi_group::SemiGroup for SumLenModMonoid {
+    type S = (i64, i64);
+    fn op(a: &Self::S, b: &Self::S) -> Self::S {
+        ((a.0 + b.0) % P, a.1 + b.1)
+    }
+}
+
+impl monoid::Monoid for SumLenModMonoid {
+    fn id() -> Self::S {
+        (0, 0)
+    }
+}
+
+// An affine map x -> a * x + b modulo P. Composition is non-commutative,
+// so it checks that `op(f, g)` is applied as "f first, then g".
+struct AffineAction;
+
+impl semi_group::SemiGroup for AffineAction {
+    type S = (i64, i64);
+    fn op(f: &Self::S, g: &Self::S) -> Self::S {
+        (g.0 * f.0 % P, (g.0 * f.1 + g.1) % P)
+    }
+}
+
+impl monoid::Monoid for AffineAction {
+    fn id() -> Self::S {
+        (1, 0)
+    }
+}
+
+impl Action<SumLenModMonoid> for AffineAction {
+    fn act(f: &Self::S, x: &(i64, i64)) -> (i64, i64) {
+        ((f.0 * x.0 + f.1 * x.1) % P, x.1)
+    }
+}
+
+/// A naive model of `LazySegmentTree` that applies every map element by element.
+/// 写像を要素ごとに作用させる `LazySegmentTree` の愚直なモデルである.
+struct NaiveLazySegmentTree<M>
+where
+    M: Monoid,
+{
+    data: Vec<M::S>,
+}
+
+impl<M> NaiveLazySegmentTree<M>
+where
+    M: Monoid,
+    M::S: Clone,
+{
+    fn new(n: usize) -> Self {
+        Self {
+            data: vec![M::id(); n],
+        }
+    }
+
+    fn set(&mut self, idx: usize, x: M::S) {
+        self.data[idx] = x;
+    }
+
+    fn get(&self, idx: usize) -> M::S {
+        self.data[idx].clone()
+    }
+
+    fn apply<F>(&mut self, l: usize, r: usize, f: &F::S)
+    where
+        F: Action<M>,
+    {
+        for x in self.data[l..r].iter_mut() {
+            *x = F::act(f, x);
+        }
+    }
+
+    fn fold(&self, l: usize, r: usize) -> M::S {
+        self.data[l..r]
+            .iter()
+            .fold(M::id(), |acc, x| M::op(&acc, x))
+    }
+
+    fn max_right<P>(&self, l: usize, f: P) -> usize
+    where
+        P: Fn(&M::S) -> bool,
+    {
+        let mut sum = M::id();
+        for r in l..self.data.len() {
+            let next_sum = M::op(&sum, &self.data[r]);
+            if !f(&next_sum) {
+                return r;
+            }
+            sum = next_sum;
+        }
+        self.data.len()
+    }
+
+    fn min_left<P>(&self, r: usize, f: P) -> usize
+    where
+        P: Fn(&M::S) -> bool,
+    {
+        let mut sum = M::id();
+        for l in (0..r).rev() {
+            let next_sum = M::op(&self.data[l], &sum);
+            if !f(&next_sum) {
+                return l + 1;
+            }
+            sum = next_sum;
+        }
+        0
+    }
+}
+
+// Helper function to pick a random non-empty-or-empty range [l, r) within [0, n].
+fn rand_range(rng: &mut impl Rng, n: usize) -> (usize, usize) {
+    let mut l = rng.random_range(0..=n);
+    let mut r = rng.random_range(0..=n);
+    if l > r {
+        std::mem::swap(&mut l, &mut r);
+    }
+    (l, r)
+}
+
+// Tests that `new` creates a tree of the correct length.
+#[test]
+fn test_new_and_len() {
+    // Arrange & Act
+    let seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(0);
+    // Assert
+    assert_eq!(seg.len(), 0);
+    assert!(seg.is_empty());
+
+    // Arrange & Act
+    let seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(10);
+    // Assert
+    assert_eq!(seg.len(), 10);
+    assert!(!seg.is_empty());
+}
+
+// Tests range add with range sum.
+#[test]
+fn test_apply_add_fold_sum() {
+    // Arrange
+    let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(6);
+    for i in 0..6 {
+        seg.set(i, (i as i64, 1));
+    }
+
+    // Act
+    seg.apply(1, 4, 10);
+    seg.apply(3, 6, -2);
+
+    // Assert: data is [0, 11, 12, 11, 2, 3]
+    assert_eq!((39, 6), seg.fold(0, 6));
+    assert_eq!((34, 3), seg.fold(1, 4));
+    assert_eq!((11, 1), seg.fold(3, 4));
+    assert_eq!(12, seg.get(2).0);
+}
+
+// Tests range assign with range min.
+#[test]
+fn test_apply_assign_fold_min() {
+    // Arrange
+    let mut seg = LazySegmentTree::<MinMonoid, AssignAction>::new(5);
+    for (i, v) in [5, 3, 8, 1, 9].into_iter().enumerate() {
+        seg.set(i, v);
+    }
+
+    // Act
+    seg.apply(2, 5, Some(4));
+
+    // Assert: data is [5, 3, 4, 4, 4]
+    assert_eq!(3, seg.fold(0, 5));
+    assert_eq!(4, seg.fold(2, 5));
+    assert_eq!(4, seg.get(3));
+}
+
+// Tests that folding or applying on an empty range has no effect.
+#[test]
+fn test_empty_range_edge_cases() {
+    // Arrange
+    let mut seg = LazySegmentTree::<MaxMonoid, AddAction>::new(5);
+
+    // Act
+    seg.apply(2, 2, 100);
+    seg.apply(4, 1, 100);
+
+    // Assert
+    assert_eq!(MaxMonoid::id(), seg.fold(0, 0));
+    assert_eq!(MaxMonoid::id(), seg.fold(5, 5));
+    assert_eq!(MaxMonoid::id(), seg.fold(0, 5));
+
+    // Act
+    seg.apply(1, 3, 100);
+
+    // Assert
+    assert_eq!(MaxMonoid::id(), seg.fold(3, 5));
+    assert_eq!(MaxMonoid::id() + 100, seg.fold(0, 5));
+    assert_eq!(MaxMonoid::id(), seg.fold(2, 2));
+}
+
+// Tests that actions reach elements that were never `set`, whose values equal the identity.
+#[test]
+fn test_apply_on_unset_tree() {
+    // Arrange
+    let mut assign_min = LazySegmentTree::<MinMonoid, AssignAction>::new(4);
+    let mut add_max = LazySegmentTree::<MaxMonoid, AddAction>::new(4);
+    let mut assign_max = LazySegmentTree::<MaxMonoid, AssignAction>::new(3);
+    let mut add_min = LazySegmentTree::<MinMonoid, AddAction>::new(3);
+
+    // Act
+    assign_min.apply(0, 4, Some(5));
+    add_max.apply(0, 4, 3);
+    assign_max.apply(1, 3, Some(-7));
+    add_min.apply(0, 2, -3);
+
+    // Assert
+    assert_eq!(5, assign_min.fold(0, 4));
+    assert_eq!(5, assign_min.get(3));
+    assert_eq!(i64::MIN + 3, add_max.fold(0, 4));
+    assert_eq!(-7, assign_max.fold(0, 3));
+    assert_eq!(i64::MIN, assign_max.get(0));
+    assert_eq!(i64::MAX - 3, add_min.fold(0, 3));
+    assert_eq!(i64::MAX, add_min.get(2));
+}
+
+// Tests that `from_slice` builds the same tree as setting every leaf, so range add works at once.
+#[test]
+fn test_from_slice() {
+    // Arrange
+    let mut rng = rand::rng();
+    for n in [0, 1, 5, 8, 33] {
+        let v = (0..n)
+            .map(|_| (rng.random_range(-100..=100), 1))
+            .collect::<Vec<(i64, i64)>>();
+        let mut naive = NaiveLazySegmentTree::<AddWithLenMonoid>::new(n);
+        for (i, &x) in v.iter().enumerate() {
+            naive.set(i, x);
+        }
+
+        // Act
+        let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::from_slice(&v);
+        seg.apply(n / 3, n, 7);
+        naive.apply::<AddAction>(n / 3, n, &7);
+
+        // Assert
+        assert_eq!(n, seg.len());
+        for l in 0..=n {
+            for r in l..=n {
+                assert_eq!(naive.fold(l, r), seg.fold(l, r));
+            }
+        }
+    }
+}
+
+// Tests that `get` panics when the index is out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 5 but the index is 5")]
+fn test_get_out_of_bounds() {
+    let mut seg = LazySegmentTree::<MinMonoid, AddAction>::new(5);
+    seg.get(5);
+}
+
+// Tests that `set` panics when the index is out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 5 but the index is 5")]
+fn test_set_out_of_bounds() {
+    let mut seg = LazySegmentTree::<MinMonoid, AddAction>::new(5);
+    seg.set(5, 1);
+}
+
+// Tests that `fold` panics when the range is out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_fold_out_of_bounds() {
+    let mut seg = LazySegmentTree::<MinMonoid, AddAction>::new(5);
+    seg.fold(0, 6);
+}
+
+// Tests that `apply` panics when the range is out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_apply_out_of_bounds() {
+    let mut seg = LazySegmentTree::<MinMonoid, AddAction>::new(5);
+    seg.apply(0, 6, 1);
+}
+
+// Tests that `max_right` panics when the predicate rejects the identity element.
+#[test]
+#[should_panic(expected = "predicate must be true for the identity element")]
+fn test_max_right_rejects_identity() {
+    let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    seg.max_right(0, |_| false);
+}
+
+// Tests `max_right` and `min_left` after range updates.
+#[test]
+fn test_max_right_min_left_add() {
+    // Arrange
+    let mut seg = LazySegmentTree::<AddWithLenMonoid, AddAction>::new(5);
+    for i in 0..5 {
+        seg.set(i, (i as i64 + 1, 1));
+    }
+    seg.apply(0, 5, 1);
+
+    // Act & Assert: data is [2, 3, 4, 5, 6]
+    assert_eq!(3, seg.max_right(0, |&(sum, _)| sum <= 9));
+    assert_eq!(5, seg.max_right(0, |_| true));
+    assert_eq!(5, seg.max_right(5, |_| true));
+    assert_eq!(3, seg.min_left(5, |&(sum, _)| sum <= 11));
+    assert_eq!(0, seg.min_left(5, |_| true));
+    assert_eq!(0, seg.min_left(0, |_| true));
+}
+
+// Performs a randomized comparison with the naive model using a non-commutative action.
+#[test]
+fn test_randomized_comparison_affine() {
+    // Arrange
+    let mut rng = rand::rng();
+    for n in [1, 2, 7, 64, 100] {
+        let mut seg = LazySegmentTree::<SumLenModMonoid, AffineAction>::new(n);
+        let mut naive = NaiveLazySegmentTree::<SumLenModMonoid>::new(n);
+        for i in 0..n {
+            let x = (rng.random_range(0..P), 1);
+            seg.set(i, x);
+            naive.set(i, x);
+        }
+
+        // Act & Assert
+        for _ in 0..2000 {
+            match rng.random_range(0..6) {
+                0 => {
+                    let (l, r) = rand_range(&mut rng, n);
+                    let f = (rng.random_range(0..P), rng.random_range(0..P));
+                    seg.apply(l, r, f);
+                    naive.apply::<AffineAction>(l, r, &f);
+                }
+                1 => {
+                    let (l, r) = rand_range(&mut rng, n);
+                    assert_eq!(naive.fold(l, r), seg.fold(l, r));
+                }
+                2 => {
+                    let idx = rng.random_range(0..n);
+                    assert_eq!(naive.get(idx), seg.get(idx));
+                }
+                3 => {
+                    let idx = rng.random_range(0..n);
+                    let x = (rng.random_range(0..P), 1);
+                    seg.set(idx, x);
+                    naive.set(idx, x);
+                }
+                4 => {
+                    let l = rng.random_range(0..=n);
+                    let k = rng.random_range(0..=n as i64);
+                    let f = |x: &(i64, i64)| x.1 <= k;
+                    assert_eq!(naive.max_right(l, f), seg.max_right(l, f));
+                }
+                5 => {
+                    let r = rng.random_range(0..=n);
+                    let k = rng.random_range(0..=n as i64);
+                    let f = |x: &(i64, i64)| x.1 <= k;
+                    assert_eq!(naive.min_left(r, f), seg.min_left(r, f));
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+// Performs a randomized comparison of range add / range max including `max_right` and `min_left`.
+#[test]
+fn test_randomized_comparison_add_max() {
+    // Arrange
+    let mut rng = rand::rng();
+    let n = 50;
+    let mut seg = LazySegmentTree::<MaxMonoid, AddAction>::new(n);
+    let mut naive = NaiveLazySegmentTree::<MaxMonoid>::new(n);
+    for i in 0..n {
+        let x = rng.random_range(-100..=100);
+        seg.set(i, x);
+        naive.set(i, x);
+    }
+
+    // Act & Assert
+    for _ in 0..5000 {
+        match rng.random_range(0..5) {
+            0 => {
+                let (l, r) = rand_range(&mut rng, n);
+                let f = rng.random_range(-10..=10);
+                seg.apply(l, r, f);
+                naive.apply::<AddAction>(l, r, &f);
+            }
+            1 => {
+                let (l, r) = rand_range(&mut rng, n);
+                assert_eq!(naive.fold(l, r), seg.fold(l, r));
+            }
+            2 => {
+                let l = rng.random_range(0..=n);
+                let k = rng.random_range(-100..=100);
+                let f = |x: &i64| *x < k;
+                assert_eq!(naive.max_right(l, f), seg.max_right(l, f));
+            }
+            3 => {
+                let r = rng.random_range(0..=n);
+                let k = rng.random_range(-100..=100);
+                let f = |x: &i64| *x < k;
+                assert_eq!(naive.min_left(r, f), seg.min_left(r, f));
+            }
+            4 => {
+                let idx = rng.random_range(0..n);
+                let x = rng.random_range(-100..=100);
+                seg.set(idx, x);
+                naive.set(idx, x);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+// Performs a randomized comparison of range assign / range min starting from an unset tree.
+#[test]
+fn test_randomized_comparison_assign_min() {
+    // Arrange
+    let mut rng = rand::rng();
+    for n in [1, 3, 16, 50] {
+        let mut seg = LazySegmentTree::<MinMonoid, AssignAction>::new(n);
+        let mut naive = NaiveLazySegmentTree::<MinMonoid>::new(n);
+
+        // Act & Assert
+        for _ in 0..1000 {
+            let (l, r) = rand_range(&mut rng, n);
+            match rng.random_range(0..3) {
+                0 => {
+                    let f = rng.random_bool(0.8).then(|| rng.random_range(-100..=100));
+                    seg.apply(l, r, f);
+                    naive.apply::<AssignAction>(l, r, &f);
+                }
+                1 => assert_eq!(naive.fold(l, r), seg.fold(l, r)),
+                2 => {
+                    let idx = rng.random_range(0..n);
+                    assert_eq!(naive.get(idx), seg.get(idx));
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use anmitsu::{
+    algebra::monoid::{AddMonoid, MinMonoid},
+    ds::segment_tree::merge_sort_tree::MergeSortTree,
+};
+use rand::{self, Rng};
+
+// Tests random updates and rectangle folds on sparse points against a naive map.
+#[test]
+fn test_randomized_comparison_add() {
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        // Arrange
+        let c = rng.random_range(1..=10);
+        let points = (0..rng.random_range(1..=40))
+            .map(|_| (rng.random_range(-c..c), rng.random_range(-c..c)))
+            .collect::<Vec<(i64, i64)>>();
+        let mut mst = MergeSortTree::<AddMonoid>::new();
+        for &(x, y) in points.iter() {
+            mst.add_point(x, y);
+        }
+        mst.build();
+        let mut naive = BTreeMap::new();
+
+        for _ in 0..100 {
+            // Act
+            let (x, y) = points[rng.random_range(0..points.len())];
+            let v = rng.random_range(-100..=100);
+            mst.update(x, y, v);
+            naive.insert((x, y), v);
+
+            // Assert
+            let x1 = rng.random_range(-c - 1..=c + 1);
+            let x2 = rng.random_range(-c - 1..=c + 1);
+            let y1 = rng.random_range(-c - 1..=c + 1);
+            let y2 = rng.random_range(-c - 1..=c + 1);
+            let expected = naive
+                .iter()
+                .filter(|&(&(x, y), _)| x1 <= x && x < x2 && y1 <= y && y < y2)
+                .map(|(_, &v)| v)
+                .sum::<i64>();
+            assert_eq!(expected, mst.rect_fold(x1, x2, y1, y2));
+        }
+    }
+}
+
+// Tests coordinates up to 10^9 with duplicate registrations.
+#[test]
+fn test_large_coordinates_min() {
+    // Arrange
+    let mut rng = rand::rng();
+    let points = (0..500)
+        .map(|_| {
+            (
+                rng.random_range(0..=1_000_000_000),
+                rng.random_range(0..=1_000_000_000),
+            )
+        })
+        .collect::<Vec<(i64, i64)>>();
+    let mut mst = MergeSortTree::<MinMonoid>::new();
+    for &(x, y) in points.iter().chain(points.iter()) {
+        mst.add_point(x, y);
+    }
+    mst.build();
+    let mut naive = BTreeMap::new();
+
+    // Act
+    for &(x, y) in points.iter() {
+        let v = rng.random_range(-1000..=1000);
+        mst.update(x, y, v);
+        naive.insert((x, y), v);
+    }
+
+    // Assert
+    assert_eq!(naive.len(), mst.len());
+    for _ in 0..200 {
+        let x1 = rng.random_range(0..=1_000_000_001);
+        let x2 = rng.random_range(x1..=1_000_000_001);
+        let y1 = rng.random_range(0..=1_000_000_001);
+        let y2 = rng.random_range(y1..=1_000_000_001);
+        let expected = naive
+            .iter()
+            .filter(|&(&(x, y), _)| x1 <= x && x < x2 && y1 <= y && y < y2)
+            .map(|(_, &v)| v)
+            .min()
+            .unwrap_or(i64::MAX);
+        assert_eq!(expected, mst.rect_fold(x1, x2, y1, y2));
+    }
+}
+
+// Tests that a tree without points folds every rectangle to the identity.
+#[test]
+fn test_empty() {
+    // Arrange
+    let mut mst = MergeSortTree::<AddMonoid>::new();
+
+    // Act
+    mst.build();
+
+    // Assert
+    assert!(mst.is_empty());
+    assert_eq!(0, mst.rect_fold(i64::MIN, i64::MAX, i64::MIN, i64::MAX));
+}
+
+// Tests that updating a point that was never registered panics.
+#[test]
+#[should_panic(expected = "The point (1, 2) has not been added")]
+fn test_update_unknown_point() {
+    let mut mst = MergeSortTree::<AddMonoid>::new();
+    mst.add_point(2, 1);
+    mst.build();
+    mst.update(1, 2, 5);
+}
+
+// Tests that adding a point after `build` panics.
+#[test]
+#[should_panic(expected = "Points cannot be added after build")]
+fn test_add_point_after_build() {
+    let mut mst = MergeSortTree::<AddMonoid>::new();
+    mst.build();
+    mst.add_point(0, 0);
+}
+
+// Tests that a query before `build` panics.
+#[test]
+#[should_panic(expected = "The tree must be built before queries")]
+fn test_rect_fold_before_build() {
+    let mut mst = MergeSortTree::<AddMonoid>::new();
+    mst.add_point(0, 0);
+    mst.rect_fold(0, 1, 0, 1);
+}
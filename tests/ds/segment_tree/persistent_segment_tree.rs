@@ -0,0 +1,224 @@
+use anmitsu::{
+    algebra::{
+        monoid::{AddMonoid, Monoid, XorMonoid},
+        semi_group::SemiGroup,
+    },
+    ds::segment_tree::persistent_segment_tree::PersistentSegmentTree,
+};
+use rand::{self, Rng};
+
+const MOD: u64 = 998_244_353;
+
+// Composition of affine maps `x -> a * x + b`, applying the left one first.
+struct AffineMonoid;
+
+impl SemiGroup for AffineMonoid {
+    type S = (u64, u64);
+
+    fn op(f: &Self::S, g: &Self::S) -> Self::S {
+        (f.0 * g.0 % MOD, (f.1 * g.0 + g.1) % MOD)
+    }
+}
+
+impl Monoid for AffineMonoid {
+    fn id() -> Self::S {
+        (1, 0)
+    }
+}
+
+fn rand_affine(rng: &mut impl Rng) -> (u64, u64) {
+    (rng.random_range(0..MOD), rng.random_range(0..MOD))
+}
+
+// Tests that a fresh tree and its initial version behave as all identities.
+#[test]
+fn test_new_and_initial() {
+    // Arrange & Act
+    let seg = PersistentSegmentTree::<AddMonoid>::new(4);
+    let v0 = seg.initial();
+
+    // Assert
+    assert_eq!(4, seg.len());
+    assert!(!seg.is_empty());
+    assert_eq!(0, seg.fold(v0, 0, 4));
+    assert_eq!(0, seg.get(v0, 3));
+    assert!(PersistentSegmentTree::<AddMonoid>::new(0).is_empty());
+}
+
+// Tests updates branching from random old versions against copies of the whole array.
+#[test]
+fn test_randomized_versions_affine() {
+    let mut rng = rand::rng();
+    for _ in 0..20 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let init = (0..n).map(|_| rand_affine(&mut rng)).collect::<Vec<_>>();
+        let mut seg = PersistentSegmentTree::<AffineMonoid>::new(n);
+        let mut versions = vec![seg.build(&init)];
+        let mut naive = vec![init];
+
+        for _ in 0..200 {
+            // Act
+            let from = rng.random_range(0..versions.len());
+            let i = rng.random_range(0..n);
+            let x = rand_affine(&mut rng);
+            versions.push(seg.update(versions[from], i, x));
+            let mut next = naive[from].clone();
+            next[i] = x;
+            naive.push(next);
+
+            // Assert
+            let k = rng.random_range(0..versions.len());
+            let l = rng.random_range(0..=n);
+            let r = rng.random_range(l..=n);
+            let expected = naive[k][l..r]
+                .iter()
+                .fold(AffineMonoid::id(), |acc, x| AffineMonoid::op(&acc, x));
+            assert_eq!(expected, seg.fold(versions[k], l, r));
+            assert_eq!(naive[k][i], seg.get(versions[k], i));
+        }
+    }
+}
+
+// Tests that an update allocates only the nodes on one root-to-leaf path.
+#[test]
+fn test_structure_sharing() {
+    // Arrange
+    let n = 1 << 10;
+    let mut seg = PersistentSegmentTree::<AddMonoid>::new(n);
+    let v0 = seg.build(&vec![1; n]);
+    let before = seg.num_nodes();
+
+    // Act
+    let v1 = seg.update(v0, 123, 5);
+
+    // Assert
+    assert_eq!(before + 11, seg.num_nodes());
+    assert_eq!(n as i64, seg.fold(v0, 0, n));
+    assert_eq!(n as i64 + 4, seg.fold(v1, 0, n));
+}
+
+// Tests `max_right` on a past version against a linear scan.
+#[test]
+fn test_max_right_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(0..=20);
+        let v = (0..n)
+            .map(|_| rng.random_range(0..=5))
+            .collect::<Vec<i64>>();
+        let mut seg = PersistentSegmentTree::<AddMonoid>::new(n);
+        let old = seg.build(&v);
+        if n > 0 {
+            seg.update(old, 0, 100);
+        }
+        let bound = rng.random_range(1..=30);
+
+        for l in 0..=n {
+            // Act
+            let result = seg.max_right(old, l, |&sum| sum < bound);
+
+            // Assert
+            let expected = (l..=n)
+                .rev()
+                .find(|&r| v[l..r].iter().sum::<i64>() < bound)
+                .unwrap();
+            assert_eq!(expected, result);
+        }
+    }
+}
+
+// Tests range k-th smallest queries through `max_right_diff` on prefix count versions.
+#[test]
+fn test_kth_smallest_random() {
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let m = rng.random_range(1..=10);
+        let a = (0..n)
+            .map(|_| rng.random_range(0..m))
+            .collect::<Vec<usize>>();
+        let mut seg = PersistentSegmentTree::<AddMonoid>::new(m);
+        let mut versions = vec![seg.initial()];
+        for &x in a.iter() {
+            let last = *versions.last().unwrap();
+            let count = seg.get(last, x);
+            versions.push(seg.update(last, x, count + 1));
+        }
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                let mut sorted = a[l..r].to_vec();
+                sorted.sort();
+                for (k, &expected) in sorted.iter().enumerate() {
+                    // Act
+                    let k = k as i64;
+                    let result = seg.max_right_diff(versions[l], versions[r], |&c| c <= k);
+
+                    // Assert
+                    assert_eq!(expected, result);
+                }
+            }
+        }
+    }
+}
+
+// Tests `diff` against comparing every element of two random versions.
+#[test]
+fn test_diff_random() {
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let mut seg = PersistentSegmentTree::<XorMonoid>::new(n);
+        let mut versions = vec![seg.build(&vec![0; n])];
+        for _ in 0..30 {
+            let from = rng.random_range(0..versions.len());
+            let i = rng.random_range(0..n);
+            let x = rng.random_range(0..3);
+            versions.push(seg.update(versions[from], i, x));
+        }
+
+        for &a in versions.iter() {
+            for &b in versions.iter() {
+                // Act
+                let result = seg.diff(a, b);
+
+                // Assert
+                let expected = (0..n)
+                    .filter(|&i| seg.get(a, i) != seg.get(b, i))
+                    .collect::<Vec<_>>();
+                assert_eq!(expected, result);
+            }
+        }
+    }
+}
+
+// Tests that `update` panics on an index out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_update_out_of_bounds() {
+    let mut seg = PersistentSegmentTree::<AddMonoid>::new(3);
+    let v0 = seg.initial();
+    seg.update(v0, 3, 1);
+}
+
+// Tests that `fold` panics when `r` exceeds the length.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 4, len: 3)"
+)]
+fn test_fold_out_of_bounds() {
+    let seg = PersistentSegmentTree::<AddMonoid>::new(3);
+    seg.fold(seg.initial(), 0, 4);
+}
+
+// Tests that `build` panics when the number of values differs from the length.
+#[test]
+#[should_panic(expected = "The number of values must equal the len (values: 2, len: 3)")]
+fn test_build_wrong_length() {
+    let mut seg = PersistentSegmentTree::<AddMonoid>::new(3);
+    seg.build(&[1, 2]);
+}
@@ -0,0 +1,112 @@
+use anmitsu::{
+    algebra::monoid::{AddMonoid, MaxMonoid, XorMonoid},
+    ds::segment_tree::segment_tree_2d::SegmentTree2D,
+};
+use rand::{self, Rng};
+
+// Tests random point updates and rectangle folds against a naive grid.
+#[test]
+fn test_randomized_comparison_add() {
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        // Arrange
+        let h = rng.random_range(1..=9);
+        let w = rng.random_range(1..=9);
+        let mut naive = vec![vec![0i64; w]; h];
+        let mut seg = SegmentTree2D::<AddMonoid>::new(h, w);
+
+        for _ in 0..100 {
+            // Act
+            let (i, j) = (rng.random_range(0..h), rng.random_range(0..w));
+            let x = rng.random_range(-100..=100);
+            seg.update(i, j, x);
+            naive[i][j] = x;
+
+            // Assert
+            let i1 = rng.random_range(0..=h);
+            let i2 = rng.random_range(i1..=h);
+            let j1 = rng.random_range(0..=w);
+            let j2 = rng.random_range(j1..=w);
+            let expected = naive[i1..i2]
+                .iter()
+                .map(|row| row[j1..j2].iter().sum::<i64>())
+                .sum::<i64>();
+            assert_eq!(expected, seg.fold(i1, i2, j1, j2));
+            assert_eq!(naive[i][j], seg.get(i, j));
+        }
+    }
+}
+
+// Tests that `set` and `build` agree with folding every rectangle of a random grid.
+#[test]
+fn test_set_build_max() {
+    let mut rng = rand::rng();
+    for _ in 0..10 {
+        // Arrange
+        let h = rng.random_range(1..=7);
+        let w = rng.random_range(1..=7);
+        let grid = (0..h)
+            .map(|_| (0..w).map(|_| rng.random_range(-50..=50)).collect())
+            .collect::<Vec<Vec<i64>>>();
+        let mut seg = SegmentTree2D::<MaxMonoid>::new(h, w);
+
+        // Act
+        for (i, row) in grid.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                seg.set(i, j, x);
+            }
+        }
+        seg.build();
+
+        // Assert
+        for i1 in 0..h {
+            for i2 in i1 + 1..=h {
+                for j1 in 0..w {
+                    for j2 in j1 + 1..=w {
+                        let expected = grid[i1..i2]
+                            .iter()
+                            .flat_map(|row| row[j1..j2].iter())
+                            .max()
+                            .unwrap();
+                        assert_eq!(*expected, seg.fold(i1, i2, j1, j2));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Tests that empty rectangles and an empty grid fold to the identity.
+#[test]
+fn test_empty() {
+    // Arrange
+    let mut seg = SegmentTree2D::<XorMonoid>::new(2, 2);
+    let empty = SegmentTree2D::<XorMonoid>::new(0, 3);
+
+    // Act
+    seg.update(0, 0, 5);
+
+    // Assert
+    assert_eq!(0, seg.fold(0, 0, 0, 2));
+    assert_eq!(0, seg.fold(1, 2, 1, 1));
+    assert_eq!((0, 3), empty.shape());
+    assert_eq!(0, empty.fold(0, 0, 0, 3));
+}
+
+// Tests that `update` panics on a column out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the shape is (2, 3) but the index is (1, 3)")]
+fn test_update_out_of_bounds() {
+    let mut seg = SegmentTree2D::<AddMonoid>::new(2, 3);
+    seg.update(1, 3, 1);
+}
+
+// Tests that `fold` panics on a rectangle outside of the grid.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: the rectangle [0, 3) x [0, 1) is not within the shape (2, 3)"
+)]
+fn test_fold_out_of_bounds() {
+    let seg = SegmentTree2D::<AddMonoid>::new(2, 3);
+    seg.fold(0, 3, 0, 1);
+}
@@ -0,0 +1,160 @@
+use anmitsu::ds::segment_tree::segment_tree_beats::SegmentTreeBeats;
+use rand::{self, Rng};
+
+// Tests random mixes of every update and query against a naive array.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let mut naive = (0..n)
+            .map(|_| rng.random_range(-100..=100))
+            .collect::<Vec<i64>>();
+        let mut seg = SegmentTreeBeats::from_slice(&naive);
+
+        for _ in 0..300 {
+            let l = rng.random_range(0..=n);
+            let r = rng.random_range(l..=n);
+            let x = rng.random_range(-100..=100);
+            match rng.random_range(0..7) {
+                0 => {
+                    // Act
+                    seg.chmin(l, r, x);
+                    naive[l..r].iter_mut().for_each(|a| *a = (*a).min(x));
+                }
+                1 => {
+                    seg.chmax(l, r, x);
+                    naive[l..r].iter_mut().for_each(|a| *a = (*a).max(x));
+                }
+                2 => {
+                    seg.add(l, r, x);
+                    naive[l..r].iter_mut().for_each(|a| *a += x);
+                }
+                3 => {
+                    seg.assign(l, r, x);
+                    naive[l..r].iter_mut().for_each(|a| *a = x);
+                }
+                4 => {
+                    // Assert
+                    assert_eq!(naive[l..r].iter().sum::<i64>(), seg.sum(l, r));
+                }
+                5 => {
+                    let expected = naive[l..r].iter().copied().min().unwrap_or(i64::MAX);
+                    assert_eq!(expected, seg.min(l, r));
+                }
+                _ => {
+                    let expected = naive[l..r].iter().copied().max().unwrap_or(i64::MIN);
+                    assert_eq!(expected, seg.max(l, r));
+                }
+            }
+        }
+
+        // Assert
+        for (i, &a) in naive.iter().enumerate() {
+            assert_eq!(a, seg.get(i));
+        }
+    }
+}
+
+// Tests that `new` starts with zeros and handles an empty tree.
+#[test]
+fn test_new() {
+    // Arrange
+    let mut seg = SegmentTreeBeats::new(3);
+    let mut empty = SegmentTreeBeats::new(0);
+
+    // Act
+    seg.chmax(0, 3, 5);
+    seg.chmin(1, 2, -1);
+    empty.add(0, 0, 1);
+
+    // Assert
+    assert_eq!(3, seg.len());
+    assert_eq!(9, seg.sum(0, 3));
+    assert_eq!(-1, seg.min(0, 3));
+    assert!(empty.is_empty());
+    assert_eq!(0, empty.sum(0, 0));
+}
+
+// Tests a large instance where repeated clamping collapses many distinct values.
+#[test]
+fn test_large_chmin_chmax() {
+    // Arrange
+    let n = 200_000;
+    let mut naive = (0..n as i64).collect::<Vec<_>>();
+    let mut seg = SegmentTreeBeats::from_slice(&naive);
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        // Act
+        let l = rng.random_range(0..n);
+        let r = rng.random_range(l + 1..=n);
+        let x = rng.random_range(0..n as i64);
+        if rng.random_bool(0.5) {
+            seg.chmin(l, r, x);
+            naive[l..r].iter_mut().for_each(|a| *a = (*a).min(x));
+        } else {
+            seg.chmax(l, r, x);
+            naive[l..r].iter_mut().for_each(|a| *a = (*a).max(x));
+        }
+    }
+
+    // Assert
+    assert_eq!(naive.iter().sum::<i64>(), seg.sum(0, n));
+    assert_eq!(*naive.iter().min().unwrap(), seg.min(0, n));
+    assert_eq!(*naive.iter().max().unwrap(), seg.max(0, n));
+}
+
+// Tests `chmin`, `chmax` and `assign` with `x` at the extremes of `i64`.
+#[test]
+fn test_extreme_values() {
+    // Arrange
+    let mut seg = SegmentTreeBeats::from_slice(&[5, 7, 3]);
+    let mut seg7 = SegmentTreeBeats::from_slice(&[3, 1, 4, 1, 5, 9, 2]);
+
+    // Act & Assert
+    seg.chmin(0, 3, i64::MIN);
+    assert_eq!(i64::MIN, seg.min(0, 3));
+    assert_eq!(i64::MIN, seg.max(0, 3));
+    assert_eq!(i64::MIN, seg.get(1));
+
+    seg.chmax(1, 3, i64::MAX);
+    assert_eq!(i64::MIN, seg.get(0));
+    assert_eq!(i64::MAX, seg.get(1));
+    assert_eq!(i64::MAX, seg.get(2));
+    assert_eq!(-1, seg.sum(0, 2));
+
+    seg.add(0, 1, 1);
+    seg.chmin(0, 3, 0);
+    assert_eq!(i64::MIN + 1, seg.get(0));
+    assert_eq!(0, seg.max(0, 3));
+
+    seg7.assign(1, 5, i64::MIN);
+    assert_eq!(i64::MIN, seg7.min(0, 7));
+    assert_eq!(i64::MIN, seg7.get(4));
+    assert_eq!(9, seg7.max(0, 7));
+    assert_eq!(3, seg7.get(0));
+
+    seg7.assign(0, 7, i64::MAX);
+    assert_eq!(i64::MAX, seg7.min(0, 7));
+    assert_eq!(i64::MAX, seg7.get(6));
+}
+
+// Tests that updates panic when `r` exceeds the length.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 4, len: 3)"
+)]
+fn test_chmin_out_of_bounds() {
+    let mut seg = SegmentTreeBeats::new(3);
+    seg.chmin(0, 4, 1);
+}
+
+// Tests that `get` panics on an index out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_get_out_of_bounds() {
+    let mut seg = SegmentTreeBeats::new(3);
+    seg.get(3);
+}
@@ -57,6 +57,7 @@ where
     data: Vec<M::S>,
 }
 
+#[allow(clippy::len_without_is_empty)]
 impl<M> NaiveSegmentTree<M>
 where
     M: monoid::Monoid,
@@ -67,7 +68,7 @@ where
     ///
     /// # Args
     /// - `n`: The size of the data structure.
-    ///        データ構造のサイズ.
+    ///   データ構造のサイズ.
     ///
     /// # Returns
     /// `NaiveSegmentTree<M>`: A new instance initialized with the monoid's identity element.
@@ -75,7 +76,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(n), where `n` is the size.
-    ///                          ここで `n` はサイズである.
+    ///   ここで `n` はサイズである.
     /// - Space complexity: O(n).
     pub fn new(n: usize) -> Self {
         Self {
@@ -104,9 +105,9 @@ where
     ///
     /// # Args
     /// - `idx`: The index to set.
-    ///          セット対象のインデックス.
+    ///   セット対象のインデックス.
     /// - `x`: The new value.
-    ///        新しい値.
+    ///   新しい値.
     ///
     /// # Panics
     /// Panics if `idx` is out of bounds.
@@ -132,9 +133,9 @@ where
     ///
     /// # Args
     /// - `idx`: The index to update.
-    ///          更新対象のインデックス.
+    ///   更新対象のインデックス.
     /// - `x`: The new value.
-    ///        新しい値.
+    ///   新しい値.
     ///
     /// # Panics
     /// Panics if `idx` is out of bounds.
@@ -152,7 +153,7 @@ where
     ///
     /// # Args
     /// - `idx`: The index to retrieve.
-    ///          値を取得するインデックス.
+    ///   値を取得するインデックス.
     ///
     /// # Returns
     /// `M::S`: The value at `idx`.
@@ -174,9 +175,9 @@ where
     ///
     /// # Args
     /// - `l`: The start index of the range (inclusive).
-    ///        `query` 区間の開始インデックス (含む).
+    ///   `query` 区間の開始インデックス (含む).
     /// - `r`: The end index of the range (exclusive).
-    ///        `query` 区間の終了インデックス (含まない).
+    ///   `query` 区間の終了インデックス (含まない).
     ///
     /// # Returns
     /// `M::S`: The folded result. Returns the identity element for an empty range.
@@ -207,9 +208,9 @@ where
     ///
     /// # Args
     /// - `l`: The start index of the range.
-    ///        範囲の開始インデックス.
+    ///   範囲の開始インデックス.
     /// - `f`: The predicate function.
-    ///        述語関数.
+    ///   述語関数.
     ///
     /// # Returns
     /// `usize`: The maximum `r` satisfying the predicate.
@@ -217,7 +218,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(N), where N is the number of elements.
-    ///                          ここで N は要素数である.
+    ///   ここで N は要素数である.
     /// - Space complexity: O(1).
     pub fn max_right<F>(&self, l: usize, f: F) -> usize
     where
@@ -245,9 +246,9 @@ where
     ///
     /// # Args
     /// - `r`: The end index of the range.
-    ///        範囲の終了インデックス.
+    ///   範囲の終了インデックス.
     /// - `f`: The predicate function.
-    ///        述語関数.
+    ///   述語関数.
     ///
     /// # Returns
     /// `usize`: The minimum `l` satisfying the predicate.
@@ -255,7 +256,7 @@ where
     ///
     /// # Complexity
     /// - Time complexity: O(N), where N is the number of elements.
-    ///                          ここで N は要素数である.
+    ///   ここで N は要素数である.
     /// - Space complexity: O(1).
     pub fn min_left<L>(&self, r: usize, f: L) -> usize
     where
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use anmitsu::{
+    algebra::{
+        monoid::{AddMonoid, MaxMonoid, Monoid},
+        semi_group::SemiGroup,
+    },
+    ds::segment_tree::segment_tree_sparse::SegmentTreeSparse,
+};
+use rand::{self, Rng};
+
+const MOD: u64 = 998_244_353;
+
+// Composition of affine maps `x -> a * x + b`, applying the left one first.
+struct AffineMonoid;
+
+impl SemiGroup for AffineMonoid {
+    type S = (u64, u64);
+
+    fn op(f: &Self::S, g: &Self::S) -> Self::S {
+        (f.0 * g.0 % MOD, (f.1 * g.0 + g.1) % MOD)
+    }
+}
+
+impl Monoid for AffineMonoid {
+    fn id() -> Self::S {
+        (1, 0)
+    }
+}
+
+// Folds `[l, r)` of a sparse map of values naively.
+fn naive_fold<M>(values: &BTreeMap<i64, M::S>, l: i64, r: i64) -> M::S
+where
+    M: Monoid,
+{
+    if l >= r {
+        return M::id();
+    }
+    values
+        .range(l..r)
+        .fold(M::id(), |acc, (_, x)| M::op(&acc, x))
+}
+
+// Tests `new`, `range` and that a fresh tree allocates no nodes.
+#[test]
+fn test_new_and_range() {
+    // Arrange & Act
+    let seg = SegmentTreeSparse::<AddMonoid>::new(-10, 20);
+
+    // Assert
+    assert_eq!((-10, 20), seg.range());
+    assert_eq!(0, seg.num_nodes());
+    assert_eq!(0, seg.fold(-10, 20));
+    assert_eq!(0, seg.get(5));
+}
+
+// Tests random updates and folds with a non-commutative monoid against a naive map.
+#[test]
+fn test_randomized_comparison_affine() {
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        // Arrange
+        let lo = rng.random_range(-50..=50);
+        let hi = lo + rng.random_range(1..=60);
+        let mut seg = SegmentTreeSparse::<AffineMonoid>::new(lo, hi);
+        let mut naive = BTreeMap::new();
+
+        for _ in 0..200 {
+            if rng.random_bool(0.5) {
+                // Act
+                let i = rng.random_range(lo..hi);
+                let x = (rng.random_range(0..MOD), rng.random_range(0..MOD));
+                seg.update(i, x);
+                naive.insert(i, x);
+
+                // Assert
+                assert_eq!(x, seg.get(i));
+            } else {
+                // Act
+                let l = rng.random_range(lo..=hi);
+                let r = rng.random_range(l..=hi);
+
+                // Assert
+                assert_eq!(naive_fold::<AffineMonoid>(&naive, l, r), seg.fold(l, r));
+            }
+        }
+    }
+}
+
+// Tests `max_right` and `min_left` against linear scans on a small range.
+#[test]
+fn test_max_right_min_left_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let lo = rng.random_range(-20..=20);
+        let hi = lo + rng.random_range(1..=30);
+        let mut seg = SegmentTreeSparse::<AddMonoid>::new(lo, hi);
+        let mut naive = BTreeMap::new();
+        for _ in 0..rng.random_range(0..10) {
+            let i = rng.random_range(lo..hi);
+            let x = rng.random_range(0..=10);
+            seg.update(i, x);
+            naive.insert(i, x);
+        }
+        let bound = rng.random_range(1..=40);
+        let f = |&sum: &i64| sum < bound;
+
+        for p in lo..=hi {
+            // Act
+            let right = seg.max_right(p, f);
+            let left = seg.min_left(p, f);
+
+            // Assert
+            let expected_right = (p..=hi)
+                .rev()
+                .find(|&r| f(&naive_fold::<AddMonoid>(&naive, p, r)))
+                .unwrap();
+            let expected_left = (lo..=p)
+                .find(|&l| f(&naive_fold::<AddMonoid>(&naive, l, p)))
+                .unwrap();
+            assert_eq!(expected_right, right);
+            assert_eq!(expected_left, left);
+        }
+    }
+}
+
+// Tests that memory stays proportional to the number of updated points on a range of 10^18.
+#[test]
+fn test_huge_range_memory() {
+    // Arrange
+    let n = 1_000_000_000_000_000_000;
+    let mut rng = rand::rng();
+    let mut seg = SegmentTreeSparse::<AddMonoid>::new(0, n);
+    let mut naive = BTreeMap::new();
+
+    // Act
+    for _ in 0..1000 {
+        let i = rng.random_range(0..n);
+        let x = rng.random_range(-100..=100);
+        seg.update(i, x);
+        naive.insert(i, x);
+    }
+
+    // Assert
+    assert!(seg.num_nodes() <= 1000 * 61);
+    for _ in 0..100 {
+        let l = rng.random_range(0..=n);
+        let r = rng.random_range(l..=n);
+        assert_eq!(naive_fold::<AddMonoid>(&naive, l, r), seg.fold(l, r));
+    }
+}
+
+// Tests the extreme ends of the whole `i64` range.
+#[test]
+fn test_full_i64_range() {
+    // Arrange
+    let mut seg = SegmentTreeSparse::<MaxMonoid>::new(i64::MIN, i64::MAX);
+
+    // Act
+    seg.update(i64::MIN, 1);
+    seg.update(i64::MAX - 1, 3);
+    seg.update(0, 2);
+
+    // Assert
+    assert_eq!(1, seg.get(i64::MIN));
+    assert_eq!(3, seg.get(i64::MAX - 1));
+    assert_eq!(2, seg.fold(i64::MIN + 1, i64::MAX - 1));
+    assert_eq!(3, seg.fold(i64::MIN, i64::MAX));
+    assert_eq!(i64::MAX - 1, seg.max_right(i64::MIN, |&m| m < 3));
+    assert_eq!(1, seg.min_left(i64::MAX - 1, |&m| m < 2));
+}
+
+// Tests that `update` panics on an index outside of the range.
+#[test]
+#[should_panic(expected = "index out of bounds: the range is [-3, 3) but the index is 3")]
+fn test_update_out_of_bounds() {
+    let mut seg = SegmentTreeSparse::<AddMonoid>::new(-3, 3);
+    seg.update(3, 1);
+}
+
+// Tests that `fold` panics on a query outside of the range.
+#[test]
+#[should_panic(expected = "index out of bounds: the range is [0, 5) but the query is [-1, 2)")]
+fn test_fold_out_of_bounds() {
+    let seg = SegmentTreeSparse::<AddMonoid>::new(0, 5);
+    seg.fold(-1, 2);
+}
+
+// Tests that `new` panics on a reversed range.
+#[test]
+#[should_panic(expected = "lo must be less than or equal to hi (lo: 2, hi: 1)")]
+fn test_new_reversed_range() {
+    SegmentTreeSparse::<AddMonoid>::new(2, 1);
+}
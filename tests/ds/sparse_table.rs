@@ -0,0 +1,72 @@
+use anmitsu::algebra::{monoid, semi_group};
+use anmitsu::ds::sparse_table::SparseTable;
+use rand::{self, Rng};
+
+// Tests every range fold on min and max against a naive scan.
+#[test]
+fn test_fold_min_max_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let v = (0..n)
+            .map(|_| rng.random_range(-100..=100))
+            .collect::<Vec<i64>>();
+        let min = SparseTable::<semi_group::MinSemiGroup>::new(&v);
+        let max = SparseTable::<monoid::MaxMonoid>::new(&v);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                // Act & Assert
+                assert_eq!(*v[l..r].iter().min().unwrap(), min.fold(l, r));
+                assert_eq!(*v[l..r].iter().max().unwrap(), max.fold(l, r));
+            }
+        }
+    }
+}
+
+// Tests every range fold on bitwise AND and OR against a naive scan.
+#[test]
+fn test_fold_and_or_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let v = (0..n).map(|_| rng.random::<u64>()).collect::<Vec<_>>();
+        let and = SparseTable::<monoid::AndMonoid>::new(&v);
+        let or = SparseTable::<monoid::OrMonoid>::new(&v);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                // Act & Assert
+                assert_eq!(v[l..r].iter().fold(u64::MAX, |a, &b| a & b), and.fold(l, r));
+                assert_eq!(v[l..r].iter().fold(0, |a, &b| a | b), or.fold(l, r));
+            }
+        }
+    }
+}
+
+// Tests the length of empty and non-empty tables.
+#[test]
+fn test_len() {
+    let empty = SparseTable::<semi_group::MaxSemiGroup>::new(&[]);
+    let st = SparseTable::<semi_group::MaxSemiGroup>::new(&[1, 2, 3]);
+    assert!(empty.is_empty());
+    assert_eq!(3, st.len());
+}
+
+// Tests that an empty range panics.
+#[test]
+#[should_panic(expected = "l must be less than r (l: 2, r: 2)")]
+fn test_fold_empty_range() {
+    SparseTable::<semi_group::MinSemiGroup>::new(&[1, 2, 3]).fold(2, 2);
+}
+
+// Tests that a range beyond the sequence panics.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 4, len: 3)"
+)]
+fn test_fold_out_of_bounds() {
+    SparseTable::<semi_group::MinSemiGroup>::new(&[1, 2, 3]).fold(0, 4);
+}
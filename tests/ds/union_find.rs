@@ -1,4 +1,5 @@
 use anmitsu::ds::union_find::UnionFind;
+use rand::{self, Rng};
 
 // Test: new_len_and_roots
 // Description: Verifies initial length, roots, and singleton sizes.
@@ -184,3 +185,83 @@ fn test_panic_get_group_empty() {
     let mut uf = UnionFind::new(0);
     uf.get_group(0); // len is 0, index 0 is out of bounds // len は 0, index 0 は範囲外
 }
+
+// Test: num_components_incremental
+// Description: Tests that the number of components decreases only on effective unions.
+#[test]
+fn test_num_components_incremental() {
+    let mut uf = UnionFind::new(4);
+    assert_eq!(uf.num_components(), 4);
+    uf.union(0, 1);
+    assert_eq!(uf.num_components(), 3);
+    uf.union(1, 0);
+    uf.union(2, 2);
+    assert_eq!(uf.num_components(), 3);
+    uf.union(2, 3);
+    uf.union(0, 3);
+    assert_eq!(uf.num_components(), 1);
+    assert_eq!(UnionFind::new(0).num_components(), 0);
+}
+
+// Test: groups_and_component_ids
+// Description: Tests groups() and component_ids() ordering by the smallest members.
+#[test]
+fn test_groups_and_component_ids() {
+    let mut uf = UnionFind::new(6);
+    uf.union(5, 1);
+    uf.union(3, 0);
+    uf.union(1, 3);
+
+    assert_eq!(uf.groups(), vec![vec![0, 1, 3, 5], vec![2], vec![4]]);
+    assert_eq!(uf.component_ids(), (vec![0, 0, 1, 0, 2, 0], 3));
+    assert!(UnionFind::new(0).groups().is_empty());
+    assert_eq!(UnionFind::new(0).component_ids(), (vec![], 0));
+}
+
+// Test: group_iter_visits_each_member_once
+// Description: Tests that group_iter starts with x and visits each member exactly once.
+#[test]
+fn test_group_iter_visits_each_member_once() {
+    let mut uf = UnionFind::new(5);
+    uf.union(0, 4);
+    uf.union(2, 4);
+
+    let members = uf.group_iter(4).collect::<Vec<_>>();
+    assert_eq!(members[0], 4);
+    let mut sorted = members.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 2, 4]);
+    assert_eq!(uf.group_iter(1).collect::<Vec<_>>(), vec![1]);
+}
+
+// Test: panic_group_iter_out_of_bounds
+// Description: Tests that group_iter panics on an out-of-bounds index.
+#[test]
+#[should_panic(expected = "Index 5 is out of bounds for UnionFind with size 5")]
+fn test_panic_group_iter_out_of_bounds() {
+    let uf = UnionFind::new(5);
+    uf.group_iter(5);
+}
+
+// Test: randomized_groups
+// Description: Compares groups, component ids and num_components with get_group after random unions.
+#[test]
+fn test_randomized_groups() {
+    let mut rng = rand::rng();
+    let n = 50;
+    let mut uf = UnionFind::new(n);
+    for _ in 0..40 {
+        uf.union(rng.random_range(0..n), rng.random_range(0..n));
+    }
+
+    let groups = uf.groups();
+    let (ids, count) = uf.component_ids();
+    assert_eq!(groups.len(), count);
+    assert_eq!(uf.num_components(), count);
+    for (id, group) in groups.iter().enumerate() {
+        assert_eq!(&uf.get_group(group[0]), group);
+        for &x in group.iter() {
+            assert_eq!(ids[x], id);
+        }
+    }
+}
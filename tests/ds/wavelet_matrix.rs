@@ -1,8 +1,9 @@
 use anmitsu::ds::wavelet_matrix;
+use rand::{self, Rng};
 
 // Sets up a common WaveletMatrix instance for general testing.
 fn setup_general_wavelet_matrix() -> wavelet_matrix::WaveletMatrix {
-    let data = vec![5, 4, 8, 6, 0, 7, 2, 5];
+    let data = [5, 4, 8, 6, 0, 7, 2, 5];
     wavelet_matrix::WaveletMatrix::new(&data)
 }
 
@@ -123,3 +124,155 @@ fn queries_with_max_value_data_work_correctly() {
         "Failed on count in partial range with MAX"
     );
 }
+
+// Helper function to pick a random range [l, r) within [0, n].
+fn rand_range(rng: &mut impl Rng, n: usize) -> (usize, usize) {
+    let mut l = rng.random_range(0..=n);
+    let mut r = rng.random_range(0..=n);
+    if l > r {
+        std::mem::swap(&mut l, &mut r);
+    }
+    (l, r)
+}
+
+// Tests `access`, `len` and `is_empty`.
+#[test]
+fn access_returns_original_values() {
+    // Arrange
+    let data = [5, 4, 8, 6, 0, 7, 2, 5];
+    let wm = setup_general_wavelet_matrix();
+
+    // Act & Assert
+    assert_eq!(data.len(), wm.len());
+    assert!(!wm.is_empty());
+    assert!(wavelet_matrix::WaveletMatrix::new(&[]).is_empty());
+    for (i, &x) in data.iter().enumerate() {
+        assert_eq!(x, wm.access(i));
+    }
+}
+
+// Tests that `access` panics when the index is out of bounds.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 8 but the index is 8")]
+fn access_out_of_bounds_panics() {
+    setup_general_wavelet_matrix().access(8);
+}
+
+// Tests `kth_smallest` and `kth_largest` on a fixed sequence.
+#[test]
+fn kth_smallest_and_largest_return_correct_values() {
+    // Arrange
+    let wm = setup_general_wavelet_matrix(); // data is [5, 4, 8, 6, 0, 7, 2, 5]
+
+    // Act & Assert
+    assert_eq!(0, wm.kth_smallest(0, 8, 0));
+    assert_eq!(5, wm.kth_smallest(0, 8, 3));
+    assert_eq!(5, wm.kth_smallest(0, 8, 4));
+    assert_eq!(8, wm.kth_smallest(0, 8, 7));
+    assert_eq!(7, wm.kth_smallest(2, 6, 2)); // [0, 6, 7, 8]
+    assert_eq!(8, wm.kth_largest(0, 8, 0));
+    assert_eq!(0, wm.kth_largest(0, 8, 7));
+}
+
+// Tests that `kth_smallest` panics when `k` is not less than the range length.
+#[test]
+#[should_panic(expected = "k must be less than r - l (k: 2, r - l: 2)")]
+fn kth_smallest_with_too_large_k_panics() {
+    setup_general_wavelet_matrix().kth_smallest(3, 5, 2);
+}
+
+// Tests that `kth_smallest` panics when `r` exceeds the length.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: r must be less than or equal to the len (r: 9, len: 8)"
+)]
+fn kth_smallest_out_of_bounds_panics() {
+    setup_general_wavelet_matrix().kth_smallest(0, 9, 0);
+}
+
+// Tests that `rank` panics when `i` exceeds the length.
+#[test]
+#[should_panic(
+    expected = "index out of bounds: i must be less than or equal to the len (i: 9, len: 8)"
+)]
+fn rank_out_of_bounds_panics() {
+    setup_general_wavelet_matrix().rank(5, 9);
+}
+
+// Tests `prev_value`, `next_value`, `rank`, `select` and `top_k_frequent` including `usize::MAX`.
+#[test]
+fn value_queries_with_max_value_work_correctly() {
+    // Arrange
+    let wm = wavelet_matrix::WaveletMatrix::new(&[usize::MAX, 0, usize::MAX, 3]);
+
+    // Act & Assert
+    assert_eq!(Some(3), wm.prev_value(0, 4, usize::MAX));
+    assert_eq!(None, wm.prev_value(0, 4, 0));
+    assert_eq!(Some(usize::MAX), wm.next_value(0, 4, 4));
+    assert_eq!(None, wm.next_value(1, 2, 1));
+    assert_eq!(2, wm.rank(usize::MAX, 4));
+    assert_eq!(Some(2), wm.select(usize::MAX, 1));
+    assert_eq!(None, wm.select(1, 0));
+    assert_eq!(
+        vec![(usize::MAX, 2), (0, 1), (3, 1)],
+        wm.top_k_frequent(0, 4, 10)
+    );
+    assert!(wm.top_k_frequent(2, 2, 10).is_empty());
+    assert!(wm.top_k_frequent(0, 4, 0).is_empty());
+}
+
+// Performs a randomized comparison of the new queries with naive implementations.
+#[test]
+fn randomized_comparison_with_naive() {
+    // Arrange
+    let mut rng = rand::rng();
+    for n in [1, 2, 10, 100] {
+        let max_value = rng.random_range(1..=20);
+        let data: Vec<usize> = (0..n).map(|_| rng.random_range(0..max_value)).collect();
+        let wm = wavelet_matrix::WaveletMatrix::new(&data);
+
+        // Act & Assert
+        for _ in 0..500 {
+            let (l, r) = rand_range(&mut rng, n);
+            let mut sorted = data[l..r].to_vec();
+            sorted.sort_unstable();
+            let x = rng.random_range(0..=max_value);
+
+            if l < r {
+                let k = rng.random_range(0..r - l);
+                assert_eq!(sorted[k], wm.kth_smallest(l, r, k));
+                assert_eq!(sorted[r - l - 1 - k], wm.kth_largest(l, r, k));
+            }
+            assert_eq!(
+                sorted.iter().rev().find(|&&y| y < x).copied(),
+                wm.prev_value(l, r, x)
+            );
+            assert_eq!(
+                sorted.iter().find(|&&y| y >= x).copied(),
+                wm.next_value(l, r, x)
+            );
+            assert_eq!(data[..r].iter().filter(|&&y| y == x).count(), wm.rank(x, r));
+            let k = rng.random_range(0..3);
+            assert_eq!(
+                data.iter()
+                    .enumerate()
+                    .filter(|&(_, &y)| y == x)
+                    .nth(k)
+                    .map(|(i, _)| i),
+                wm.select(x, k)
+            );
+
+            let mut freq: Vec<(usize, usize)> = Vec::new();
+            for &y in sorted.iter() {
+                match freq.last_mut() {
+                    Some((v, c)) if *v == y => *c += 1,
+                    _ => freq.push((y, 1)),
+                }
+            }
+            freq.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            let k = rng.random_range(0..=freq.len() + 1);
+            freq.truncate(k);
+            assert_eq!(freq, wm.top_k_frequent(l, r, k));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use anmitsu::{
+    algebra::monoid::{AddMonoid, XorMonoid},
+    ds::weighted_union_find::WeightedUnionFind,
+};
+use rand::{self, Rng};
+
+// Returns pot(y) - pot(x) by a BFS over the accepted constraints, or `None` if unreachable.
+fn naive_diff(n: usize, edges: &[(usize, usize, i64)], x: usize, y: usize) -> Option<i64> {
+    let mut adj = vec![Vec::new(); n];
+    for &(a, b, w) in edges {
+        adj[a].push((b, w));
+        adj[b].push((a, -w));
+    }
+    let mut pot = vec![None; n];
+    pot[x] = Some(0);
+    let mut queue = std::collections::VecDeque::from([x]);
+    while let Some(v) = queue.pop_front() {
+        for &(u, w) in adj[v].iter() {
+            if pot[u].is_none() {
+                pot[u] = Some(pot[v].unwrap() + w);
+                queue.push_back(u);
+            }
+        }
+    }
+    pot[y]
+}
+
+// Test: new_len_and_singletons
+// Description: Verifies initial length, sizes and potentials.
+#[test]
+fn test_new_len_and_singletons() {
+    let mut uf = WeightedUnionFind::<AddMonoid>::new(3);
+    assert_eq!(3, uf.len());
+    assert!(!uf.is_empty());
+    assert!(WeightedUnionFind::<AddMonoid>::new(0).is_empty());
+
+    for i in 0..3 {
+        assert_eq!(i, uf.find(i));
+        assert_eq!(0, uf.potential(i));
+        assert_eq!(1, uf.get_size(i));
+        assert_eq!(Some(0), uf.diff(i, i));
+    }
+    assert_eq!(None, uf.diff(0, 1));
+}
+
+// Test: union_and_diff
+// Description: Tests that constraints propagate through chains and detect contradictions.
+#[test]
+fn test_union_and_diff() {
+    let mut uf = WeightedUnionFind::<AddMonoid>::new(5);
+    assert!(uf.union(0, 1, 3)); // pot(1) = pot(0) + 3
+    assert!(uf.union(2, 3, -4)); // pot(3) = pot(2) - 4
+    assert!(uf.union(3, 1, 10)); // pot(1) = pot(3) + 10
+
+    assert_eq!(Some(3), uf.diff(0, 1));
+    assert_eq!(Some(-10), uf.diff(1, 3));
+    assert_eq!(Some(-3), uf.diff(0, 2)); // 3 - 10 + 4
+    assert_eq!(Some(3), uf.diff(2, 0));
+    assert_eq!(4, uf.get_size(2));
+    assert_eq!(None, uf.diff(0, 4));
+
+    assert!(uf.union(0, 2, -3));
+    assert!(!uf.union(0, 2, 0));
+    assert_eq!(Some(-3), uf.diff(0, 2));
+    assert!(uf.union(4, 4, 0));
+    assert!(!uf.union(4, 4, 1));
+}
+
+// Test: xor_group
+// Description: Tests the XOR group, where every potential is its own inverse.
+#[test]
+fn test_xor_group() {
+    let mut uf = WeightedUnionFind::<XorMonoid>::new(4);
+    assert!(uf.union(0, 1, 0b101));
+    assert!(uf.union(1, 2, 0b011));
+    assert_eq!(Some(0b110), uf.diff(0, 2));
+    assert_eq!(Some(0b110), uf.diff(2, 0));
+    assert!(!uf.union(2, 0, 0b111));
+    assert!(uf.is_same(0, 2));
+    assert!(!uf.is_same(0, 3));
+}
+
+// Test: panic_find_out_of_bounds
+// Description: Tests that find panics on an out-of-bounds index.
+#[test]
+#[should_panic(expected = "Index 3 is out of bounds for WeightedUnionFind with size 3")]
+fn test_panic_find_out_of_bounds() {
+    let mut uf = WeightedUnionFind::<AddMonoid>::new(3);
+    uf.find(3);
+}
+
+// Test: panic_union_out_of_bounds
+// Description: Tests that union panics on an out-of-bounds index.
+#[test]
+#[should_panic(expected = "Index out of bounds for union: x=0, y=5, len=3")]
+fn test_panic_union_out_of_bounds() {
+    let mut uf = WeightedUnionFind::<AddMonoid>::new(3);
+    uf.union(0, 5, 1);
+}
+
+// Test: randomized_comparison
+// Description: Compares union and diff with a naive BFS over the accepted constraints.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    let n = 30;
+    // Hidden potentials make roughly half of the constraints consistent.
+    let hidden: Vec<i64> = (0..n).map(|_| rng.random_range(-100..=100)).collect();
+    let mut uf = WeightedUnionFind::<AddMonoid>::new(n);
+    let mut edges = Vec::new();
+
+    for _ in 0..300 {
+        let x = rng.random_range(0..n);
+        let y = rng.random_range(0..n);
+        if rng.random_bool(0.5) {
+            let w = if rng.random_bool(0.7) {
+                hidden[y] - hidden[x]
+            } else {
+                rng.random_range(-100..=100)
+            };
+            let expected = match naive_diff(n, &edges, x, y) {
+                Some(d) => d == w,
+                None => {
+                    edges.push((x, y, w));
+                    true
+                }
+            };
+            assert_eq!(expected, uf.union(x, y, w));
+        } else {
+            assert_eq!(naive_diff(n, &edges, x, y), uf.diff(x, y));
+        }
+    }
+}
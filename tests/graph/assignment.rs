@@ -0,0 +1,81 @@
+use anmitsu::graph::assignment;
+use rand::{self, Rng};
+
+// Enumerates every injective assignment of rows to columns and returns the costs.
+fn all_costs(cost: &[Vec<i64>]) -> Vec<i64> {
+    fn walk(cost: &[Vec<i64>], row: usize, used: &mut [bool], acc: i64, out: &mut Vec<i64>) {
+        if row == cost.len() {
+            out.push(acc);
+            return;
+        }
+        for col in 0..used.len() {
+            if !used[col] {
+                used[col] = true;
+                walk(cost, row + 1, used, acc + cost[row][col], out);
+                used[col] = false;
+            }
+        }
+    }
+
+    let m = cost.first().map_or(0, |row| row.len());
+    let mut out = Vec::new();
+    walk(cost, 0, &mut vec![false; m], 0, &mut out);
+    out
+}
+
+// Tests the minimum and maximum assignments against enumeration.
+#[test]
+fn test_hungarian_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(0..=5);
+        let m = rng.random_range(n.max(1)..=6);
+        let cost = (0..n)
+            .map(|_| {
+                (0..m)
+                    .map(|_| rng.random_range(-50..=50))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let costs = all_costs(&cost);
+
+        // Act
+        let (min, min_assignment) = assignment::hungarian_min(&cost);
+        let (max, max_assignment) = assignment::hungarian_max(&cost);
+
+        // Assert
+        assert_eq!(*costs.iter().min().unwrap(), min);
+        assert_eq!(*costs.iter().max().unwrap(), max);
+        for (total, assigned) in [(min, min_assignment), (max, max_assignment)] {
+            assert_eq!(n, assigned.len());
+            let mut used = vec![false; m];
+            for &j in assigned.iter() {
+                assert!(!used[j]);
+                used[j] = true;
+            }
+            let sum = assigned
+                .iter()
+                .enumerate()
+                .map(|(i, &j)| cost[i][j])
+                .sum::<i64>();
+            assert_eq!(total, sum);
+        }
+    }
+}
+
+// Tests that a matrix with more rows than columns panics.
+#[test]
+#[should_panic(
+    expected = "The number of rows must not exceed the number of columns (rows: 2, columns: 1)"
+)]
+fn test_too_many_rows() {
+    assignment::hungarian_min(&[vec![1], vec![2]]);
+}
+
+// Tests that a ragged matrix panics.
+#[test]
+#[should_panic(expected = "Every row of the cost matrix must have the same length")]
+fn test_ragged_matrix() {
+    assignment::hungarian_max(&[vec![1, 2], vec![3]]);
+}
@@ -0,0 +1,48 @@
+use anmitsu::graph::Graph;
+
+// Tests the accessors of directed and undirected graphs.
+#[test]
+fn test_directed_and_undirected_accessors() {
+    // Arrange & Act
+    let d = Graph::directed(3, vec![(0, 1, 5), (2, 1, 3)]);
+    let u = Graph::<i64>::undirected(2, vec![]);
+
+    // Assert
+    assert!(d.is_directed());
+    assert_eq!(3, d.num_vertices());
+    assert_eq!(2, d.num_edges());
+    assert_eq!(&[(0, 1, 5), (2, 1, 3)], d.edges());
+    assert!(!u.is_directed());
+    assert_eq!(0, u.num_edges());
+}
+
+// Tests that an edge with an out-of-bounds endpoint panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_endpoint_out_of_bounds() {
+    Graph::undirected(3, vec![(0, 3, 1)]);
+}
+
+// Tests that the CSR adjacency lists the edges of each vertex in order.
+#[test]
+fn test_csr_adjacency() {
+    // Arrange
+    let d = Graph::directed(3, vec![(1, 0, ()), (0, 2, ()), (1, 2, ())]);
+    let u = Graph::undirected(3, vec![(1, 0, ()), (0, 2, ()), (1, 1, ())]);
+
+    // Act & Assert
+    assert_eq!(&[(2, 1)], d.out_edges(0));
+    assert_eq!(vec![0, 2], d.neighbors(1).collect::<Vec<_>>());
+    assert_eq!(0, d.degree(2));
+    assert_eq!(&[(1, 0), (2, 1)], u.out_edges(0));
+    assert_eq!(vec![0, 1, 1], u.neighbors(1).collect::<Vec<_>>());
+    assert_eq!(1, u.degree(2));
+}
+
+// Tests that querying the adjacency of an out-of-bounds vertex panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_out_edges_out_of_bounds() {
+    let g = Graph::<()>::directed(2, vec![]);
+    g.out_edges(2);
+}
@@ -0,0 +1,118 @@
+use anmitsu::graph::bipartite_matching::BipartiteMatching;
+use rand::{self, Rng};
+
+// Computes the size of a maximum matching by Kuhn's augmenting path algorithm.
+fn naive_matching_size(left: usize, right: usize, edges: &[(usize, usize)]) -> usize {
+    fn try_kuhn(
+        l: usize,
+        adj: &[Vec<usize>],
+        seen: &mut [bool],
+        mate: &mut [Option<usize>],
+    ) -> bool {
+        for &r in adj[l].iter() {
+            if seen[r] {
+                continue;
+            }
+            seen[r] = true;
+            if mate[r].is_none_or(|l2| try_kuhn(l2, adj, seen, mate)) {
+                mate[r] = Some(l);
+                return true;
+            }
+        }
+        false
+    }
+
+    let mut adj = vec![Vec::new(); left];
+    for &(l, r) in edges.iter() {
+        adj[l].push(r);
+    }
+    let mut mate = vec![None; right];
+    (0..left)
+        .filter(|&l| try_kuhn(l, &adj, &mut vec![false; right], &mut mate))
+        .count()
+}
+
+// Tests the maximum matching and the minimum vertex cover against Kuhn's algorithm.
+#[test]
+fn test_max_matching_and_cover_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let left = rng.random_range(1..=10);
+        let right = rng.random_range(1..=10);
+        let m = rng.random_range(0..=30);
+        let edges = (0..m)
+            .map(|_| (rng.random_range(0..left), rng.random_range(0..right)))
+            .collect::<Vec<_>>();
+        let mut bm = BipartiteMatching::new(left, right);
+        for &(l, r) in edges.iter() {
+            bm.add_edge(l, r);
+        }
+
+        // Act
+        let matching = bm.max_matching();
+        let (cover_left, cover_right) = bm.min_vertex_cover();
+
+        // Assert
+        let size = naive_matching_size(left, right, &edges);
+        assert_eq!(size, matching.len());
+        assert!(matching.iter().all(|p| edges.contains(p)));
+        assert!(matching.windows(2).all(|w| w[0].0 < w[1].0));
+        let mut used_right = vec![false; right];
+        for &(_, r) in matching.iter() {
+            assert!(!used_right[r]);
+            used_right[r] = true;
+        }
+        assert_eq!(size, cover_left.len() + cover_right.len());
+        assert!(
+            edges
+                .iter()
+                .all(|(l, r)| cover_left.contains(l) || cover_right.contains(r))
+        );
+    }
+}
+
+// Tests that adding edges after a matching extends it.
+#[test]
+fn test_incremental_edges() {
+    // Arrange
+    let mut bm = BipartiteMatching::new(2, 2);
+    bm.add_edge(0, 0);
+    bm.add_edge(1, 0);
+
+    // Act & Assert
+    assert_eq!(1, bm.max_matching().len());
+    bm.add_edge(0, 1);
+    assert_eq!(vec![(0, 1), (1, 0)], bm.max_matching());
+}
+
+// Tests that a long augmenting path does not overflow the stack.
+#[test]
+fn test_long_augmenting_path() {
+    // Arrange
+    let n = 200_000;
+    let mut bm = BipartiteMatching::new(n, n);
+    for i in 0..n {
+        bm.add_edge(i, i);
+    }
+    bm.max_matching();
+    for i in 0..n - 1 {
+        bm.add_edge(i + 1, i);
+    }
+    let mut other = BipartiteMatching::new(n, n);
+    for i in 0..n - 1 {
+        other.add_edge(i, i + 1);
+        other.add_edge(i + 1, i);
+    }
+
+    // Act & Assert
+    assert_eq!(n, bm.max_matching().len());
+    assert_eq!(n, other.max_matching().len());
+}
+
+// Tests that an out-of-bounds right vertex panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_add_edge_out_of_bounds() {
+    BipartiteMatching::new(3, 2).add_edge(0, 2);
+}
@@ -0,0 +1,152 @@
+use anmitsu::graph::{Graph, components};
+use rand::{self, Rng};
+
+fn random_digraph(rng: &mut impl Rng, n: usize, m: usize) -> Graph<()> {
+    let edges = (0..m)
+        .map(|_| (rng.random_range(0..n), rng.random_range(0..n), ()))
+        .collect();
+    Graph::directed(n, edges)
+}
+
+// Computes the reachability matrix by running a search from every vertex.
+fn reachability(g: &Graph<()>) -> Vec<Vec<bool>> {
+    let n = g.num_vertices();
+    (0..n)
+        .map(|s| {
+            let mut seen = vec![false; n];
+            let mut stack = vec![s];
+            seen[s] = true;
+            while let Some(v) = stack.pop() {
+                for to in g.neighbors(v) {
+                    if !seen[to] {
+                        seen[to] = true;
+                        stack.push(to);
+                    }
+                }
+            }
+            seen
+        })
+        .collect()
+}
+
+// Tests that toposort orders every edge forward or reports a cycle.
+#[test]
+fn test_toposort_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(1..=12);
+        let m = rng.random_range(0..=20);
+        let g = random_digraph(&mut rng, n, m);
+        let reach = reachability(&g);
+        let has_cycle = g.edges().iter().any(|&(u, v, _)| reach[v][u]);
+
+        // Act
+        let result = components::toposort(&g);
+
+        // Assert
+        match result {
+            None => assert!(has_cycle),
+            Some(order) => {
+                assert!(!has_cycle);
+                let mut pos = vec![usize::MAX; n];
+                for (i, &v) in order.iter().enumerate() {
+                    pos[v] = i;
+                }
+                assert!(pos.iter().all(|&p| p < n));
+                assert!(g.edges().iter().all(|&(u, v, _)| pos[u] < pos[v]));
+            }
+        }
+    }
+}
+
+// Tests that toposort rejects an undirected graph.
+#[test]
+#[should_panic(expected = "The graph must be directed")]
+fn test_toposort_undirected() {
+    components::toposort(&Graph::<()>::undirected(1, vec![]));
+}
+
+// Tests the connected components against mutual reachability on the undirected graph.
+#[test]
+fn test_connected_components_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=15);
+        let m = rng.random_range(0..=15);
+        let d = random_digraph(&mut rng, n, m);
+        let u = Graph::undirected(n, d.edges().to_vec());
+        let reach = reachability(&u);
+
+        // Act
+        let (ids, count) = components::connected_components(&d);
+
+        // Assert
+        assert_eq!(ids, components::connected_components(&u).0);
+        assert_eq!(count, ids.iter().max().unwrap() + 1);
+        for a in 0..n {
+            for b in 0..n {
+                assert_eq!(reach[a][b], ids[a] == ids[b]);
+            }
+        }
+    }
+}
+
+// Tests the strongly connected components and the condensation against the reachability matrix.
+#[test]
+fn test_strongly_connected_components_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(1..=12);
+        let m = rng.random_range(0..=25);
+        let g = random_digraph(&mut rng, n, m);
+        let reach = reachability(&g);
+
+        // Act
+        let (ids, count) = components::strongly_connected_components(&g);
+        let (cids, dag) = components::condensation(&g);
+
+        // Assert
+        assert_eq!(ids, cids);
+        assert_eq!(count, dag.num_vertices());
+        assert!(ids.iter().all(|&id| id < count));
+        for a in 0..n {
+            for b in 0..n {
+                assert_eq!(reach[a][b] && reach[b][a], ids[a] == ids[b]);
+            }
+        }
+        assert!(g.edges().iter().all(|&(u, v, _)| ids[u] <= ids[v]));
+
+        let mut expected = g
+            .edges()
+            .iter()
+            .map(|&(u, v, _)| (ids[u], ids[v], ()))
+            .filter(|&(a, b, _)| a != b)
+            .collect::<Vec<_>>();
+        expected.sort_unstable();
+        expected.dedup();
+        assert_eq!(expected, dag.edges());
+    }
+}
+
+// Tests Tarjan's algorithm on a cycle and a path of 10^6 vertices.
+#[test]
+fn test_strongly_connected_components_large() {
+    // Arrange
+    let n = 1_000_000;
+    let cycle = Graph::directed(n, (0..n).map(|i| (i, (i + 1) % n, ())).collect());
+    let path = Graph::directed(n, (0..n - 1).map(|i| (i + 1, i, ())).collect());
+
+    // Act
+    let (cycle_ids, cycle_count) = components::strongly_connected_components(&cycle);
+    let (path_ids, path_count) = components::strongly_connected_components(&path);
+
+    // Assert
+    assert_eq!(1, cycle_count);
+    assert!(cycle_ids.iter().all(|&id| id == 0));
+    assert_eq!(n, path_count);
+    assert_eq!(n - 1, path_ids[0]);
+    assert_eq!(0, path_ids[n - 1]);
+}
@@ -0,0 +1,77 @@
+use anmitsu::graph::general_matching::general_matching;
+use rand::{self, Rng};
+
+// Computes the size of a maximum matching by a DP over vertex subsets.
+fn naive_matching_size(n: usize, edges: &[(usize, usize)]) -> usize {
+    let mut adj = vec![0usize; n];
+    for &(u, v) in edges.iter() {
+        if u != v {
+            adj[u] |= 1 << v;
+            adj[v] |= 1 << u;
+        }
+    }
+
+    // best[set] is the size of a maximum matching within `set`.
+    let mut best = vec![0; 1 << n];
+    for set in 1usize..1 << n {
+        let u = set.trailing_zeros() as usize;
+        let rest = set & !(1 << u);
+        best[set] = best[rest];
+        let mut partners = adj[u] & rest;
+        while partners != 0 {
+            let v = partners.trailing_zeros() as usize;
+            partners &= partners - 1;
+            best[set] = best[set].max(best[rest & !(1 << v)] + 1);
+        }
+    }
+    best[(1 << n) - 1]
+}
+
+// Tests the general matching against the subset DP.
+#[test]
+fn test_general_matching_random() {
+    let mut rng = rand::rng();
+    for _ in 0..300 {
+        // Arrange
+        let n = rng.random_range(1..=12);
+        let m = rng.random_range(0..=25);
+        let edges = (0..m)
+            .map(|_| (rng.random_range(0..n), rng.random_range(0..n)))
+            .collect::<Vec<_>>();
+
+        // Act
+        let matching = general_matching(n, &edges);
+
+        // Assert
+        assert_eq!(naive_matching_size(n, &edges), matching.len());
+        let mut used = vec![false; n];
+        for &(u, v) in matching.iter() {
+            assert!(u < v);
+            assert!(edges.contains(&(u, v)) || edges.contains(&(v, u)));
+            assert!(!used[u] && !used[v]);
+            used[u] = true;
+            used[v] = true;
+        }
+    }
+}
+
+// Tests an odd cycle, which needs a blossom contraction to be matched perfectly with its pendant.
+#[test]
+fn test_blossom() {
+    // Arrange
+    // A 5-cycle 0-1-2-3-4 with pendants 5 (on 0) and 6 (on 5), where greedy matching picks (0, 1), (2, 3) first.
+    let edges = vec![(0, 1), (2, 3), (1, 2), (3, 4), (4, 0), (0, 5), (5, 6)];
+
+    // Act
+    let matching = general_matching(7, &edges);
+
+    // Assert
+    assert_eq!(3, matching.len());
+}
+
+// Tests that an out-of-bounds endpoint panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_endpoint_out_of_bounds() {
+    general_matching(3, &[(0, 3)]);
+}
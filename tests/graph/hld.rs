@@ -0,0 +1,186 @@
+use anmitsu::algebra::{monoid::Monoid, semi_group::SemiGroup};
+use anmitsu::graph::{hld::HeavyLightDecomposition, tree::Tree};
+use rand::{self, Rng};
+
+const MOD: u64 = 998_244_353;
+
+// The composition of affine maps `x -> a x + b`, applying the left operand first.
+struct AffineMonoid;
+
+impl SemiGroup for AffineMonoid {
+    type S = (u64, u64);
+    fn op(f: &Self::S, g: &Self::S) -> Self::S {
+        (f.0 * g.0 % MOD, (f.1 * g.0 + g.1) % MOD)
+    }
+}
+
+impl Monoid for AffineMonoid {
+    fn id() -> Self::S {
+        (1, 0)
+    }
+}
+
+fn random_tree(rng: &mut impl Rng, n: usize) -> Tree {
+    let edges = (1..n)
+        .map(|v| (rng.random_range(0..v), v))
+        .collect::<Vec<_>>();
+    Tree::from_edges(n, &edges, rng.random_range(0..n))
+}
+
+// Tests that the positions form a preorder with contiguous subtrees.
+#[test]
+fn test_positions_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let tree = random_tree(&mut rng, n);
+
+        // Act
+        let hld = HeavyLightDecomposition::new(&tree);
+
+        // Assert
+        let mut seen = vec![false; n];
+        for v in 0..n {
+            assert!(!seen[hld.pos(v)]);
+            seen[hld.pos(v)] = true;
+            let (l, r) = hld.subtree_range(v);
+            assert_eq!(tree.subtree_size(v), r - l);
+            for w in 0..n {
+                let inside = (l..r).contains(&hld.pos(w));
+                assert_eq!(tree.lca(v, w) == v, inside);
+                assert_eq!(tree.lca(v, w), hld.lca(v, w));
+            }
+        }
+    }
+}
+
+// Tests path ranges, path folds on a non-commutative monoid and updates against walking the path.
+#[test]
+fn test_path_fold_and_update_random() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let tree = random_tree(&mut rng, n);
+        let hld = HeavyLightDecomposition::new(&tree);
+        let mut values = (0..n)
+            .map(|_| (rng.random_range(1..MOD), rng.random_range(0..MOD)))
+            .collect::<Vec<_>>();
+        let (mut forward, mut backward) = hld.segment_trees::<AffineMonoid>(&values);
+
+        for _ in 0..100 {
+            let u = rng.random_range(0..n);
+            let v = rng.random_range(0..n);
+            let path = (0..=tree.distance(u, v))
+                .map(|k| tree.jump(u, v, k).unwrap())
+                .collect::<Vec<_>>();
+            match rng.random_range(0..3) {
+                0 => {
+                    // Act
+                    let ranges = hld.path_ranges(u, v);
+
+                    // Assert
+                    assert!(ranges.len() <= 2 * (n.ilog2() as usize + 1));
+                    let positions = ranges
+                        .iter()
+                        .flat_map(|&(l, r, reversed)| {
+                            let mut ps = (l..r).collect::<Vec<_>>();
+                            if reversed {
+                                ps.reverse();
+                            }
+                            ps
+                        })
+                        .collect::<Vec<_>>();
+                    let expected = path.iter().map(|&w| hld.pos(w)).collect::<Vec<_>>();
+                    assert_eq!(expected, positions);
+                }
+                1 => {
+                    // Act
+                    let actual = hld.path_fold(&forward, &backward, u, v);
+
+                    // Assert
+                    let expected = path.iter().fold(AffineMonoid::id(), |acc, &w| {
+                        AffineMonoid::op(&acc, &values[w])
+                    });
+                    assert_eq!(expected, actual);
+                }
+                _ => {
+                    // Act
+                    let shift = rng.random_range(0..MOD);
+                    hld.path_update(&mut forward, &mut backward, u, v, |&(a, b)| {
+                        (a, (b + shift) % MOD)
+                    });
+                    let w = rng.random_range(0..n);
+                    let x = (rng.random_range(1..MOD), rng.random_range(0..MOD));
+                    hld.update(&mut forward, &mut backward, w, x);
+
+                    // Assert
+                    for &p in path.iter() {
+                        values[p].1 = (values[p].1 + shift) % MOD;
+                    }
+                    values[w] = x;
+                    for (p, &value) in values.iter().enumerate() {
+                        assert_eq!(value, forward.get(hld.pos(p)));
+                        assert_eq!(value, backward.get(n - 1 - hld.pos(p)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Tests subtree folds against summing over descendants.
+#[test]
+fn test_subtree_fold_random() {
+    use anmitsu::algebra::monoid::AddMonoid;
+
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=40);
+        let tree = random_tree(&mut rng, n);
+        let hld = HeavyLightDecomposition::new(&tree);
+        let values = (0..n)
+            .map(|_| rng.random_range(-100..=100))
+            .collect::<Vec<i64>>();
+        let (forward, _) = hld.segment_trees::<AddMonoid>(&values);
+
+        for v in 0..n {
+            // Act
+            let actual = hld.subtree_fold(&forward, v);
+
+            // Assert
+            let expected = (0..n)
+                .filter(|&w| tree.lca(v, w) == v)
+                .map(|w| values[w])
+                .sum::<i64>();
+            assert_eq!(expected, actual);
+        }
+    }
+}
+
+// Tests that a long path is decomposed without overflowing the stack.
+#[test]
+fn test_long_path() {
+    // Arrange
+    let n = 200_000;
+    let parents = (0..n).map(|v: usize| v.checked_sub(1)).collect::<Vec<_>>();
+    let tree = Tree::from_parents(&parents);
+
+    // Act
+    let hld = HeavyLightDecomposition::new(&tree);
+
+    // Assert
+    assert_eq!(vec![(0, n, true)], hld.path_ranges(n - 1, 0));
+}
+
+// Tests that a wrong number of values panics.
+#[test]
+#[should_panic(
+    expected = "The number of values must equal the number of vertices (values: 1, vertices: 2)"
+)]
+fn test_segment_trees_wrong_length() {
+    let tree = Tree::from_parents(&[None, Some(0)]);
+    HeavyLightDecomposition::new(&tree).segment_trees::<AffineMonoid>(&[(1, 0)]);
+}
@@ -0,0 +1,168 @@
+use anmitsu::graph::max_flow::{Edge, MaxFlow};
+use rand::{self, Rng};
+
+// Computes the minimum capacity of an s-t cut by enumerating every vertex subset.
+fn naive_min_cut(n: usize, edges: &[(usize, usize, i64)], s: usize, t: usize) -> i64 {
+    (0..1usize << n)
+        .filter(|&set| set >> s & 1 == 1 && set >> t & 1 == 0)
+        .map(|set| {
+            edges
+                .iter()
+                .filter(|&&(u, v, _)| set >> u & 1 == 1 && set >> v & 1 == 0)
+                .map(|&(_, _, c)| c)
+                .sum::<i64>()
+        })
+        .min()
+        .unwrap()
+}
+
+// Tests the maximum flow, the flows on the edges and the minimum cut against a brute-force minimum cut.
+#[test]
+fn test_max_flow_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(2..=8);
+        let m = rng.random_range(0..=20);
+        let edges = (0..m)
+            .map(|_| {
+                let u = rng.random_range(0..n);
+                let v = rng.random_range(0..n);
+                (u, v, rng.random_range(0..=10))
+            })
+            .collect::<Vec<_>>();
+        let s = rng.random_range(0..n);
+        let t = (s + rng.random_range(1..n)) % n;
+        let mut mf = MaxFlow::new(n);
+        for &(u, v, c) in edges.iter() {
+            mf.add_edge(u, v, c);
+        }
+
+        // Act
+        let flow = mf.flow(s, t, i64::MAX);
+        let cut = mf.min_cut(s);
+
+        // Assert
+        assert_eq!(naive_min_cut(n, &edges, s, t), flow);
+        let mut excess = vec![0; n];
+        for (i, e) in mf.edges().into_iter().enumerate() {
+            assert_eq!((edges[i].0, edges[i].1, edges[i].2), (e.from, e.to, e.cap));
+            assert!(0 <= e.flow && e.flow <= e.cap);
+            excess[e.from] -= e.flow;
+            excess[e.to] += e.flow;
+        }
+        for (v, &x) in excess.iter().enumerate() {
+            let expected = if v == s {
+                -flow
+            } else if v == t {
+                flow
+            } else {
+                0
+            };
+            assert_eq!(expected, x);
+        }
+        assert!(cut[s] && !cut[t]);
+        let cut_capacity = edges
+            .iter()
+            .filter(|&&(u, v, _)| cut[u] && !cut[v])
+            .map(|&(_, _, c)| c)
+            .sum::<i64>();
+        assert_eq!(flow, cut_capacity);
+    }
+}
+
+// Tests that the flow can be pushed in several calls with limits.
+#[test]
+fn test_flow_with_limit() {
+    // Arrange
+    let mut mf = MaxFlow::new(4);
+    mf.add_edge(0, 1, 3);
+    mf.add_edge(0, 2, 2);
+    mf.add_edge(1, 2, 1);
+    mf.add_edge(1, 3, 2);
+    mf.add_edge(2, 3, 3);
+
+    // Act & Assert
+    assert_eq!(0, mf.flow(0, 3, 0));
+    assert_eq!(2, mf.flow(0, 3, 2));
+    assert_eq!(3, mf.flow(0, 3, 10));
+    assert_eq!(0, mf.flow(0, 3, 10));
+    assert_eq!(vec![true, false, false, false], mf.min_cut(0));
+}
+
+// Tests a bipartite matching as unit-capacity flow.
+#[test]
+fn test_bipartite_matching() {
+    // Arrange
+    let (left, right) = (3, 3);
+    let (s, t) = (left + right, left + right + 1);
+    let mut mf = MaxFlow::new(left + right + 2);
+    let pairs = [(0, 0), (0, 1), (1, 0), (2, 0)];
+    let handles = pairs
+        .iter()
+        .map(|&(a, b)| mf.add_edge(a, left + b, 1))
+        .collect::<Vec<_>>();
+    for a in 0..left {
+        mf.add_edge(s, a, 1);
+    }
+    for b in 0..right {
+        mf.add_edge(left + b, t, 1);
+    }
+
+    // Act
+    let matched = mf.flow(s, t, i64::MAX);
+
+    // Assert
+    assert_eq!(2, matched);
+    let used = handles
+        .iter()
+        .filter(|&&h| mf.get_edge(h).flow == 1)
+        .count();
+    assert_eq!(2, used);
+    assert_eq!(
+        Edge {
+            from: 0,
+            to: 4,
+            cap: 1,
+            flow: 1
+        },
+        mf.get_edge(handles[1])
+    );
+}
+
+// Tests that a flow on a long path does not overflow the stack.
+#[test]
+fn test_long_path() {
+    // Arrange
+    let n = 200_000;
+    let mut mf = MaxFlow::new(n);
+    for v in 0..n - 1 {
+        mf.add_edge(v, v + 1, 1 + (v % 7) as i64);
+    }
+
+    // Act & Assert
+    assert_eq!(1, mf.flow(0, n - 1, i64::MAX));
+}
+
+// Tests that a negative capacity panics.
+#[test]
+#[should_panic(expected = "The capacity must be non-negative")]
+fn test_negative_capacity() {
+    MaxFlow::new(2).add_edge(0, 1, -1);
+}
+
+// Tests that the same source and sink panic.
+#[test]
+#[should_panic(expected = "The source and the sink must be different")]
+fn test_same_source_and_sink() {
+    MaxFlow::<i64>::new(2).flow(1, 1, 5);
+}
+
+// Tests that an out-of-bounds edge handle panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+fn test_get_edge_out_of_bounds() {
+    let mut mf = MaxFlow::new(2);
+    mf.add_edge(0, 1, 1);
+    mf.get_edge(1);
+}
@@ -0,0 +1,153 @@
+use anmitsu::graph::min_cost_flow::MinCostFlow;
+use rand::{self, Rng};
+
+// Computes the minimum cost of every amount of flow by augmenting one unit at a time
+// along a shortest path found by Bellman-Ford on the residual graph.
+fn naive_costs(n: usize, edges: &[(usize, usize, i64, i64)], s: usize, t: usize) -> Vec<i64> {
+    let mut residual = Vec::new();
+    for &(u, v, c, w) in edges.iter() {
+        residual.push((u, v, c, w));
+        residual.push((v, u, 0, -w));
+    }
+
+    let mut costs = vec![0];
+    loop {
+        let mut dist = vec![i64::MAX; n];
+        let mut prev = vec![usize::MAX; n];
+        dist[s] = 0;
+        for _ in 0..n {
+            for (i, &(u, v, c, w)) in residual.iter().enumerate() {
+                if c > 0 && dist[u] != i64::MAX && dist[u] + w < dist[v] {
+                    dist[v] = dist[u] + w;
+                    prev[v] = i;
+                }
+            }
+        }
+        if dist[t] == i64::MAX {
+            return costs;
+        }
+
+        let mut v = t;
+        while v != s {
+            let i = prev[v];
+            residual[i].2 -= 1;
+            residual[i ^ 1].2 += 1;
+            v = residual[i].0;
+        }
+        costs.push(costs.last().unwrap() + dist[t]);
+    }
+}
+
+// Tests the slope against the naive minimum costs of every amount of flow.
+#[test]
+fn test_slope_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(2..=7);
+        let m = rng.random_range(0..=15);
+        let edges = (0..m)
+            .map(|_| {
+                let u = rng.random_range(0..n);
+                let v = rng.random_range(0..n);
+                (u, v, rng.random_range(0..=4), rng.random_range(0..=10))
+            })
+            .collect::<Vec<_>>();
+        let s = rng.random_range(0..n);
+        let t = (s + rng.random_range(1..n)) % n;
+        let limit = rng.random_range(0..=12);
+        let mut mcf = MinCostFlow::new(n);
+        for &(u, v, c, w) in edges.iter() {
+            mcf.add_edge(u, v, c, w);
+        }
+        let costs = naive_costs(n, &edges, s, t);
+
+        // Act
+        let slope = mcf.slope(s, t, limit);
+
+        // Assert
+        let max_flow = (costs.len() as i64 - 1).min(limit);
+        assert_eq!((0, 0), slope[0]);
+        assert_eq!((max_flow, costs[max_flow as usize]), *slope.last().unwrap());
+        for w in slope.windows(2) {
+            let ((f0, c0), (f1, c1)) = (w[0], w[1]);
+            assert!(f0 < f1);
+            for f in f0..=f1 {
+                assert_eq!(costs[f as usize] * (f1 - f0), c0 * (f1 - f) + c1 * (f - f0));
+            }
+        }
+        for w in slope.windows(3) {
+            let ((f0, c0), (f1, c1), (f2, c2)) = (w[0], w[1], w[2]);
+            assert!((c1 - c0) * (f2 - f1) < (c2 - c1) * (f1 - f0));
+        }
+
+        let mut excess = vec![0; n];
+        let mut total = 0;
+        for e in mcf.edges() {
+            assert!(0 <= e.flow && e.flow <= e.cap);
+            excess[e.from] -= e.flow;
+            excess[e.to] += e.flow;
+            total += e.flow * e.cost;
+        }
+        assert_eq!(slope.last().unwrap().1, total);
+        assert_eq!(max_flow, excess[t]);
+    }
+}
+
+// Tests an assignment problem against the best of every permutation.
+#[test]
+fn test_assignment_random() {
+    fn best(cost: &[Vec<i64>], row: usize, used: &mut Vec<bool>) -> i64 {
+        if row == cost.len() {
+            return 0;
+        }
+        let mut result = i64::MAX;
+        for col in 0..cost.len() {
+            if !used[col] {
+                used[col] = true;
+                result = result.min(cost[row][col] + best(cost, row + 1, used));
+                used[col] = false;
+            }
+        }
+        result
+    }
+
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        // Arrange
+        let k = rng.random_range(1..=6);
+        let cost = (0..k)
+            .map(|_| (0..k).map(|_| rng.random_range(0..100)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let (s, t) = (2 * k, 2 * k + 1);
+        let mut mcf = MinCostFlow::new(2 * k + 2);
+        for (i, row) in cost.iter().enumerate() {
+            mcf.add_edge(s, i, 1, 0);
+            mcf.add_edge(k + i, t, 1, 0);
+            for (j, &c) in row.iter().enumerate() {
+                mcf.add_edge(i, k + j, 1, c);
+            }
+        }
+
+        // Act
+        let (flow, total) = mcf.flow(s, t, i64::MAX);
+
+        // Assert
+        assert_eq!(k as i64, flow);
+        assert_eq!(best(&cost, 0, &mut vec![false; k]), total);
+    }
+}
+
+// Tests that a negative cost panics.
+#[test]
+#[should_panic(expected = "The cost must be non-negative")]
+fn test_negative_cost() {
+    MinCostFlow::new(2).add_edge(0, 1, 1, -1);
+}
+
+// Tests that the same source and sink panic.
+#[test]
+#[should_panic(expected = "The source and the sink must be different")]
+fn test_same_source_and_sink() {
+    MinCostFlow::new(2).flow(0, 0, 1);
+}
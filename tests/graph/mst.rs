@@ -0,0 +1,152 @@
+use anmitsu::{
+    ds::union_find::UnionFind,
+    graph::mst::{kruskal_mst, minimum_spanning_forest, mst_with_forced_edges, second_best_mst},
+};
+use rand::{self, Rng};
+
+// Returns the weights of all spanning trees as (weight, sorted edge indices) by brute force.
+fn all_spanning_trees(n: usize, edges: &[(usize, usize, i64)]) -> Vec<(i64, Vec<usize>)> {
+    let m = edges.len();
+    let mut res = Vec::new();
+    for mask in 0_usize..1 << m {
+        if mask.count_ones() as usize + 1 != n {
+            continue;
+        }
+        let mut uf = UnionFind::new(n);
+        let mut ok = true;
+        let mut total = 0;
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            if mask >> i & 1 == 1 {
+                if uf.is_same(u, v) {
+                    ok = false;
+                    break;
+                }
+                uf.union(u, v);
+                total += w;
+            }
+        }
+        if ok {
+            res.push((total, (0..m).filter(|&i| mask >> i & 1 == 1).collect()));
+        }
+    }
+    res
+}
+
+// Helper function to generate random edges.
+fn rand_edges(rng: &mut impl Rng, n: usize, m: usize) -> Vec<(usize, usize, i64)> {
+    (0..m)
+        .map(|_| {
+            (
+                rng.random_range(0..n),
+                rng.random_range(0..n),
+                rng.random_range(-5..=5),
+            )
+        })
+        .collect()
+}
+
+// Tests a small fixed graph.
+#[test]
+fn test_kruskal_mst_fixed() {
+    // Arrange
+    let edges = vec![
+        (0, 1, 7),
+        (0, 2, 3),
+        (1, 2, 1),
+        (2, 3, 5),
+        (1, 3, 2),
+        (3, 3, 0),
+    ];
+
+    // Act
+    let (total, chosen) = kruskal_mst(4, &edges).unwrap();
+
+    // Assert
+    assert_eq!(6, total);
+    assert_eq!(vec![2, 4, 1], chosen);
+}
+
+// Tests the edge cases of empty and single-vertex graphs.
+#[test]
+fn test_trivial_graphs() {
+    assert_eq!(Some((0, vec![])), kruskal_mst::<i64>(0, &[]));
+    assert_eq!(Some((0, vec![])), kruskal_mst::<i64>(1, &[]));
+    assert_eq!(None, kruskal_mst::<i64>(2, &[]));
+    assert_eq!((0, vec![]), minimum_spanning_forest::<i64>(3, &[]));
+    assert_eq!(None, second_best_mst(1, &[(0, 0, 1)]));
+    assert_eq!(None, second_best_mst::<i64>(0, &[]));
+    assert_eq!(None, second_best_mst::<i64>(1, &[]));
+    assert_eq!(None, second_best_mst(3, &[(0, 1, 1), (1, 2, 2)]));
+}
+
+// Tests that forced edges forming a cycle are rejected.
+#[test]
+fn test_forced_edges_with_cycle() {
+    let edges = vec![(0, 1, 1), (1, 2, 1), (2, 0, 1), (2, 3, 1)];
+    assert_eq!(None, mst_with_forced_edges(4, &edges, &[0, 1, 2]));
+    assert_eq!(None, mst_with_forced_edges(4, &edges, &[3, 3]));
+    assert_eq!(
+        Some((3, vec![2, 1, 3])),
+        mst_with_forced_edges(4, &edges, &[2, 1])
+    );
+}
+
+// Tests that an out-of-bounds forced edge panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+fn test_forced_edge_out_of_bounds() {
+    mst_with_forced_edges(2, &[(0, 1, 1)], &[1]);
+}
+
+// Performs a randomized comparison with brute force enumeration of spanning trees.
+#[test]
+fn test_randomized_comparison() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(1..=5);
+        let m = rng.random_range(0..=8);
+        let edges = rand_edges(&mut rng, n, m);
+        let trees = all_spanning_trees(n, &edges);
+
+        // Act
+        let mst = kruskal_mst(n, &edges);
+        let second = second_best_mst(n, &edges);
+
+        // Assert
+        let best = trees.iter().map(|t| t.0).min();
+        assert_eq!(best, mst.as_ref().map(|t| t.0));
+        if let Some((total, chosen)) = mst {
+            let mut chosen = chosen;
+            chosen.sort();
+            assert_eq!(
+                Some(total),
+                trees.iter().find(|t| t.1 == chosen).map(|t| t.0)
+            );
+            let expected = trees.iter().filter(|t| t.1 != chosen).map(|t| t.0).min();
+            assert_eq!(expected, second);
+
+            if m > 0 {
+                let forced = rng.random_range(0..m);
+                let expected = trees
+                    .iter()
+                    .filter(|t| t.1.contains(&forced))
+                    .map(|t| t.0)
+                    .min();
+                assert_eq!(
+                    expected,
+                    mst_with_forced_edges(n, &edges, &[forced]).map(|t| t.0)
+                );
+            }
+        }
+
+        let (forest_total, forest) = minimum_spanning_forest(n, &edges);
+        let mut uf = UnionFind::new(n);
+        for &(u, v, _) in edges.iter() {
+            uf.union(u, v);
+        }
+        let components = (0..n).filter(|&v| uf.is_root(v)).count();
+        assert_eq!(n - components, forest.len());
+        assert_eq!(forest_total, forest.iter().map(|&i| edges[i].2).sum());
+    }
+}
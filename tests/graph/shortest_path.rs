@@ -0,0 +1,284 @@
+use anmitsu::algebra::path_monoid::{MaxMinMonoid, MinPlusMonoid, ShortestPathCountMonoid};
+use anmitsu::ds::modint::modint998244353::ModInt998244353;
+use anmitsu::graph::{Graph, shortest_path};
+use rand::{self, Rng};
+
+const INF: i64 = i64::MAX / 4;
+
+fn random_graph(rng: &mut impl Rng, n: usize, m: usize, weights: (i64, i64)) -> Graph<i64> {
+    let edges = (0..m)
+        .map(|_| {
+            let u = rng.random_range(0..n);
+            let v = rng.random_range(0..n);
+            (u, v, rng.random_range(weights.0..=weights.1))
+        })
+        .collect();
+    if rng.random_bool(0.5) {
+        Graph::directed(n, edges)
+    } else {
+        Graph::undirected(n, edges)
+    }
+}
+
+// Returns `walk[k][i][j]`, the minimum weight of a walk from `i` to `j` with at most `k` edges.
+fn naive_walks(g: &Graph<i64>) -> Vec<Vec<Vec<i64>>> {
+    let n = g.num_vertices();
+    let mut walk = vec![vec![vec![INF; n]; n]];
+    for (i, row) in walk[0].iter_mut().enumerate() {
+        row[i] = 0;
+    }
+    for k in 0..n {
+        let mut next = walk[k].clone();
+        for (i, row) in next.iter_mut().enumerate() {
+            for v in (0..n).filter(|&v| walk[k][i][v] < INF) {
+                for &(to, e) in g.out_edges(v) {
+                    row[to] = row[to].min(walk[k][i][v] + g.edges()[e].2);
+                }
+            }
+        }
+        walk.push(next);
+    }
+    walk
+}
+
+// Checks that `path` follows edges of `g` and that its total weight is `dist`.
+fn assert_valid_path(g: &Graph<i64>, path: &[usize], dist: i64) {
+    let total = path
+        .windows(2)
+        .map(|w| {
+            g.out_edges(w[0])
+                .iter()
+                .filter(|&&(to, _)| to == w[1])
+                .map(|&(_, e)| g.edges()[e].2)
+                .min()
+                .expect("path uses a missing edge")
+        })
+        .sum::<i64>();
+    assert_eq!(dist, total);
+}
+
+fn to_option(d: i64) -> Option<i64> {
+    (d < INF).then_some(d)
+}
+
+// Tests Dijkstra, dense Dijkstra, SPFA and Floyd-Warshall against naive distances with non-negative weights.
+#[test]
+fn test_min_plus_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=10);
+        let m = rng.random_range(0..=25);
+        let g = random_graph(&mut rng, n, m, (0, 10));
+        let walk = naive_walks(&g);
+        let all = shortest_path::floyd_warshall::<MinPlusMonoid>(&g).unwrap();
+
+        for (s, walk_s) in walk[n].iter().enumerate() {
+            // Act
+            let expected = walk_s.iter().map(|&d| to_option(d)).collect::<Vec<_>>();
+            let heap = shortest_path::dijkstra::<MinPlusMonoid>(&g, s);
+            let dense = shortest_path::dijkstra_dense::<MinPlusMonoid>(&g, s);
+            let queue = shortest_path::spfa::<MinPlusMonoid>(&g, s).unwrap();
+            let (bellman, affected) = shortest_path::bellman_ford::<MinPlusMonoid>(&g, s);
+
+            // Assert
+            assert_eq!(expected, heap.dist);
+            assert_eq!(expected, dense.dist);
+            assert_eq!(expected, queue.dist);
+            assert_eq!(expected, bellman.dist);
+            assert_eq!(expected, all.dist[s]);
+            assert!(affected.is_empty());
+            for (t, &d) in expected.iter().enumerate() {
+                let Some(d) = d else {
+                    assert_eq!(None, heap.path(t));
+                    assert_eq!(None, all.path(s, t));
+                    continue;
+                };
+                for paths in [&heap, &dense, &queue, &bellman] {
+                    let path = paths.path(t).unwrap();
+                    assert_eq!((s, t), (path[0], *path.last().unwrap()));
+                    assert_valid_path(&g, &path, d);
+                }
+                let path = all.path(s, t).unwrap();
+                assert_eq!((s, t), (path[0], *path.last().unwrap()));
+                assert_valid_path(&g, &path, d);
+            }
+        }
+    }
+}
+
+// Tests Bellman-Ford, SPFA and Floyd-Warshall against naive walks with negative weights.
+#[test]
+fn test_negative_weights_random() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        // Arrange
+        let n = rng.random_range(1..=8);
+        let m = rng.random_range(0..=12);
+        let edges = (0..m)
+            .map(|_| {
+                let u = rng.random_range(0..n);
+                let v = rng.random_range(0..n);
+                (u, v, rng.random_range(-3..=10))
+            })
+            .collect();
+        let g = Graph::directed(n, edges);
+        let walk = naive_walks(&g);
+        let on_negative_cycle = (0..n).map(|c| walk[n][c][c] < 0).collect::<Vec<_>>();
+
+        // Act
+        let all = shortest_path::floyd_warshall::<MinPlusMonoid>(&g);
+
+        // Assert
+        assert_eq!(on_negative_cycle.contains(&true), all.is_none());
+        for s in 0..n {
+            // Act
+            let (bellman, affected) = shortest_path::bellman_ford::<MinPlusMonoid>(&g, s);
+            let queue = shortest_path::spfa::<MinPlusMonoid>(&g, s);
+
+            // Assert
+            let expected_affected = (0..n)
+                .filter(|&v| {
+                    (0..n)
+                        .any(|c| on_negative_cycle[c] && walk[n][s][c] < INF && walk[n][c][v] < INF)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(expected_affected, affected);
+            assert_eq!(affected.is_empty(), queue.is_some());
+            for (v, &dist) in bellman.dist.iter().enumerate() {
+                let expected = if affected.contains(&v) {
+                    None
+                } else {
+                    to_option(walk[n - 1][s][v])
+                };
+                assert_eq!(expected, dist);
+                if let Some(d) = expected {
+                    assert_valid_path(&g, &bellman.path(v).unwrap(), d);
+                }
+            }
+            if let Some(queue) = queue {
+                assert_eq!(bellman.dist, queue.dist);
+            }
+        }
+    }
+}
+
+// Tests bottleneck paths against the largest threshold at which the destination is reachable.
+#[test]
+fn test_max_min_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=10);
+        let m = rng.random_range(0..=20);
+        let g = random_graph(&mut rng, n, m, (1, 10));
+        let s = rng.random_range(0..n);
+        let reachable = |threshold: i64| {
+            let mut seen = vec![false; n];
+            let mut stack = vec![s];
+            seen[s] = true;
+            while let Some(v) = stack.pop() {
+                for &(to, e) in g.out_edges(v) {
+                    if g.edges()[e].2 >= threshold && !seen[to] {
+                        seen[to] = true;
+                        stack.push(to);
+                    }
+                }
+            }
+            seen
+        };
+        let expected = (0..n)
+            .map(|t| {
+                if t == s {
+                    Some(i64::MAX)
+                } else {
+                    (1..=10).rev().find(|&th| reachable(th)[t])
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Act
+        let heap = shortest_path::dijkstra::<MaxMinMonoid>(&g, s);
+        let dense = shortest_path::dijkstra_dense::<MaxMinMonoid>(&g, s);
+        let all = shortest_path::floyd_warshall::<MaxMinMonoid>(&g).unwrap();
+
+        // Assert
+        assert_eq!(expected, heap.dist);
+        assert_eq!(expected, dense.dist);
+        assert_eq!(expected, all.dist[s]);
+    }
+}
+
+// Tests counting shortest paths against enumerating every simple path.
+#[test]
+fn test_shortest_path_count_random() {
+    fn enumerate(g: &Graph<i64>, v: usize, len: i64, on_path: &mut [bool], out: &mut [Vec<i64>]) {
+        out[v].push(len);
+        on_path[v] = true;
+        for &(to, e) in g.out_edges(v) {
+            if !on_path[to] {
+                enumerate(g, to, len + g.edges()[e].2, on_path, out);
+            }
+        }
+        on_path[v] = false;
+    }
+
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=7);
+        let m = rng.random_range(0..=14);
+        let g = random_graph(&mut rng, n, m, (1, 3));
+        let counted = Graph::directed(
+            n,
+            (0..n)
+                .flat_map(|v| g.out_edges(v).iter().map(move |&(to, e)| (v, to, e)))
+                .map(|(v, to, e)| (v, to, (g.edges()[e].2, ModInt998244353::new(1))))
+                .collect(),
+        );
+        let s = rng.random_range(0..n);
+        let mut lengths = vec![Vec::new(); n];
+        enumerate(&g, s, 0, &mut vec![false; n], &mut lengths);
+        let expected = lengths
+            .iter()
+            .map(|ls| {
+                let min = *ls.iter().min()?;
+                let count = ls.iter().filter(|&&l| l == min).count();
+                Some((min, ModInt998244353::new(count as u64)))
+            })
+            .collect::<Vec<_>>();
+
+        // Act
+        let heap = shortest_path::dijkstra::<ShortestPathCountMonoid>(&counted, s);
+        let dense = shortest_path::dijkstra_dense::<ShortestPathCountMonoid>(&counted, s);
+        let all = shortest_path::floyd_warshall::<ShortestPathCountMonoid>(&counted).unwrap();
+
+        // Assert
+        assert_eq!(expected, heap.dist);
+        assert_eq!(expected, dense.dist);
+        assert_eq!(expected, all.dist[s]);
+    }
+}
+
+// Tests that an undirected negative edge is a negative cycle affecting its whole component.
+#[test]
+fn test_undirected_negative_edge() {
+    // Arrange
+    let g = Graph::undirected(3, vec![(0, 1, 4), (1, 2, -1)]);
+
+    // Act
+    let (paths, affected) = shortest_path::bellman_ford::<MinPlusMonoid>(&g, 0);
+
+    // Assert
+    assert_eq!(vec![0, 1, 2], affected);
+    assert_eq!(vec![None, None, None], paths.dist);
+    assert_eq!(None, shortest_path::spfa::<MinPlusMonoid>(&g, 0));
+}
+
+// Tests that reconstructing a path to an out-of-bounds vertex panics.
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_path_out_of_bounds() {
+    let g = Graph::directed(2, vec![(0, 1, 1)]);
+    shortest_path::dijkstra::<MinPlusMonoid>(&g, 0).path(2);
+}
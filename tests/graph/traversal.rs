@@ -0,0 +1,154 @@
+use anmitsu::graph::{Graph, traversal};
+use rand::{self, Rng};
+
+fn random_edges<W>(
+    rng: &mut impl Rng,
+    n: usize,
+    m: usize,
+    mut weight: impl FnMut(&mut dyn rand::RngCore) -> W,
+) -> Vec<(usize, usize, W)> {
+    (0..m)
+        .map(|_| {
+            let u = rng.random_range(0..n);
+            let v = rng.random_range(0..n);
+            (u, v, weight(rng))
+        })
+        .collect()
+}
+
+// Computes all distances from `s` by relaxing every edge n times.
+fn naive_distances(g: &Graph<u8>, s: usize) -> Vec<Option<usize>> {
+    let mut dist = vec![None; g.num_vertices()];
+    dist[s] = Some(0);
+    for _ in 0..g.num_vertices() {
+        for v in 0..g.num_vertices() {
+            for &(to, e) in g.out_edges(v) {
+                if let Some(d) = dist[v] {
+                    let nd = d + g.edges()[e].2 as usize;
+                    if dist[to].is_none_or(|old| nd < old) {
+                        dist[to] = Some(nd);
+                    }
+                }
+            }
+        }
+    }
+    dist
+}
+
+// Tests BFS against the naive distances with unit weights.
+#[test]
+fn test_bfs_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=20);
+        let m = rng.random_range(0..=40);
+        let edges = random_edges(&mut rng, n, m, |_| 1u8);
+        let g = if rng.random_bool(0.5) {
+            Graph::directed(n, edges)
+        } else {
+            Graph::undirected(n, edges)
+        };
+        let s = rng.random_range(0..n);
+
+        // Act & Assert
+        assert_eq!(naive_distances(&g, s), traversal::bfs(&g, s));
+    }
+}
+
+// Tests 0-1 BFS against the naive distances.
+#[test]
+fn test_zero_one_bfs_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=20);
+        let m = rng.random_range(0..=40);
+        let edges = random_edges(&mut rng, n, m, |r| (r.next_u32() % 2) as u8);
+        let g = if rng.random_bool(0.5) {
+            Graph::directed(n, edges)
+        } else {
+            Graph::undirected(n, edges)
+        };
+        let s = rng.random_range(0..n);
+
+        // Act & Assert
+        assert_eq!(naive_distances(&g, s), traversal::zero_one_bfs(&g, s));
+    }
+}
+
+// Tests that 0-1 BFS rejects a weight other than 0 or 1.
+#[test]
+#[should_panic(expected = "0-1 BFS requires every weight to be 0 or 1")]
+fn test_zero_one_bfs_invalid_weight() {
+    let g = Graph::directed(2, vec![(0, 1, 2u8)]);
+    traversal::zero_one_bfs(&g, 0);
+}
+
+// Tests that the iterative DFS visits vertices in the same order as a recursive DFS.
+#[test]
+fn test_dfs_matches_recursive() {
+    fn recurse(
+        g: &Graph<()>,
+        v: usize,
+        visited: &mut [bool],
+        pre: &mut Vec<usize>,
+        post: &mut Vec<usize>,
+    ) {
+        visited[v] = true;
+        pre.push(v);
+        for to in g.neighbors(v) {
+            if !visited[to] {
+                recurse(g, to, visited, pre, post);
+            }
+        }
+        post.push(v);
+    }
+
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=20);
+        let m = rng.random_range(0..=40);
+        let edges = random_edges(&mut rng, n, m, |_| ());
+        let g = if rng.random_bool(0.5) {
+            Graph::directed(n, edges)
+        } else {
+            Graph::undirected(n, edges)
+        };
+        let s = rng.random_range(0..n);
+        let (mut visited, mut pre, mut post) = (vec![false; n], vec![], vec![]);
+        recurse(&g, s, &mut visited, &mut pre, &mut post);
+
+        // Act
+        let tree = traversal::dfs(&g, s);
+
+        // Assert
+        assert_eq!(pre, tree.preorder);
+        assert_eq!(post, tree.postorder);
+        assert_eq!(None, tree.parent[s]);
+        for &v in tree.preorder.iter().skip(1) {
+            let p = tree.parent[v].unwrap();
+            assert!(g.neighbors(p).any(|to| to == v));
+        }
+    }
+}
+
+// Tests that the traversals handle a path of 10^6 vertices without overflowing the stack.
+#[test]
+fn test_traversals_on_long_path() {
+    // Arrange
+    let n = 1_000_000;
+    let g = Graph::undirected(n, (0..n - 1).map(|i| (i, i + 1, 1u8)).collect());
+
+    // Act
+    let dist = traversal::bfs(&g, 0);
+    let tree = traversal::dfs(&g, 0);
+    let dist01 = traversal::zero_one_bfs(&g, n - 1);
+
+    // Assert
+    assert_eq!(Some(n - 1), dist[n - 1]);
+    assert_eq!(n, tree.preorder.len());
+    assert_eq!(0, tree.postorder[n - 1]);
+    assert_eq!(Some(n - 1), dist01[0]);
+}
@@ -0,0 +1,196 @@
+use anmitsu::graph::tree::Tree;
+use rand::{self, Rng};
+
+// Generates a random tree as parents, where the root is random and every other vertex hangs below an earlier one.
+fn random_parents(rng: &mut impl Rng, n: usize) -> Vec<Option<usize>> {
+    let mut perm = (0..n).collect::<Vec<_>>();
+    for i in (1..n).rev() {
+        perm.swap(i, rng.random_range(0..=i));
+    }
+    let mut parents = vec![None; n];
+    for i in 1..n {
+        parents[perm[i]] = Some(perm[rng.random_range(0..i)]);
+    }
+    parents
+}
+
+// Returns the path from `v` up to the root.
+fn naive_ancestors(parents: &[Option<usize>], mut v: usize) -> Vec<usize> {
+    let mut path = vec![v];
+    while let Some(p) = parents[v] {
+        path.push(p);
+        v = p;
+    }
+    path
+}
+
+// Returns the path from `u` to `v` by walking up to their lowest common ancestor.
+fn naive_path(parents: &[Option<usize>], u: usize, v: usize) -> Vec<usize> {
+    let up = naive_ancestors(parents, u);
+    let mut down = naive_ancestors(parents, v);
+    let w = *up.iter().find(|a| down.contains(a)).unwrap();
+    let mut path = up.into_iter().take_while(|&a| a != w).collect::<Vec<_>>();
+    path.push(w);
+    down.truncate(down.iter().position(|&a| a == w).unwrap());
+    path.extend(down.into_iter().rev());
+    path
+}
+
+// Tests the ancestor and path queries against walking up the parents.
+#[test]
+fn test_queries_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let parents = random_parents(&mut rng, n);
+        let tree = Tree::from_parents(&parents);
+
+        // Act & Assert
+        for u in 0..n {
+            let ancestors = naive_ancestors(&parents, u);
+            assert_eq!(parents[u], tree.parent(u));
+            assert_eq!(ancestors.len() - 1, tree.depth(u));
+            for k in 0..=n {
+                assert_eq!(ancestors.get(k).copied(), tree.kth_ancestor(u, k));
+            }
+            let size = (0..n)
+                .filter(|&v| naive_ancestors(&parents, v).contains(&u))
+                .count();
+            assert_eq!(size, tree.subtree_size(u));
+            let (l, r) = tree.subtree_range(u);
+            assert_eq!(size, r - l);
+            assert!(
+                tree.preorder()[l..r]
+                    .iter()
+                    .all(|&v| naive_ancestors(&parents, v).contains(&u))
+            );
+            assert_eq!(u, tree.preorder()[tree.preorder_index(u)]);
+
+            for v in 0..n {
+                let path = naive_path(&parents, u, v);
+                let w = *naive_ancestors(&parents, u)
+                    .iter()
+                    .find(|a| naive_ancestors(&parents, v).contains(a))
+                    .unwrap();
+                assert_eq!(w, tree.lca(u, v));
+                assert_eq!(w, tree.lca_euler(u, v));
+                assert_eq!(path.len() - 1, tree.distance(u, v));
+                for k in 0..=n {
+                    assert_eq!(path.get(k).copied(), tree.jump(u, v, k));
+                }
+            }
+        }
+    }
+}
+
+// Tests the children lists and the construction from edges.
+#[test]
+fn test_from_edges_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let parents = random_parents(&mut rng, n);
+        let root = (0..n).find(|&v| parents[v].is_none()).unwrap();
+        let mut edges = (0..n)
+            .filter_map(|v| Some((parents[v]?, v)))
+            .map(|(p, v)| if rng.random_bool(0.5) { (p, v) } else { (v, p) })
+            .collect::<Vec<_>>();
+        for i in (1..edges.len()).rev() {
+            edges.swap(i, rng.random_range(0..=i));
+        }
+
+        // Act
+        let tree = Tree::from_edges(n, &edges, root);
+
+        // Assert
+        assert_eq!(root, tree.root());
+        for v in 0..n {
+            assert_eq!(parents[v], tree.parent(v));
+            let children = (0..n)
+                .filter(|&c| parents[c] == Some(v))
+                .collect::<Vec<_>>();
+            assert_eq!(children, tree.children(v));
+        }
+    }
+}
+
+// Tests the diameter and the center against the eccentricities of all vertices.
+#[test]
+fn test_diameter_and_center_random() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        // Arrange
+        let n = rng.random_range(1..=30);
+        let tree = Tree::from_parents(&random_parents(&mut rng, n));
+        let eccentricity = (0..n)
+            .map(|u| (0..n).map(|v| tree.distance(u, v)).max().unwrap())
+            .collect::<Vec<_>>();
+
+        // Act
+        let (len, path) = tree.diameter();
+        let center = tree.center();
+
+        // Assert
+        assert_eq!(*eccentricity.iter().max().unwrap(), len);
+        assert_eq!(len + 1, path.len());
+        assert!(path.windows(2).all(|w| tree.distance(w[0], w[1]) == 1));
+        assert_eq!(len, tree.distance(path[0], path[len]));
+        let radius = *eccentricity.iter().min().unwrap();
+        let expected = (0..n)
+            .filter(|&v| eccentricity[v] == radius)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, center);
+    }
+}
+
+// Tests that a path of 10^6 vertices does not overflow the stack.
+#[test]
+fn test_long_path() {
+    // Arrange
+    let n = 1_000_000;
+    let parents = (0..n).map(|v: usize| v.checked_sub(1)).collect::<Vec<_>>();
+
+    // Act
+    let tree = Tree::from_parents(&parents);
+
+    // Assert
+    assert_eq!(n - 1, tree.depth(n - 1));
+    assert_eq!(n, tree.subtree_size(0));
+    assert_eq!(12345, tree.lca(12345, n - 1));
+    assert_eq!(12345, tree.lca_euler(n - 1, 12345));
+    assert_eq!(Some(500_000), tree.jump(n - 1, 0, n - 1 - 500_000));
+}
+
+// Tests that parents with a cycle panic.
+#[test]
+#[should_panic(expected = "The parents must form a tree with exactly one root")]
+fn test_parents_with_cycle() {
+    Tree::from_parents(&[None, Some(2), Some(1)]);
+}
+
+// Tests that parents with two roots panic.
+#[test]
+#[should_panic(expected = "The parents must form a tree with exactly one root")]
+fn test_parents_with_two_roots() {
+    Tree::from_parents(&[None, None]);
+}
+
+// Tests that a disconnected edge list panics.
+#[test]
+#[should_panic(expected = "The edges must form a tree")]
+fn test_edges_not_tree() {
+    Tree::from_edges(4, &[(0, 1), (1, 0), (2, 3)], 0);
+}
+
+// Tests that the top-level `tree` module re-exports the same `Tree`.
+#[test]
+fn test_top_level_tree_module() {
+    // Arrange
+    let tree: Tree = anmitsu::tree::Tree::from_edges(4, &[(0, 1), (1, 2), (1, 3)], 0);
+
+    // Act & Assert
+    assert_eq!(1, tree.lca(2, 3));
+    assert_eq!(2, tree.distance(2, 3));
+}
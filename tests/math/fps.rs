@@ -0,0 +1,290 @@
+// tests/math/fps.rs
+use anmitsu::{ds::modint::modint998244353::ModInt998244353, math::fps::Fps};
+use rand::{self, Rng};
+
+type Mint = ModInt998244353;
+
+// Helper function to build a series from small coefficients.
+fn fps(coef: &[u32]) -> Fps {
+    Fps::new(coef.iter().map(|&c| Mint::new(c as u64)).collect())
+}
+
+// Helper function to build a random series of length n.
+fn rand_fps(rng: &mut impl Rng, n: usize) -> Fps {
+    Fps::new(
+        (0..n)
+            .map(|_| Mint::new(rng.random_range(0..998244353)))
+            .collect(),
+    )
+}
+
+// Naive O(NM) multiplication.
+fn naive_mul(a: &Fps, b: &Fps) -> Fps {
+    if a.is_empty() || b.is_empty() {
+        return Fps::new(Vec::new());
+    }
+    let mut c = vec![Mint::new(0); a.len() + b.len() - 1];
+    for (i, &x) in a.coef().iter().enumerate() {
+        for (j, &y) in b.coef().iter().enumerate() {
+            c[i + j] += x * y;
+        }
+    }
+    Fps::new(c)
+}
+
+// Tests basic accessors and `prefix`.
+#[test]
+fn test_new_len_prefix() {
+    // Arrange
+    let f = fps(&[1, 2, 3]);
+
+    // Act & Assert
+    assert_eq!(3, f.len());
+    assert!(!f.is_empty());
+    assert!(Fps::new(Vec::new()).is_empty());
+    assert_eq!(fps(&[1, 2]), f.prefix(2));
+    assert_eq!(fps(&[1, 2, 3, 0, 0]), f.prefix(5));
+    assert_eq!(fps(&[]), f.prefix(0));
+}
+
+// Tests coefficient-wise addition, subtraction, negation and scalar multiplication.
+#[test]
+fn test_add_sub_neg_scalar() {
+    // Arrange
+    let f = fps(&[1, 2, 3]);
+    let g = fps(&[5, 7]);
+
+    // Act & Assert
+    assert_eq!(fps(&[6, 9, 3]), f.clone() + g.clone());
+    assert_eq!(fps(&[0, 0, 3]), f.clone() - fps(&[1, 2]));
+    assert_eq!(fps(&[0, 0]), -fps(&[0, 0]));
+    assert_eq!(fps(&[2, 4, 6]), f * Mint::new(2));
+    assert_eq!(fps(&[998244352]), -fps(&[1]));
+}
+
+// Performs a randomized comparison of `Mul` with naive multiplication.
+#[test]
+fn test_mul_randomized() {
+    // Arrange
+    let mut rng = rand::rng();
+    for _ in 0..20 {
+        let n = rng.random_range(0..100);
+        let m = rng.random_range(0..100);
+        let f = rand_fps(&mut rng, n);
+        let g = rand_fps(&mut rng, m);
+
+        // Act & Assert
+        assert_eq!(naive_mul(&f, &g), f * g);
+    }
+}
+
+// Tests `derivative` and `integral`, including that they are inverse to each other.
+#[test]
+fn test_derivative_integral() {
+    // Arrange
+    let mut rng = rand::rng();
+    let f = rand_fps(&mut rng, 50);
+
+    // Act & Assert
+    assert_eq!(fps(&[2, 6]), fps(&[1, 2, 3]).derivative());
+    assert_eq!(fps(&[]), fps(&[]).derivative());
+    assert_eq!(fps(&[0]), fps(&[]).integral());
+    assert_eq!(f, f.integral().derivative());
+}
+
+// Tests that `f * f.inv(n)` is 1 modulo x^n.
+#[test]
+fn test_inv_randomized() {
+    // Arrange
+    let mut rng = rand::rng();
+    for n in [0, 1, 2, 3, 10, 64, 100] {
+        let mut f = rand_fps(&mut rng, n.max(1));
+        if f.coef()[0] == Mint::new(0) {
+            f = f + fps(&[1]);
+        }
+
+        // Act
+        let g = f.inv(n);
+
+        // Assert
+        assert_eq!(n, g.len());
+        assert_eq!(fps(&[1]).prefix(n), (f * g).prefix(n));
+    }
+}
+
+// Tests that `inv` panics on a zero constant term.
+#[test]
+#[should_panic(expected = "The constant term must be non-zero to compute inv")]
+fn test_inv_zero_constant_term() {
+    fps(&[0, 1]).inv(3);
+}
+
+// Tests `log` and `exp` on known series and their mutual inverse relation.
+#[test]
+fn test_log_exp() {
+    // Arrange
+    let mut rng = rand::rng();
+    let n = 100;
+    let mut coef = rand_fps(&mut rng, n).coef().to_vec();
+    coef[0] = Mint::new(0);
+    let g = Fps::new(coef);
+
+    // Act & Assert: exp(x) = Σ x^k / k!
+    let e = fps(&[0, 1]).exp(10);
+    let mut fact = Mint::new(1);
+    for k in 0..10 {
+        if k > 0 {
+            fact *= k as u32;
+        }
+        assert_eq!(Mint::new(1) / fact, e.coef()[k]);
+    }
+    assert_eq!(g, g.exp(n).log(n));
+    assert_eq!(fps(&[]), fps(&[1]).log(0));
+    assert_eq!(fps(&[]), fps(&[0]).exp(0));
+}
+
+// Tests that `log` panics unless the constant term is one.
+#[test]
+#[should_panic(expected = "The constant term must be one to compute log")]
+fn test_log_invalid_constant_term() {
+    fps(&[2, 1]).log(3);
+}
+
+// Tests that `exp` panics unless the constant term is zero.
+#[test]
+#[should_panic(expected = "The constant term must be zero to compute exp")]
+fn test_exp_invalid_constant_term() {
+    fps(&[1, 1]).exp(3);
+}
+
+// Performs a randomized comparison of `pow` with repeated multiplication.
+#[test]
+fn test_pow_randomized() {
+    // Arrange
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        let n = rng.random_range(1..40);
+        let zeros = rng.random_range(0..4);
+        let mut coef = vec![Mint::new(0); zeros];
+        coef.extend(
+            {
+                let len = rng.random_range(1..10);
+                rand_fps(&mut rng, len)
+            }
+            .coef(),
+        );
+        let f = Fps::new(coef);
+        let k = rng.random_range(0..8);
+
+        // Act
+        let actual = f.pow(k, n);
+
+        // Assert
+        let mut expected = fps(&[1]);
+        for _ in 0..k {
+            expected = naive_mul(&expected, &f).prefix(n);
+        }
+        assert_eq!(expected.prefix(n), actual);
+    }
+}
+
+// Tests `pow` edge cases: the zero series, zero exponent and huge exponents.
+#[test]
+fn test_pow_edge_cases() {
+    assert_eq!(fps(&[1, 0, 0]), fps(&[0, 0]).pow(0, 3));
+    assert_eq!(fps(&[0, 0, 0]), fps(&[0, 0]).pow(1, 3));
+    assert_eq!(fps(&[0, 0, 0]), fps(&[0, 1]).pow(u64::MAX, 3));
+    // (1 + x)^(p) = 1 + x^p ≡ 1 mod x^3.
+    assert_eq!(fps(&[1, 0, 0]), fps(&[1, 1]).pow(998244353, 3));
+    assert_eq!(fps(&[]), fps(&[1, 1]).pow(5, 0));
+}
+
+// Tests that `sqrt` returns a square root whenever one exists.
+#[test]
+fn test_sqrt_randomized() {
+    // Arrange
+    let mut rng = rand::rng();
+    for _ in 0..20 {
+        let n = rng.random_range(1..60);
+        let zeros = rng.random_range(0..3);
+        let mut coef = vec![Mint::new(0); zeros];
+        coef.extend(rand_fps(&mut rng, n).coef());
+        let g = Fps::new(coef);
+        let f = (g.clone() * g).prefix(n);
+
+        // Act
+        let h = f.sqrt(n).unwrap();
+
+        // Assert
+        assert_eq!(n, h.len());
+        assert_eq!(f, (h.clone() * h).prefix(n));
+    }
+}
+
+// Tests that `sqrt` returns `None` when no square root exists.
+#[test]
+fn test_sqrt_none() {
+    // 3 is a quadratic non-residue modulo 998244353.
+    assert!(fps(&[3, 1]).sqrt(2).is_none());
+    assert!(fps(&[0, 1]).sqrt(2).is_none());
+    assert_eq!(
+        Some(fps(&[0, 0])),
+        fps(&[0, 0, 1]).sqrt(2).map(|h| h.prefix(2))
+    );
+    assert_eq!(Some(fps(&[0, 0, 0])), fps(&[]).sqrt(3));
+}
+
+// Performs a randomized check of `div_rem`, `Div` and `Rem`.
+#[test]
+fn test_div_rem_randomized() {
+    // Arrange
+    let mut rng = rand::rng();
+    for _ in 0..30 {
+        let f = {
+            let len = rng.random_range(0..60);
+            rand_fps(&mut rng, len)
+        };
+        let len = rng.random_range(1..30);
+        let mut coef = rand_fps(&mut rng, len).coef().to_vec();
+        // Keep the leading coefficient non-zero.
+        coef[len - 1] = Mint::new(rng.random_range(1..998244353));
+        let g = Fps::new(coef);
+
+        // Act
+        let (q, r) = f.div_rem(&g);
+
+        // Assert
+        assert!(r.len() < g.len());
+        let mut lhs = (q.clone() * g.clone() + r.clone()).coef().to_vec();
+        while lhs.last() == Some(&Mint::new(0)) {
+            lhs.pop();
+        }
+        let mut rhs = f.coef().to_vec();
+        while rhs.last() == Some(&Mint::new(0)) {
+            rhs.pop();
+        }
+        assert_eq!(rhs, lhs);
+        assert_eq!(q, f.clone() / g.clone());
+        assert_eq!(r, f % g);
+    }
+}
+
+// Tests `div_rem` when the divisor has trailing zeros or a higher degree.
+#[test]
+fn test_div_rem_edge_cases() {
+    assert_eq!(
+        (fps(&[2, 1]), fps(&[3])),
+        fps(&[5, 3, 1]).div_rem(&fps(&[1, 1, 0]))
+    );
+    assert_eq!(
+        (fps(&[]), fps(&[1, 2])),
+        fps(&[1, 2, 0]).div_rem(&fps(&[0, 0, 1]))
+    );
+}
+
+// Tests that division by the zero polynomial panics.
+#[test]
+#[should_panic(expected = "Division by the zero polynomial")]
+fn test_div_rem_by_zero() {
+    fps(&[1, 2]).div_rem(&fps(&[0, 0]));
+}
@@ -43,5 +43,5 @@ fn test_gcd_large_numbers() {
     assert_eq!(1, gcd(1, u128::MAX));
 
     // 3 * 2^{125} と 5 * 2^{125}
-    assert_eq!(1 << 125, gcd(3 * 1 << 125, 5 * 1 << 125));
+    assert_eq!(1 << 125, gcd(3 << 125, 5 << 125));
 }
@@ -1,14 +1,49 @@
 pub mod ds {
     pub mod modint {
+        pub mod convolution;
         pub mod convolution998244353;
+        pub mod dynamic_modint;
         pub mod modint998244353;
+        pub mod static_modint;
     }
     pub mod segment_tree {
+        pub mod lazy_segment_tree;
+        pub mod merge_sort_tree;
+        pub mod persistent_segment_tree;
+        pub mod segment_tree_2d;
+        pub mod segment_tree_beats;
         pub mod segment_tree_dense;
+        pub mod segment_tree_sparse;
     }
     pub mod bit_vector;
+    pub mod disjoint_sparse_table;
+    pub mod fenwick;
+    pub mod offline_dynamic_connectivity;
+    pub mod persistent_union_find;
+    pub mod rollback_union_find;
+    pub mod sparse_table;
     pub mod union_find;
     pub mod wavelet_matrix;
+    pub mod weighted_union_find;
+}
+
+pub mod graph {
+    pub mod assignment;
+    pub mod base;
+    pub mod bipartite_matching;
+    pub mod components;
+    pub mod general_matching;
+    pub mod hld;
+    pub mod max_flow;
+    pub mod min_cost_flow;
+    pub mod mst;
+    pub mod shortest_path;
+    pub mod traversal;
+    pub mod tree;
+}
+
+pub mod math {
+    pub mod fps;
 }
 
 pub mod number_theory {